@@ -3,6 +3,43 @@ use crate::{Curve, Error};
 pub trait HashToCurve: Curve {
     fn hash_to_curve(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Point, Error>;
     fn hash_to_scalar(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Scalar, Error>;
+
+    /// Deterministically maps a field element to the point on the curve
+    ///
+    /// Corresponds to `map_to_curve` followed by `clear_cofactor` defined in the [hash to curve draft].
+    /// Field element `u` is encoded as big-endian integer which is reduced modulo field order. Not every
+    /// curve exposes this primitive, default implementation always returns an error.
+    ///
+    /// [hash to curve draft]: https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-mappings-for-elliptic-curve
+    fn map_to_curve(u: &[u8]) -> Result<Self::Point, Error> {
+        let _ = u;
+        Err(Error)
+    }
+}
+
+/// Incremental hash to curve
+///
+/// Produces exactly the same output as [`HashToCurve`], but the message doesn't need to be
+/// known upfront: it can be fed into the hasher chunk by chunk.
+pub trait IncrementalHashToCurve: HashToCurve {
+    /// State of the hasher which has absorbed DST and part of the message
+    type Hasher: Clone;
+
+    fn new_hasher(ctx: Tag) -> Self::Hasher;
+    fn update_hasher(hasher: &mut Self::Hasher, msg: &[u8]);
+    fn finalize_to_curve(hasher: Self::Hasher) -> Result<Self::Point, Error>;
+    fn finalize_to_scalar(hasher: Self::Hasher) -> Result<Self::Scalar, Error>;
+}
+
+/// Nonuniform encoding of bytestrings into points on the curve
+///
+/// Corresponds to `encode_to_curve` primitive defined in the [hash to curve draft]. Unlike
+/// [`HashToCurve::hash_to_curve`], output distribution is not uniform, so it's only suitable
+/// for protocols that explicitly require this primitive.
+///
+/// [hash to curve draft]: https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-encoding-byte-strings-to-el
+pub trait EncodeToCurve: Curve {
+    fn encode_to_curve(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Point, Error>;
 }
 
 /// Domain separation tag
@@ -44,3 +81,217 @@ impl<'s> Tag<'s> {
         self.0
     }
 }
+
+impl<'s> From<&'s TagBuf> for Tag<'s> {
+    fn from(tag: &'s TagBuf) -> Self {
+        tag.as_tag()
+    }
+}
+
+/// Owned domain separation tag
+///
+/// Unlike [`Tag`], it owns the bytestring, which makes it possible to build tag hierarchies:
+/// a protocol defines a root tag, and derives a sub-tag per protocol round via [`derive`](Self::derive).
+/// All constructors are `const`, so the whole hierarchy can be defined at compile time:
+///
+/// ```rust
+/// use generic_ec_core::hash_to_curve::TagBuf;
+///
+/// const PROTOCOL: TagBuf = TagBuf::new_unwrap(b"MYPROTOCOL").with_version(2);
+/// const ROUND1: TagBuf = PROTOCOL.derive("round1");
+/// const ROUND2: TagBuf = PROTOCOL.derive("round2");
+///
+/// assert_eq!(ROUND1.as_bytes(), b"MYPROTOCOL@v2/round1");
+/// assert_eq!(ROUND2.as_bytes(), b"MYPROTOCOL@v2/round2");
+/// ```
+///
+/// Tag can be at most [`TagBuf::MAX_LEN`] bytes long. Longer DSTs must be hashed according to the
+/// [rule defined in the hash to curve draft][oversize], which is done by [`TagBuf::new_xmd`]
+/// and [`TagBuf::new_xof`].
+///
+/// [oversize]: https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-using-dsts-longer-than-255-
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TagBuf {
+    bytes: [u8; TagBuf::MAX_LEN],
+    len: u8,
+}
+
+impl TagBuf {
+    /// Maximum length of the tag
+    pub const MAX_LEN: usize = 255;
+
+    /// Separator between parent tag and derived label
+    const LABEL_SEPARATOR: u8 = b'/';
+    /// Separator between tag and its version
+    const VERSION_SEPARATOR: u8 = b'@';
+    /// Salt used to hash DSTs that are longer than [`TagBuf::MAX_LEN`]
+    const OVERSIZE_DST_SALT: &'static [u8] = b"H2C-OVERSIZE-DST-";
+
+    /// Tries to construct a tag from bytestring `tag`
+    ///
+    /// Returns `None` if `tag` is empty or longer than [`TagBuf::MAX_LEN`]
+    pub const fn new(tag: &[u8]) -> Option<Self> {
+        if tag.is_empty() {
+            return None;
+        }
+        Self::empty().try_append(tag)
+    }
+
+    /// Constructs a tag from bytestring `tag`
+    ///
+    /// ## Panics
+    /// Panics if `tag` is empty or longer than [`TagBuf::MAX_LEN`]
+    pub const fn new_unwrap(tag: &[u8]) -> Self {
+        match Self::new(tag) {
+            Some(tag) => tag,
+            None => panic!("tag must be non-empty and at most 255 bytes long"),
+        }
+    }
+
+    /// Constructs a tag of any (non-zero) length to be used with `expand_message_xmd` based on hash `H`
+    ///
+    /// If `tag` is longer than [`TagBuf::MAX_LEN`], it's replaced with `H("H2C-OVERSIZE-DST-" || tag)`.
+    /// Resulting tag leads to the same output of hash to curve as the original `tag` if the curve
+    /// uses `expand_message_xmd` with the same hash function `H`.
+    ///
+    /// Returns `None` if `tag` is empty.
+    pub fn new_xmd<H: digest::Digest>(tag: &[u8]) -> Option<Self> {
+        if tag.len() <= Self::MAX_LEN {
+            return Self::new(tag);
+        }
+        let hash = H::new()
+            .chain_update(Self::OVERSIZE_DST_SALT)
+            .chain_update(tag)
+            .finalize();
+        Self::new(&hash)
+    }
+
+    /// Constructs a tag of any (non-zero) length to be used with `expand_message_xof` based on
+    /// extendable output function `H`
+    ///
+    /// If `tag` is longer than [`TagBuf::MAX_LEN`], it's replaced with 32 bytes of
+    /// `H("H2C-OVERSIZE-DST-" || tag)` (which corresponds to 128 bits security level).
+    /// Resulting tag leads to the same output of hash to curve as the original `tag` if the curve
+    /// uses `expand_message_xof` with the same function `H`.
+    ///
+    /// Returns `None` if `tag` is empty.
+    pub fn new_xof<H>(tag: &[u8]) -> Option<Self>
+    where
+        H: digest::ExtendableOutput + digest::Update + Default,
+    {
+        if tag.len() <= Self::MAX_LEN {
+            return Self::new(tag);
+        }
+        let mut hash = [0u8; 32];
+        let mut hasher = H::default();
+        hasher.update(Self::OVERSIZE_DST_SALT);
+        hasher.update(tag);
+        hasher.finalize_xof_into(&mut hash);
+        Self::new(&hash)
+    }
+
+    /// Derives a sub-tag `{self}/{label}`
+    ///
+    /// Label must be non-empty and must not contain `/` or `@` characters, so derivation
+    /// is unambiguous.
+    ///
+    /// ## Panics
+    /// Panics if label is invalid, or if resulting tag is longer than [`TagBuf::MAX_LEN`]
+    pub const fn derive(&self, label: &str) -> Self {
+        match self.try_derive(label) {
+            Some(tag) => tag,
+            None => panic!("invalid label or derived tag is too long"),
+        }
+    }
+
+    /// Derives a sub-tag `{self}/{label}`
+    ///
+    /// Returns `None` if label is invalid (see [`derive`](Self::derive)), or if resulting tag
+    /// is longer than [`TagBuf::MAX_LEN`]
+    pub const fn try_derive(&self, label: &str) -> Option<Self> {
+        let label = label.as_bytes();
+        if label.is_empty() {
+            return None;
+        }
+        let mut i = 0;
+        while i < label.len() {
+            if label[i] == Self::LABEL_SEPARATOR || label[i] == Self::VERSION_SEPARATOR {
+                return None;
+            }
+            i += 1;
+        }
+
+        match self.try_append(&[Self::LABEL_SEPARATOR]) {
+            Some(tag) => tag.try_append(label),
+            None => None,
+        }
+    }
+
+    /// Appends version to the tag: `{self}@v{version}`
+    ///
+    /// ## Panics
+    /// Panics if resulting tag is longer than [`TagBuf::MAX_LEN`]
+    pub const fn with_version(&self, version: u16) -> Self {
+        // `@v` followed by at most 5 decimal digits
+        let mut suffix = [Self::VERSION_SEPARATOR, b'v', 0, 0, 0, 0, 0];
+        let mut digits = 1;
+        let mut rest = version / 10;
+        while rest > 0 {
+            digits += 1;
+            rest /= 10;
+        }
+        let mut rest = version;
+        let mut i = 0;
+        while i < digits {
+            suffix[2 + digits - 1 - i] = b'0' + (rest % 10) as u8;
+            rest /= 10;
+            i += 1;
+        }
+
+        let (suffix, _) = suffix.split_at(2 + digits);
+        match self.try_append(suffix) {
+            Some(tag) => tag,
+            None => panic!("tag is too long"),
+        }
+    }
+
+    /// Bytestring corresponding to the tag
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.len)]
+    }
+
+    /// Borrows the tag as [`Tag`]
+    pub fn as_tag(&self) -> Tag<'_> {
+        Tag(self.as_bytes())
+    }
+
+    const fn empty() -> Self {
+        Self {
+            bytes: [0u8; Self::MAX_LEN],
+            len: 0,
+        }
+    }
+
+    const fn try_append(mut self, bytes: &[u8]) -> Option<Self> {
+        let len = self.len as usize;
+        if bytes.len() > Self::MAX_LEN - len {
+            return None;
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            self.bytes[len + i] = bytes[i];
+            i += 1;
+        }
+        self.len = (len + bytes.len()) as u8;
+        Some(self)
+    }
+}
+
+impl core::fmt::Debug for TagBuf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match core::str::from_utf8(self.as_bytes()) {
+            Ok(tag) => f.debug_tuple("TagBuf").field(&tag).finish(),
+            Err(_) => f.debug_tuple("TagBuf").field(&self.as_bytes()).finish(),
+        }
+    }
+}
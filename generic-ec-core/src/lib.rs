@@ -114,6 +114,14 @@ pub trait SmallFactor {
     fn is_torsion_free(&self) -> Choice;
 }
 
+/// Curve that exposes multiplication of a point at cofactor
+///
+/// Points of the curve may have a small order component (torsion). Multiplication at cofactor
+/// eliminates it, output of [`mul_by_cofactor`](Self::mul_by_cofactor) must always be torsion free.
+pub trait MulByCofactor: Curve {
+    fn mul_by_cofactor(point: &Self::Point) -> Self::Point;
+}
+
 pub struct CurveGenerator;
 
 pub trait CompressedEncoding
@@ -148,15 +156,59 @@ where
 
     fn from_be_bytes_exact(bytes: &Self::Bytes) -> Option<Self>;
     fn from_le_bytes_exact(bytes: &Self::Bytes) -> Option<Self>;
+
+    /// Same as [`from_be_bytes_exact`](Self::from_be_bytes_exact), but constant time
+    ///
+    /// Default implementation reduces the integer modulo group order and checks (in constant time)
+    /// that encoding of the result matches `bytes`. Backends are encouraged to override it
+    /// if they can check validity natively.
+    fn ct_from_be_bytes_exact(bytes: &Self::Bytes) -> CtOption<Self> {
+        let scalar = Self::from_be_bytes(bytes);
+        let is_canonical = scalar.to_be_bytes().as_ref().ct_eq(bytes.as_ref());
+        CtOption::new(scalar, is_canonical)
+    }
+    /// Same as [`from_le_bytes_exact`](Self::from_le_bytes_exact), but constant time
+    ///
+    /// Default implementation reduces the integer modulo group order and checks (in constant time)
+    /// that encoding of the result matches `bytes`. Backends are encouraged to override it
+    /// if they can check validity natively.
+    fn ct_from_le_bytes_exact(bytes: &Self::Bytes) -> CtOption<Self> {
+        let scalar = Self::from_le_bytes(bytes);
+        let is_canonical = scalar.to_le_bytes().as_ref().ct_eq(bytes.as_ref());
+        CtOption::new(scalar, is_canonical)
+    }
 }
 
 pub trait Decode: Sized {
     fn decode(bytes: &[u8]) -> Option<Self>;
+
+    /// Same as [`decode`](Self::decode), but constant time
+    ///
+    /// Only validity of encoded point must be checked in constant time, length of `bytes` is
+    /// considered to be public.
+    ///
+    /// Default implementation wraps [`decode`](Self::decode) which is not constant time. Backends
+    /// are encouraged to override it.
+    fn ct_decode(bytes: &[u8]) -> CtOption<Self>
+    where
+        Self: Default,
+    {
+        match Self::decode(bytes) {
+            Some(point) => CtOption::new(point, Choice::from(1)),
+            None => CtOption::new(Self::default(), Choice::from(0)),
+        }
+    }
 }
 
 pub struct Error;
 
 pub trait ByteArray: AsRef<[u8]> + AsMut<[u8]> + Clone + Send + Sync + 'static {
+    /// Length of the byte array
+    ///
+    /// Defaults to size of `Self`, which matches the length for plain byte arrays. Types that
+    /// carry anything besides the bytes must override it.
+    const LEN: usize = core::mem::size_of::<Self>();
+
     /// New byte array of zeroes
     ///
     /// Alternative to [`Default`] that is not implemented for generic `[T; N]`
@@ -165,12 +217,16 @@ pub trait ByteArray: AsRef<[u8]> + AsMut<[u8]> + Clone + Send + Sync + 'static {
 }
 
 impl<const N: usize> ByteArray for [u8; N] {
+    const LEN: usize = N;
+
     fn zeroes() -> Self {
         [0; N]
     }
 }
 
 impl<N: ArrayLength<u8>> ByteArray for GenericArray<u8, N> {
+    const LEN: usize = N::USIZE;
+
     fn zeroes() -> Self {
         GenericArray::default()
     }
@@ -1,3 +1,29 @@
+use generic_ec_core::coords::{
+    AlwaysHasAffineY, AlwaysHasAffineYAndSign, HasAffineX, HasAffineXY, HasAffineY, Sign,
+};
+use generic_ec_core::hash_to_curve::{EncodeToCurve, HashToCurve, IncrementalHashToCurve, Tag};
+
+use crate::expand_msg::{Expander, MessageHasher, XmdHasher};
+
+mod affine;
+mod elligator;
+pub mod x25519;
+
+/// Ed25519 curve
+///
+/// Hash to curve primitives implement `edwards25519_XMD:SHA-512_ELL2_RO_` and
+/// `edwards25519_XMD:SHA-512_ELL2_NU_` suites defined in [RFC 9380]. Hash to
+/// scalar uses `expand_message_xmd` with SHA-512, and reduces 48 bytes of its
+/// output modulo group order.
+///
+/// Curve exposes affine $x, y$ coordinates encoded as 32 bytes big-endian integers, like
+/// for other curves. Sign of $x$ coordinate is [`Sign::Negative`] if $x$ is odd, which matches
+/// point encoding defined in [RFC 8032]. Note that RFC 8032 encodes coordinates in little-endian,
+/// so big-endian coordinates need to be reversed (e.g. via `Coordinate::to_le_bytes` in
+/// `generic-ec`) when building RFC 8032 encodings.
+///
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.2
+/// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-curve25519-and-e
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Default, zeroize::Zeroize)]
 pub struct Ed25519 {
     _private: (),
@@ -14,8 +40,89 @@ impl generic_ec_core::Curve for Ed25519 {
 
     type ScalarArray = <Scalar as generic_ec_core::IntegerEncoding>::Bytes;
 
-    // We don't expose affine coordinates for ed25519 curve
-    type CoordinateArray = [u8; 0];
+    type CoordinateArray = [u8; 32];
+}
+
+impl generic_ec_core::MulByCofactor for Ed25519 {
+    fn mul_by_cofactor(point: &Self::Point) -> Self::Point {
+        Point(point.0.mul_by_cofactor())
+    }
+}
+
+impl AlwaysHasAffineY for Ed25519 {
+    fn y(point: &Self::Point) -> Self::CoordinateArray {
+        let (_, y) = Self::y_and_sign(point);
+        y
+    }
+}
+
+impl AlwaysHasAffineYAndSign for Ed25519 {
+    fn y_and_sign(point: &Self::Point) -> (Sign, Self::CoordinateArray) {
+        // RFC 8032 encoding is `y` in little-endian with sign of `x` in the most significant bit
+        let mut y = point.0.compress().to_bytes();
+        let sign = if y[31] >> 7 == 1 {
+            Sign::Negative
+        } else {
+            Sign::NonNegative
+        };
+        y[31] &= 0x7f;
+        y.reverse();
+        (sign, y)
+    }
+
+    fn from_y_and_sign(x_sign: Sign, y: &Self::CoordinateArray) -> Option<Self::Point> {
+        let mut compressed = *y;
+        compressed.reverse();
+        if compressed[31] >> 7 == 1 {
+            return None;
+        }
+        if x_sign.is_negative() {
+            compressed[31] |= 0x80;
+        }
+        let point = curve25519::edwards::CompressedEdwardsY(compressed).decompress()?;
+        // Decompression accepts non-canonical `y` and negative zero `x`, we reject them
+        // to make sure that every point has exactly one representation
+        if point.compress().to_bytes() != compressed {
+            return None;
+        }
+        Some(Point(point))
+    }
+}
+
+impl HasAffineX for Ed25519 {
+    fn x(point: &Self::Point) -> Option<Self::CoordinateArray> {
+        let (x, _) = Self::x_and_y(point)?;
+        Some(x)
+    }
+}
+
+impl HasAffineY for Ed25519 {
+    fn y(point: &Self::Point) -> Option<Self::CoordinateArray> {
+        Some(<Self as AlwaysHasAffineY>::y(point))
+    }
+}
+
+impl HasAffineXY for Ed25519 {
+    fn x_and_y(point: &Self::Point) -> Option<(Self::CoordinateArray, Self::CoordinateArray)> {
+        let (x_sign, y) = Self::y_and_sign(point);
+        let y_fe = affine::from_be_bytes(&y)?;
+        let x = affine::recover_x(&y_fe, subtle::Choice::from(u8::from(x_sign.is_negative())));
+        Some((affine::to_be_bytes(&x), y))
+    }
+
+    fn from_x_and_y(x: &Self::CoordinateArray, y: &Self::CoordinateArray) -> Option<Self::Point> {
+        let x = affine::from_be_bytes(x)?;
+        let y_fe = affine::from_be_bytes(y)?;
+        if !bool::from(affine::is_on_curve(&x, &y_fe)) {
+            return None;
+        }
+        let x_sign = if bool::from(affine::is_negative(&x)) {
+            Sign::Negative
+        } else {
+            Sign::NonNegative
+        };
+        Self::from_y_and_sign(x_sign, y)
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, zeroize::Zeroize)]
@@ -66,7 +173,7 @@ impl generic_ec_core::OnCurve for Point {
 impl generic_ec_core::SmallFactor for Point {
     #[inline]
     fn is_torsion_free(&self) -> subtle::Choice {
-        subtle::Choice::from(u8::from(self.0.is_torsion_free()))
+        group::cofactor::CofactorGroup::is_torsion_free(&self.0)
     }
 }
 
@@ -106,6 +213,14 @@ impl generic_ec_core::Decode for Point {
         let compressed = curve25519::edwards::CompressedEdwardsY::from_slice(bytes).ok()?;
         compressed.decompress().map(Self)
     }
+
+    fn ct_decode(bytes: &[u8]) -> subtle::CtOption<Self> {
+        // Length of encoded point is considered public
+        let Ok(compressed) = <[u8; 32]>::try_from(bytes) else {
+            return subtle::CtOption::new(Self::default(), subtle::Choice::from(0));
+        };
+        <curve25519::EdwardsPoint as group::GroupEncoding>::from_bytes(&compressed).map(Self)
+    }
 }
 
 impl core::cmp::PartialOrd for Point {
@@ -280,6 +395,16 @@ impl generic_ec_core::IntegerEncoding for Scalar {
     fn from_le_bytes_exact(bytes: &Self::Bytes) -> Option<Self> {
         Option::from(curve25519::Scalar::from_canonical_bytes(*bytes)).map(Self)
     }
+
+    fn ct_from_be_bytes_exact(bytes: &Self::Bytes) -> subtle::CtOption<Self> {
+        let mut bytes = *bytes;
+        bytes.reverse();
+        Self::ct_from_le_bytes_exact(&bytes)
+    }
+
+    fn ct_from_le_bytes_exact(bytes: &Self::Bytes) -> subtle::CtOption<Self> {
+        curve25519::Scalar::from_canonical_bytes(*bytes).map(Self)
+    }
 }
 
 impl core::cmp::PartialOrd for Scalar {
@@ -293,3 +418,87 @@ impl core::cmp::Ord for Scalar {
         self.0.as_bytes().cmp(other.0.as_bytes())
     }
 }
+
+/// Amount of bytes sampled via `expand_message` per field element or scalar
+const HASH_TO_FIELD_LEN: usize = 48;
+
+impl HashToCurve for Ed25519 {
+    fn hash_to_curve(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Point, generic_ec_core::Error> {
+        let mut hasher = Self::new_hasher(ctx);
+        msgs.iter().for_each(|msg| hasher.update(msg));
+        Self::finalize_to_curve(hasher)
+    }
+
+    fn hash_to_scalar(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Scalar, generic_ec_core::Error> {
+        let mut hasher = Self::new_hasher(ctx);
+        msgs.iter().for_each(|msg| hasher.update(msg));
+        Self::finalize_to_scalar(hasher)
+    }
+
+    fn map_to_curve(u: &[u8]) -> Result<Self::Point, generic_ec_core::Error> {
+        let mut okm = [0u8; HASH_TO_FIELD_LEN];
+        let offset = HASH_TO_FIELD_LEN
+            .checked_sub(u.len())
+            .ok_or(generic_ec_core::Error)?;
+        okm[offset..].copy_from_slice(u);
+        let point = field_element_to_curve(&elligator::field_element_from_okm(&okm))?;
+        Ok(Point(point.mul_by_cofactor()))
+    }
+}
+
+impl IncrementalHashToCurve for Ed25519 {
+    type Hasher = XmdHasher<sha2::Sha512>;
+
+    fn new_hasher(ctx: Tag) -> Self::Hasher {
+        XmdHasher::new(ctx.as_bytes())
+    }
+
+    fn update_hasher(hasher: &mut Self::Hasher, msg: &[u8]) {
+        hasher.update(msg)
+    }
+
+    fn finalize_to_curve(hasher: Self::Hasher) -> Result<Self::Point, generic_ec_core::Error> {
+        let mut expander = hasher.finalize(2 * HASH_TO_FIELD_LEN)?;
+        let q0 = map_to_curve(&mut expander)?;
+        let q1 = map_to_curve(&mut expander)?;
+        Ok(Point((q0 + q1).mul_by_cofactor()))
+    }
+
+    fn finalize_to_scalar(hasher: Self::Hasher) -> Result<Self::Scalar, generic_ec_core::Error> {
+        let mut expander = hasher.finalize(HASH_TO_FIELD_LEN)?;
+        let mut okm = [0u8; 64];
+        expander.fill_bytes(&mut okm[..HASH_TO_FIELD_LEN]);
+        // `okm` is big-endian integer, while `from_bytes_mod_order_wide` expects little-endian
+        okm[..HASH_TO_FIELD_LEN].reverse();
+        Ok(Scalar(curve25519::Scalar::from_bytes_mod_order_wide(&okm)))
+    }
+}
+
+impl EncodeToCurve for Ed25519 {
+    fn encode_to_curve(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Point, generic_ec_core::Error> {
+        let mut hasher = XmdHasher::<sha2::Sha512>::new(ctx.as_bytes());
+        msgs.iter().for_each(|msg| hasher.update(msg));
+        let mut expander = hasher.finalize(HASH_TO_FIELD_LEN)?;
+        let q = map_to_curve(&mut expander)?;
+        Ok(Point(q.mul_by_cofactor()))
+    }
+}
+
+/// Samples a field element from `expander`, and maps it to the point on the curve
+fn map_to_curve(
+    expander: &mut impl Expander,
+) -> Result<curve25519::EdwardsPoint, generic_ec_core::Error> {
+    let mut okm = [0u8; HASH_TO_FIELD_LEN];
+    expander.fill_bytes(&mut okm);
+    field_element_to_curve(&elligator::field_element_from_okm(&okm))
+}
+
+/// Maps a field element to the point on the curve (without clearing cofactor)
+fn field_element_to_curve(
+    u: &elligator::FieldElement,
+) -> Result<curve25519::EdwardsPoint, generic_ec_core::Error> {
+    let (x, y) = elligator::map_to_curve(u);
+    curve25519::edwards::CompressedEdwardsY(elligator::compress(&x, &y))
+        .decompress()
+        .ok_or(generic_ec_core::Error)
+}
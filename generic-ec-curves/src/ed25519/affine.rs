@@ -0,0 +1,67 @@
+//! Affine coordinates of edwards25519 points
+//!
+//! `curve25519-dalek` only exposes compressed form of the point, i.e. $y$ coordinate and sign
+//! of $x$. Here we recover $x$ coordinate from the curve equation $-x^2 + y^2 = 1 + d x^2 y^2$
+//! as described in [RFC 8032 Section 5.1.3].
+//!
+//! [RFC 8032 Section 5.1.3]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.3
+
+use crypto_bigint::modular::constant_mod::ResidueParams;
+use crypto_bigint::{Encoding, U256};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::elligator::{sgn0, FieldElement, Modulus, C4, SQRT_M1};
+
+/// Curve coefficient `d = -121665 / 121666`
+const D: FieldElement = FieldElement::new(&U256::from_be_hex(
+    "52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3",
+));
+
+/// Parses field element from its canonical big-endian encoding
+///
+/// Returns `None` if `bytes` represent an integer which is not less than $p$
+pub fn from_be_bytes(bytes: &[u8; 32]) -> Option<FieldElement> {
+    let int = U256::from_be_bytes(*bytes);
+    if int < Modulus::MODULUS {
+        Some(FieldElement::new(&int))
+    } else {
+        None
+    }
+}
+
+/// Encodes field element as 32 bytes big-endian
+pub fn to_be_bytes(x: &FieldElement) -> [u8; 32] {
+    x.retrieve().to_be_bytes()
+}
+
+/// Indicates whether field element is negative (i.e. odd) in the sense of RFC 8032
+pub fn is_negative(x: &FieldElement) -> Choice {
+    sgn0(x)
+}
+
+/// Recovers $x$ coordinate of the point from its $y$ coordinate and sign of $x$
+///
+/// `y` must be a coordinate of a valid point on the curve, otherwise output is meaningless.
+pub fn recover_x(y: &FieldElement, x_is_negative: Choice) -> FieldElement {
+    let one = FieldElement::ONE;
+
+    let y2 = y.square();
+    let u = y2 - one;
+    let v = D * y2 + one;
+
+    // `x = sqrt(u / v)`. `v` is never zero as `d` is not a square
+    let (v_inv, _) = v.invert();
+    let x2 = u * v_inv;
+    let x = x2.pow(&C4) * x2;
+    let x_alt = x * SQRT_M1;
+    let x = FieldElement::conditional_select(&x_alt, &x, x.square().ct_eq(&x2));
+
+    FieldElement::conditional_select(&x, &-x, sgn0(&x) ^ x_is_negative)
+}
+
+/// Checks that $(x, y)$ satisfy the curve equation
+pub fn is_on_curve(x: &FieldElement, y: &FieldElement) -> Choice {
+    let x2 = x.square();
+    let y2 = y.square();
+    (y2 - x2).ct_eq(&(FieldElement::ONE + D * x2 * y2))
+}
@@ -0,0 +1,133 @@
+//! Elligator 2 map to edwards25519 curve
+//!
+//! Follows [RFC 9380 Appendix G.2], which defines `map_to_curve_elligator2_edwards25519`.
+//! `curve25519-dalek` doesn't expose its field arithmetic, so we implement the map
+//! on top of [`crypto_bigint`] constant-time modular arithmetic.
+//!
+//! [RFC 9380 Appendix G.2]: https://www.rfc-editor.org/rfc/rfc9380.html#appendix-G.2
+
+use crypto_bigint::modular::constant_mod::{Residue, ResidueParams};
+use crypto_bigint::{impl_modulus, Encoding, U256, U512};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+impl_modulus!(
+    Modulus,
+    U256,
+    "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+);
+
+/// Element of the base field of curve25519
+pub type FieldElement = Residue<Modulus, { U256::LIMBS }>;
+
+/// Montgomery curve coefficient `J = 486662`
+const J: FieldElement = FieldElement::new(&U256::from_u64(486662));
+/// `c2 = 2^((p + 3) / 8)`
+const C2: FieldElement = FieldElement::new(&U256::from_be_hex(
+    "2b8324804fc1df0b2b4d00993dfbd7a72f431806ad2fe478c4ee1b274a0ea0b1",
+));
+/// `c3 = sqrt(-1)`
+pub const SQRT_M1: FieldElement = FieldElement::new(&U256::from_be_hex(
+    "2b8324804fc1df0b2b4d00993dfbd7a72f431806ad2fe478c4ee1b274a0ea0b0",
+));
+/// `c4 = (p - 5) / 8`
+pub const C4: U256 =
+    U256::from_be_hex("0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd");
+/// `sqrt(-486664)` such that `sgn0(sqrt(-486664)) = 0`
+const SQRT_AM2: FieldElement = FieldElement::new(&U256::from_be_hex(
+    "0f26edf460a006bbd27b08dc03fc4f7ec5a1d3d14b7d1a82cc6e04aaff457e06",
+));
+
+/// Reduces 48 bytes produced by `expand_message` (in big-endian) modulo $p$
+pub fn field_element_from_okm(okm: &[u8; 48]) -> FieldElement {
+    let mut wide = [0u8; 64];
+    wide[16..].copy_from_slice(okm);
+    let (hi, lo) = U512::from_be_slice(&wide).split();
+    let (reduced, _) = U256::const_rem_wide((lo, hi), &Modulus::MODULUS);
+    FieldElement::new(&reduced)
+}
+
+/// `sgn0(x)` as defined in RFC 9380
+pub fn sgn0(x: &FieldElement) -> Choice {
+    Choice::from((x.retrieve().as_words()[0] & 1) as u8)
+}
+
+/// `map_to_curve_elligator2_curve25519`
+///
+/// Returns point on curve25519 in projective coordinates `(xn, xd, yn, yd)`
+fn map_to_curve25519(u: &FieldElement) -> (FieldElement, FieldElement, FieldElement, FieldElement) {
+    let one = FieldElement::ONE;
+
+    let tv1 = u.square();
+    let tv1 = tv1 + tv1;
+    let xd = tv1 + one;
+    let x1n = -J;
+    let tv2 = xd.square();
+    let gxd = tv2 * xd;
+    let gx1 = J * tv1;
+    let gx1 = gx1 * x1n;
+    let gx1 = gx1 + tv2;
+    let gx1 = gx1 * x1n;
+    let tv3 = gxd.square();
+    let tv2 = tv3.square();
+    let tv3 = tv3 * gxd;
+    let tv3 = tv3 * gx1;
+    let tv2 = tv2 * tv3;
+    let y11 = tv2.pow(&C4);
+    let y11 = y11 * tv3;
+    let y12 = y11 * SQRT_M1;
+    let tv2 = y11.square();
+    let tv2 = tv2 * gxd;
+    let e1 = tv2.ct_eq(&gx1);
+    let y1 = FieldElement::conditional_select(&y12, &y11, e1);
+    let x2n = x1n * tv1;
+    let y21 = y11 * u;
+    let y21 = y21 * C2;
+    let y22 = y21 * SQRT_M1;
+    let gx2 = gx1 * tv1;
+    let tv2 = y21.square();
+    let tv2 = tv2 * gxd;
+    let e2 = tv2.ct_eq(&gx2);
+    let y2 = FieldElement::conditional_select(&y22, &y21, e2);
+    let tv2 = y1.square();
+    let tv2 = tv2 * gxd;
+    let e3 = tv2.ct_eq(&gx1);
+    let xn = FieldElement::conditional_select(&x2n, &x1n, e3);
+    let y = FieldElement::conditional_select(&y2, &y1, e3);
+    let e4 = sgn0(&y);
+    let y = FieldElement::conditional_select(&y, &-y, e3 ^ e4);
+
+    (xn, xd, y, one)
+}
+
+/// `map_to_curve_elligator2_edwards25519`
+///
+/// Returns affine coordinates `(x, y)` of the point on edwards25519
+pub fn map_to_curve(u: &FieldElement) -> (FieldElement, FieldElement) {
+    let zero = FieldElement::ZERO;
+    let one = FieldElement::ONE;
+
+    let (xmn, xmd, ymn, ymd) = map_to_curve25519(u);
+    let xn = xmn * ymd;
+    let xn = xn * SQRT_AM2;
+    let xd = xmd * ymn;
+    let yn = xmn - xmd;
+    let yd = xmn + xmd;
+    let tv1 = xd * yd;
+    let e = tv1.ct_eq(&zero);
+    let xn = FieldElement::conditional_select(&xn, &zero, e);
+    let xd = FieldElement::conditional_select(&xd, &one, e);
+    let yn = FieldElement::conditional_select(&yn, &one, e);
+    let yd = FieldElement::conditional_select(&yd, &one, e);
+
+    // `xd` and `yd` are guaranteed to be non-zero, so inversion always succeeds
+    let (xd_inv, _) = xd.invert();
+    let (yd_inv, _) = yd.invert();
+    (xn * xd_inv, yn * yd_inv)
+}
+
+/// Encodes affine point `(x, y)` on edwards25519 into compressed form
+pub fn compress(x: &FieldElement, y: &FieldElement) -> [u8; 32] {
+    let mut bytes = y.retrieve().to_le_bytes();
+    bytes[31] |= sgn0(x).unwrap_u8() << 7;
+    bytes
+}
@@ -0,0 +1,58 @@
+//! Montgomery form of curve25519 and X25519 function
+//!
+//! Edwards curve edwards25519 is birationally equivalent to Montgomery curve curve25519, which is
+//! used by X25519 key agreement defined in [RFC 7748]. Montgomery points are represented by their
+//! $u$ coordinate encoded as 32 bytes little-endian integer.
+//!
+//! [RFC 7748]: https://www.rfc-editor.org/rfc/rfc7748
+
+use generic_ec_core::coords::Sign;
+
+use super::{Point, Scalar};
+
+/// Returns $u$ coordinate of the point in Montgomery form
+///
+/// Identity point is mapped to $u = 0$.
+pub fn to_montgomery(point: &Point) -> [u8; 32] {
+    point.0.to_montgomery().to_bytes()
+}
+
+/// Converts Montgomery $u$ coordinate back to the point in Edwards form
+///
+/// Birational map loses the sign of $x$ coordinate, so it needs to be provided explicitly.
+/// Returns `None` if $u$ doesn't correspond to a point on the curve (i.e. it's a point on the twist).
+pub fn from_montgomery(u: &[u8; 32], x_sign: Sign) -> Option<Point> {
+    curve25519::montgomery::MontgomeryPoint(*u)
+        .to_edwards(u8::from(x_sign.is_negative()))
+        .map(Point)
+}
+
+/// X25519 function defined in [RFC 7748](https://www.rfc-editor.org/rfc/rfc7748#section-5)
+///
+/// Clamps `k` and multiplies point with $u$ coordinate at it. Any 32 bytes are accepted as $u$
+/// coordinate (the most significant bit is ignored, as required by RFC).
+pub fn x25519(k: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    curve25519::montgomery::MontgomeryPoint(u)
+        .mul_clamped(k)
+        .to_bytes()
+}
+
+/// Multiplies point with $u$ coordinate at scalar $s$ and cofactor-clearing factor
+///
+/// Computes $\[8\] \cdot \[s \cdot 8^{-1} \bmod \ell\] \cdot P$. For any point $P$ on the curve, the
+/// output is the same as `x25519(k, u)` for every $k$ such that `clamp_integer(k)` $\equiv s \pmod \ell$,
+/// i.e. it's compatible with X25519 when $s$ is derived from X25519 secret key. Torsion
+/// component of $P$ is eliminated, so $s \bmod 8$ is never leaked.
+pub fn diffie_hellman(u: &[u8; 32], scalar: &Scalar) -> [u8; 32] {
+    let eight = curve25519::Scalar::from(8u8);
+    let point = curve25519::montgomery::MontgomeryPoint(*u) * (scalar.0 * eight.invert());
+    (point * eight).to_bytes()
+}
+
+/// Clamps integer as defined in [RFC 7748](https://www.rfc-editor.org/rfc/rfc7748#section-5)
+///
+/// Clears 3 least significant bits, clears the most significant bit and sets the second most
+/// significant bit. Integer is encoded in little-endian.
+pub const fn clamp_integer(bytes: [u8; 32]) -> [u8; 32] {
+    curve25519::scalar::clamp_integer(bytes)
+}
@@ -0,0 +1,278 @@
+//! Incremental `expand_message` primitive
+//!
+//! Implements `expand_message_xmd` and `expand_message_xof` defined in [RFC 9380], but,
+//! unlike implementation provided by [`elliptic_curve`](https://docs.rs/elliptic-curve),
+//! the message can be fed chunk by chunk. Output is exactly the same as if the whole
+//! message was provided at once.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message
+
+use digest::core_api::BlockSizeUser;
+use digest::generic_array::typenum::{IsLess, IsLessOrEqual, Unsigned, U256, U32};
+use digest::generic_array::GenericArray;
+use digest::{ExtendableOutput, FixedOutput, HashMarker, Update, XofReader};
+
+/// Salt used to hash DST which is longer than 255 bytes
+const OVERSIZE_DST_SALT: &[u8] = b"H2C-OVERSIZE-DST-";
+/// Maximum length of DST
+const MAX_DST_LEN: usize = 255;
+
+/// Message expander that supports incremental hashing
+pub trait IncrementalExpandMsg {
+    /// State of the expander which has absorbed DST and part of the message
+    type Hasher: MessageHasher;
+}
+
+/// State of `expand_message` which has absorbed DST and part of the message
+pub trait MessageHasher: Clone {
+    /// Expander that outputs uniform bytes
+    type Expander: Expander;
+
+    /// Starts hashing the message with given DST
+    fn new(dst: &[u8]) -> Self;
+    /// Appends a chunk to the message
+    fn update(&mut self, msg: &[u8]);
+    /// Finishes hashing the message, outputs expander of `len_in_bytes` uniform bytes
+    fn finalize(self, len_in_bytes: usize) -> Result<Self::Expander, generic_ec_core::Error>;
+}
+
+/// Outputs uniform bytes
+pub trait Expander {
+    /// Fills `okm` with next uniform bytes
+    fn fill_bytes(&mut self, okm: &mut [u8]);
+}
+
+/// DST as it's appended to hashed data, i.e. `DST_prime` without trailing length byte
+#[derive(Clone)]
+struct Dst {
+    bytes: [u8; MAX_DST_LEN],
+    len: u8,
+}
+
+impl Dst {
+    fn new(dst: &[u8]) -> Self {
+        let mut bytes = [0u8; MAX_DST_LEN];
+        bytes[..dst.len()].copy_from_slice(dst);
+        Self {
+            bytes,
+            // Can't overflow: `dst` fits into the buffer
+            len: dst.len() as u8,
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.len)]
+    }
+
+    fn update_hash(&self, hash: &mut impl Update) {
+        hash.update(self.as_bytes());
+        hash.update(&[self.len]);
+    }
+}
+
+/// State of `expand_message_xmd`
+pub struct XmdHasher<H> {
+    b_0: H,
+    dst: Dst,
+}
+
+impl<H: Clone> Clone for XmdHasher<H> {
+    fn clone(&self) -> Self {
+        Self {
+            b_0: self.b_0.clone(),
+            dst: self.dst.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "rust-crypto")]
+impl<H> IncrementalExpandMsg for elliptic_curve::hash2curve::ExpandMsgXmd<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker + Clone,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
+    type Hasher = XmdHasher<H>;
+}
+
+impl<H> MessageHasher for XmdHasher<H>
+where
+    H: BlockSizeUser + Default + FixedOutput + HashMarker + Clone,
+    H::OutputSize: IsLess<U256> + IsLessOrEqual<H::BlockSize>,
+{
+    type Expander = XmdExpander<H>;
+
+    fn new(dst: &[u8]) -> Self {
+        let dst = if dst.len() > MAX_DST_LEN {
+            let mut hash = H::default();
+            hash.update(OVERSIZE_DST_SALT);
+            hash.update(dst);
+            Dst::new(&hash.finalize_fixed())
+        } else {
+            Dst::new(dst)
+        };
+
+        let mut b_0 = H::default();
+        b_0.update(&GenericArray::<u8, H::BlockSize>::default());
+        Self { b_0, dst }
+    }
+
+    fn update(&mut self, msg: &[u8]) {
+        self.b_0.update(msg)
+    }
+
+    fn finalize(self, len_in_bytes: usize) -> Result<Self::Expander, generic_ec_core::Error> {
+        if len_in_bytes == 0 {
+            return Err(generic_ec_core::Error);
+        }
+        let len_in_bytes = u16::try_from(len_in_bytes).or(Err(generic_ec_core::Error))?;
+        let b_in_bytes = H::OutputSize::to_usize();
+        let ell = u8::try_from(usize::from(len_in_bytes).div_ceil(b_in_bytes))
+            .or(Err(generic_ec_core::Error))?;
+
+        let mut b_0 = self.b_0;
+        b_0.update(&len_in_bytes.to_be_bytes());
+        b_0.update(&[0]);
+        self.dst.update_hash(&mut b_0);
+        let b_0 = b_0.finalize_fixed();
+
+        let mut b_i = H::default();
+        b_i.update(&b_0);
+        b_i.update(&[1]);
+        self.dst.update_hash(&mut b_i);
+        let b_i = b_i.finalize_fixed();
+
+        Ok(XmdExpander {
+            b_0,
+            b_i,
+            dst: self.dst,
+            index: 1,
+            offset: 0,
+            ell,
+        })
+    }
+}
+
+/// Outputs uniform bytes produced by `expand_message_xmd`
+pub struct XmdExpander<H: FixedOutput> {
+    b_0: GenericArray<u8, H::OutputSize>,
+    b_i: GenericArray<u8, H::OutputSize>,
+    dst: Dst,
+    index: u8,
+    offset: usize,
+    ell: u8,
+}
+
+impl<H> XmdExpander<H>
+where
+    H: Default + FixedOutput + Update,
+{
+    /// Computes next block `b_i`. Returns `false` if all `ell` blocks were already output.
+    fn next_block(&mut self) -> bool {
+        if self.index >= self.ell {
+            return false;
+        }
+        self.index += 1;
+        self.offset = 0;
+
+        let mut tmp = GenericArray::<u8, H::OutputSize>::default();
+        tmp.iter_mut()
+            .zip(self.b_0.iter().zip(self.b_i.iter()))
+            .for_each(|(t, (b_0, b_i))| *t = b_0 ^ b_i);
+
+        let mut b_i = H::default();
+        b_i.update(&tmp);
+        b_i.update(&[self.index]);
+        self.dst.update_hash(&mut b_i);
+        self.b_i = b_i.finalize_fixed();
+        true
+    }
+}
+
+impl<H> Expander for XmdExpander<H>
+where
+    H: Default + FixedOutput + Update,
+{
+    fn fill_bytes(&mut self, okm: &mut [u8]) {
+        for byte in okm {
+            if self.offset == self.b_i.len() && !self.next_block() {
+                return;
+            }
+            *byte = self.b_i[self.offset];
+            self.offset += 1;
+        }
+    }
+}
+
+/// State of `expand_message_xof`
+pub struct XofHasher<H> {
+    hash: H,
+    dst: Dst,
+}
+
+impl<H: Clone> Clone for XofHasher<H> {
+    fn clone(&self) -> Self {
+        Self {
+            hash: self.hash.clone(),
+            dst: self.dst.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "rust-crypto")]
+impl<H> IncrementalExpandMsg for elliptic_curve::hash2curve::ExpandMsgXof<H>
+where
+    H: Default + ExtendableOutput + Update + Clone,
+{
+    type Hasher = XofHasher<H>;
+}
+
+impl<H> MessageHasher for XofHasher<H>
+where
+    H: Default + ExtendableOutput + Update + Clone,
+{
+    type Expander = XofExpander<H::Reader>;
+
+    fn new(dst: &[u8]) -> Self {
+        let dst = if dst.len() > MAX_DST_LEN {
+            // `ceil(2 * k / 8)` bytes where `k = 128` is target security level
+            let mut hashed_dst = GenericArray::<u8, U32>::default();
+            let mut hash = H::default();
+            hash.update(OVERSIZE_DST_SALT);
+            hash.update(dst);
+            hash.finalize_xof().read(&mut hashed_dst);
+            Dst::new(&hashed_dst)
+        } else {
+            Dst::new(dst)
+        };
+
+        Self {
+            hash: H::default(),
+            dst,
+        }
+    }
+
+    fn update(&mut self, msg: &[u8]) {
+        self.hash.update(msg)
+    }
+
+    fn finalize(self, len_in_bytes: usize) -> Result<Self::Expander, generic_ec_core::Error> {
+        if len_in_bytes == 0 {
+            return Err(generic_ec_core::Error);
+        }
+        let len_in_bytes = u16::try_from(len_in_bytes).or(Err(generic_ec_core::Error))?;
+
+        let mut hash = self.hash;
+        hash.update(&len_in_bytes.to_be_bytes());
+        self.dst.update_hash(&mut hash);
+        Ok(XofExpander(hash.finalize_xof()))
+    }
+}
+
+/// Outputs uniform bytes produced by `expand_message_xof`
+pub struct XofExpander<R>(R);
+
+impl<R: XofReader> Expander for XofExpander<R> {
+    fn fill_bytes(&mut self, okm: &mut [u8]) {
+        self.0.read(okm)
+    }
+}
@@ -10,17 +10,21 @@
 
 #[cfg(feature = "ed25519")]
 pub mod ed25519;
+#[cfg(any(feature = "rust-crypto", feature = "ed25519"))]
+pub mod expand_msg;
 #[cfg(feature = "rust-crypto")]
 pub mod rust_crypto;
+#[cfg(feature = "rust-crypto")]
+pub mod try_and_increment;
 
 #[cfg(feature = "secp256k1")]
-pub use rust_crypto::Secp256k1;
+pub use rust_crypto::{Secp256k1, Secp256k1Sha512, Secp256k1Shake128, Secp256k1Shake256};
 
 #[cfg(feature = "secp256r1")]
-pub use rust_crypto::Secp256r1;
+pub use rust_crypto::{Secp256r1, Secp256r1Sha512, Secp256r1Shake128, Secp256r1Shake256};
 
 #[cfg(feature = "stark")]
-pub use rust_crypto::Stark;
+pub use rust_crypto::{Stark, StarkTryAndIncrement};
 
 #[cfg(feature = "ed25519")]
 pub use ed25519::Ed25519;
@@ -1,11 +1,15 @@
 use elliptic_curve::{
+    generic_array::GenericArray,
     group::cofactor::CofactorGroup,
-    hash2curve::{ExpandMsg, FromOkm, GroupDigest},
+    hash2curve::{ExpandMsg, FromOkm, GroupDigest, MapToCurve},
     CurveArithmetic,
 };
-use generic_ec_core::hash_to_curve::{HashToCurve, Tag};
+use generic_ec_core::coords::HasAffineXAndParity;
+use generic_ec_core::hash_to_curve::{EncodeToCurve, HashToCurve, IncrementalHashToCurve, Tag};
 
 use super::{RustCryptoCurve, RustCryptoPoint, RustCryptoScalar};
+use crate::expand_msg::{Expander, IncrementalExpandMsg, MessageHasher};
+use crate::try_and_increment::{self, TryAndIncrement};
 
 impl<C, X> HashToCurve for RustCryptoCurve<C, X>
 where
@@ -27,4 +31,121 @@ where
             .or(Err(generic_ec_core::Error))?;
         Ok(RustCryptoScalar(scalar))
     }
+
+    fn map_to_curve(u: &[u8]) -> Result<Self::Point, generic_ec_core::Error> {
+        // `from_okm` reduces a wide integer modulo field order, so we pad `u` with leading zeroes
+        let mut okm = GenericArray::<u8, <C::FieldElement as FromOkm>::Length>::default();
+        let offset = okm
+            .len()
+            .checked_sub(u.len())
+            .ok_or(generic_ec_core::Error)?;
+        okm[offset..].copy_from_slice(u);
+        let point = C::FieldElement::from_okm(&okm).map_to_curve();
+        Ok(RustCryptoPoint(point.clear_cofactor().into()))
+    }
+}
+
+impl<C, X> EncodeToCurve for RustCryptoCurve<C, X>
+where
+    C: CurveArithmetic + GroupDigest,
+    C::ProjectivePoint: CofactorGroup,
+    for<'a> X: ExpandMsg<'a>,
+    RustCryptoCurve<C, X>: generic_ec_core::Curve<Point = RustCryptoPoint<C>>,
+{
+    fn encode_to_curve(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Point, generic_ec_core::Error> {
+        let point = <C as GroupDigest>::encode_from_bytes::<X>(msgs, &[ctx.as_bytes()])
+            .or(Err(generic_ec_core::Error))?;
+        Ok(RustCryptoPoint(point))
+    }
+}
+
+impl<C, X> IncrementalHashToCurve for RustCryptoCurve<C, X>
+where
+    C: CurveArithmetic + GroupDigest,
+    C::ProjectivePoint: CofactorGroup,
+    C::Scalar: FromOkm,
+    for<'a> X: ExpandMsg<'a>,
+    X: IncrementalExpandMsg,
+    RustCryptoCurve<C, X>:
+        generic_ec_core::Curve<Point = RustCryptoPoint<C>, Scalar = RustCryptoScalar<C>>,
+{
+    type Hasher = X::Hasher;
+
+    fn new_hasher(ctx: Tag) -> Self::Hasher {
+        X::Hasher::new(ctx.as_bytes())
+    }
+
+    fn update_hasher(hasher: &mut Self::Hasher, msg: &[u8]) {
+        hasher.update(msg)
+    }
+
+    fn finalize_to_curve(hasher: Self::Hasher) -> Result<Self::Point, generic_ec_core::Error> {
+        // Mirrors `GroupDigest::hash_from_bytes`
+        let mut expander = hasher.finalize(2 * field_element_len::<C::FieldElement>())?;
+        let q0 = hash_to_field::<C::FieldElement>(&mut expander).map_to_curve();
+        let q1 = hash_to_field::<C::FieldElement>(&mut expander).map_to_curve();
+        Ok(RustCryptoPoint(
+            q0.clear_cofactor().into() + q1.clear_cofactor(),
+        ))
+    }
+
+    fn finalize_to_scalar(hasher: Self::Hasher) -> Result<Self::Scalar, generic_ec_core::Error> {
+        let mut expander = hasher.finalize(field_element_len::<C::Scalar>())?;
+        Ok(RustCryptoScalar(hash_to_field::<C::Scalar>(&mut expander)))
+    }
+}
+
+impl<C, X> HashToCurve for RustCryptoCurve<C, TryAndIncrement<X>>
+where
+    C: CurveArithmetic,
+    X: IncrementalExpandMsg,
+    RustCryptoCurve<C, TryAndIncrement<X>>: HasAffineXAndParity,
+{
+    fn hash_to_curve(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Point, generic_ec_core::Error> {
+        let mut hasher = Self::new_hasher(ctx);
+        msgs.iter().for_each(|msg| hasher.update(msg));
+        Self::finalize_to_curve(hasher)
+    }
+
+    fn hash_to_scalar(ctx: Tag, msgs: &[&[u8]]) -> Result<Self::Scalar, generic_ec_core::Error> {
+        let mut hasher = Self::new_hasher(ctx);
+        msgs.iter().for_each(|msg| hasher.update(msg));
+        Self::finalize_to_scalar(hasher)
+    }
+}
+
+impl<C, X> IncrementalHashToCurve for RustCryptoCurve<C, TryAndIncrement<X>>
+where
+    C: CurveArithmetic,
+    X: IncrementalExpandMsg,
+    RustCryptoCurve<C, TryAndIncrement<X>>: HasAffineXAndParity,
+{
+    type Hasher = X::Hasher;
+
+    fn new_hasher(ctx: Tag) -> Self::Hasher {
+        X::Hasher::new(ctx.as_bytes())
+    }
+
+    fn update_hasher(hasher: &mut Self::Hasher, msg: &[u8]) {
+        hasher.update(msg)
+    }
+
+    fn finalize_to_curve(hasher: Self::Hasher) -> Result<Self::Point, generic_ec_core::Error> {
+        try_and_increment::hash_to_curve::<Self, _>(hasher)
+    }
+
+    fn finalize_to_scalar(hasher: Self::Hasher) -> Result<Self::Scalar, generic_ec_core::Error> {
+        try_and_increment::hash_to_scalar::<Self, _>(hasher)
+    }
+}
+
+fn field_element_len<F: FromOkm>() -> usize {
+    use elliptic_curve::generic_array::typenum::Unsigned;
+    F::Length::to_usize()
+}
+
+fn hash_to_field<F: FromOkm>(expander: &mut impl Expander) -> F {
+    let mut okm = GenericArray::<u8, F::Length>::default();
+    expander.fill_bytes(&mut okm);
+    F::from_okm(&okm)
 }
@@ -4,25 +4,43 @@ use core::marker::PhantomData;
 use core::ops::Mul;
 
 use elliptic_curve::group::cofactor::CofactorGroup;
-use elliptic_curve::hash2curve::ExpandMsgXmd;
 use elliptic_curve::ops::Reduce;
 use elliptic_curve::sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint};
 use elliptic_curve::{CurveArithmetic, FieldBytesSize, ScalarPrimitive};
-use generic_ec_core::{CompressedEncoding, Curve, IntegerEncoding, UncompressedEncoding};
+use generic_ec_core::{
+    CompressedEncoding, Curve, IntegerEncoding, MulByCofactor, UncompressedEncoding,
+};
 use subtle::{ConditionallySelectable, ConstantTimeEq};
 use zeroize::{DefaultIsZeroes, Zeroize};
 
 #[cfg(any(feature = "secp256k1", feature = "secp256r1", feature = "stark"))]
 use sha2::Sha256;
+#[cfg(any(feature = "secp256k1", feature = "secp256r1"))]
+use sha2::Sha512;
+#[cfg(any(feature = "secp256k1", feature = "secp256r1"))]
+use sha3::{Shake128, Shake256};
 
 pub use self::{curve_name::CurveName, point::RustCryptoPoint, scalar::RustCryptoScalar};
 
+/// Message expanders that can be used to instantiate [`RustCryptoCurve`]
+///
+/// Expander determines which hash function is used by hash to curve primitives. Any
+/// hash function supported by the expander can be plugged in, e.g.
+/// `RustCryptoCurve<k256::Secp256k1, ExpandMsgXmd<sha2::Sha384>>`.
+pub use elliptic_curve::hash2curve::{ExpandMsgXmd, ExpandMsgXof};
+
 mod affine_coords;
 mod curve_name;
 mod hash_to_curve;
 mod point;
 mod scalar;
 
+/// Curve implemented on top of RustCrypto library
+///
+/// `C` is a curve from RustCrypto ecosystem, and `X` is a message expander that determines
+/// which hash function is used by hash to curve primitives (see [`ExpandMsgXmd`] and
+/// [`ExpandMsgXof`]). Curves instantiated with different expanders share the same points
+/// and scalars representation, the only difference is the hash to curve suite.
 pub struct RustCryptoCurve<C, X> {
     _ph: PhantomData<fn() -> (C, X)>,
 }
@@ -58,8 +76,49 @@ pub type Secp256k1 = RustCryptoCurve<k256::Secp256k1, ExpandMsgXmd<Sha256>>;
 #[cfg(feature = "secp256r1")]
 pub type Secp256r1 = RustCryptoCurve<p256::NistP256, ExpandMsgXmd<Sha256>>;
 
+/// secp256k1 curve with hash to curve suite based on SHA-512
+///
+/// Same as [`Secp256k1`] but hash to curve primitives use `expand_message_xmd` with SHA-512
+#[cfg(feature = "secp256k1")]
+pub type Secp256k1Sha512 = RustCryptoCurve<k256::Secp256k1, ExpandMsgXmd<Sha512>>;
+/// secp256k1 curve with hash to curve suite based on SHAKE128
+///
+/// Same as [`Secp256k1`] but hash to curve primitives use `expand_message_xof` with SHAKE128
+#[cfg(feature = "secp256k1")]
+pub type Secp256k1Shake128 = RustCryptoCurve<k256::Secp256k1, ExpandMsgXof<Shake128>>;
+/// secp256k1 curve with hash to curve suite based on SHAKE256
+///
+/// Same as [`Secp256k1`] but hash to curve primitives use `expand_message_xof` with SHAKE256
+#[cfg(feature = "secp256k1")]
+pub type Secp256k1Shake256 = RustCryptoCurve<k256::Secp256k1, ExpandMsgXof<Shake256>>;
+
+/// secp256r1 curve with hash to curve suite based on SHA-512
+///
+/// Same as [`Secp256r1`] but hash to curve primitives use `expand_message_xmd` with SHA-512
+#[cfg(feature = "secp256r1")]
+pub type Secp256r1Sha512 = RustCryptoCurve<p256::NistP256, ExpandMsgXmd<Sha512>>;
+/// secp256r1 curve with hash to curve suite based on SHAKE128
+///
+/// Same as [`Secp256r1`] but hash to curve primitives use `expand_message_xof` with SHAKE128
+#[cfg(feature = "secp256r1")]
+pub type Secp256r1Shake128 = RustCryptoCurve<p256::NistP256, ExpandMsgXof<Shake128>>;
+/// secp256r1 curve with hash to curve suite based on SHAKE256
+///
+/// Same as [`Secp256r1`] but hash to curve primitives use `expand_message_xof` with SHAKE256
+#[cfg(feature = "secp256r1")]
+pub type Secp256r1Shake256 = RustCryptoCurve<p256::NistP256, ExpandMsgXof<Shake256>>;
+
 #[cfg(feature = "stark")]
 pub type Stark = RustCryptoCurve<stark_curve::StarkCurve, ExpandMsgXmd<Sha256>>;
+/// Stark curve with hash to curve implemented via try-and-increment method
+///
+/// Same as [`Stark`] but also implements hash to curve primitives (see
+/// [`TryAndIncrement`](crate::try_and_increment::TryAndIncrement))
+#[cfg(feature = "stark")]
+pub type StarkTryAndIncrement = RustCryptoCurve<
+    stark_curve::StarkCurve,
+    crate::try_and_increment::TryAndIncrement<ExpandMsgXmd<Sha256>>,
+>;
 
 impl<C, X> Curve for RustCryptoCurve<C, X>
 where
@@ -94,6 +153,17 @@ where
     type CoordinateArray = elliptic_curve::FieldBytes<C>;
 }
 
+impl<C, X> MulByCofactor for RustCryptoCurve<C, X>
+where
+    C: CurveArithmetic,
+    C::ProjectivePoint: CofactorGroup,
+    RustCryptoCurve<C, X>: Curve<Point = RustCryptoPoint<C>>,
+{
+    fn mul_by_cofactor(point: &Self::Point) -> Self::Point {
+        RustCryptoPoint(point.0.clear_cofactor().into())
+    }
+}
+
 impl<C: CurveName, X> fmt::Debug for RustCryptoCurve<C, X> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("RustCryptoCurve")
@@ -149,16 +219,19 @@ impl<C, X> Default for RustCryptoCurve<C, X> {
 mod tests {
     use generic_ec_core::{
         coords::{HasAffineX, HasAffineXAndParity, HasAffineXY},
-        hash_to_curve::HashToCurve,
+        hash_to_curve::{EncodeToCurve, HashToCurve},
         Curve,
     };
 
-    use super::{Secp256k1, Secp256r1};
+    use super::{
+        Secp256k1, Secp256k1Sha512, Secp256k1Shake128, Secp256k1Shake256, Secp256r1,
+        Secp256r1Sha512, Secp256r1Shake128, Secp256r1Shake256,
+    };
 
     /// Asserts that `E` implements `Curve`
     fn _impls_curve<E: Curve>() {}
     fn _exposes_affine_coords<E: HasAffineX + HasAffineXAndParity + HasAffineXY>() {}
-    fn _impls_hash_to_curve<E: HashToCurve>() {}
+    fn _impls_hash_to_curve<E: HashToCurve + EncodeToCurve>() {}
 
     fn _curves_impl_trait() {
         _impls_curve::<Secp256k1>();
@@ -169,5 +242,12 @@ mod tests {
 
         _impls_hash_to_curve::<Secp256k1>();
         _impls_hash_to_curve::<Secp256r1>();
+
+        _impls_hash_to_curve::<Secp256k1Sha512>();
+        _impls_hash_to_curve::<Secp256k1Shake128>();
+        _impls_hash_to_curve::<Secp256k1Shake256>();
+        _impls_hash_to_curve::<Secp256r1Sha512>();
+        _impls_hash_to_curve::<Secp256r1Shake128>();
+        _impls_hash_to_curve::<Secp256r1Shake256>();
     }
 }
@@ -7,7 +7,7 @@ use elliptic_curve::{
     CurveArithmetic, FieldBytesSize, Group,
 };
 use generic_ec_core::*;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use zeroize::Zeroize;
 
 pub struct RustCryptoPoint<E: CurveArithmetic>(pub E::ProjectivePoint);
@@ -153,6 +153,26 @@ where
         Option::from(E::AffinePoint::from_encoded_point(&encoded_point))
             .map(|point: E::AffinePoint| Self(point.into()))
     }
+
+    fn ct_decode(bytes: &[u8]) -> CtOption<Self> {
+        // This is the only representation of identity point recognized by `elliptic-curve`
+        // library, we handle it separately
+        let all_zero = bytes
+            .iter()
+            .fold(Choice::from(1), |acc, byte| acc & byte.ct_eq(&0));
+
+        // Length and tag of encoded point are considered public
+        let decoded = match EncodedPoint::<E>::from_bytes(bytes) {
+            Ok(encoded_point) => E::AffinePoint::from_encoded_point(&encoded_point)
+                .map(|point: E::AffinePoint| Self(point.into())),
+            Err(_) => CtOption::new(Self::zero(), Choice::from(0)),
+        };
+
+        let is_some = decoded.is_some() | all_zero;
+        let point =
+            Self::conditional_select(&decoded.unwrap_or(Self::zero()), &Self::zero(), all_zero);
+        CtOption::new(point, is_some)
+    }
 }
 
 impl<E> Clone for RustCryptoPoint<E>
@@ -196,4 +196,14 @@ where
         let scalar_core: Option<ScalarPrimitive<E>> = ScalarPrimitive::<E>::new(uint).into();
         Some(Self(E::Scalar::from(scalar_core?)))
     }
+
+    fn ct_from_be_bytes_exact(bytes: &Self::Bytes) -> CtOption<Self> {
+        let uint = E::Uint::from_be_byte_array(bytes.clone());
+        ScalarPrimitive::<E>::new(uint).map(|s| Self(E::Scalar::from(s)))
+    }
+
+    fn ct_from_le_bytes_exact(bytes: &Self::Bytes) -> CtOption<Self> {
+        let uint = E::Uint::from_le_byte_array(bytes.clone());
+        ScalarPrimitive::<E>::new(uint).map(|s| Self(E::Scalar::from(s)))
+    }
 }
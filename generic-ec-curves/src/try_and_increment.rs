@@ -0,0 +1,119 @@
+//! Try-and-increment hash to curve
+//!
+//! Generic hash to curve that works for any prime order curve which can decode a point
+//! from its affine $x$ coordinate and parity of $y$, so it can be used for curves which
+//! don't have SSWU (or any other) mapping defined. A candidate $x$ coordinate (and $y$
+//! parity) is derived from `expand_message(msg || ctr)`, where `ctr` is a single byte
+//! counter that is incremented until the candidate corresponds to a valid point.
+//!
+//! Note that this method is not constant-time: number of attempts depends on the message.
+//! Don't use it for hashing secret data.
+
+use core::marker::PhantomData;
+
+use generic_ec_core::coords::{HasAffineXAndParity, Parity};
+use generic_ec_core::{
+    Additive, ByteArray, Curve, Error, IntegerEncoding, Multiplicative, One, SmallFactor, Zero,
+};
+
+use crate::expand_msg::{Expander, IncrementalExpandMsg, MessageHasher};
+
+/// Message expander `X` used in try-and-increment mode
+///
+/// `RustCryptoCurve<C, TryAndIncrement<X>>` implements hash to curve via try-and-increment method
+/// using message expander `X`.
+pub struct TryAndIncrement<X>(PhantomData<X>);
+
+impl<X: IncrementalExpandMsg> IncrementalExpandMsg for TryAndIncrement<X> {
+    type Hasher = X::Hasher;
+}
+
+/// Hashes the message absorbed by `hasher` into a point via try-and-increment method
+///
+/// Candidate $x$ coordinates are truncated to bit length of the group order, so the curve
+/// is expected to be of prime order. Returns error if none of 256 attempts succeeded, which
+/// happens with negligible probability.
+pub fn hash_to_curve<E, H>(hasher: H) -> Result<E::Point, Error>
+where
+    E: HasAffineXAndParity,
+    H: MessageHasher,
+{
+    let coord_len = E::CoordinateArray::zeroes().as_ref().len();
+    let excess_bits = coord_len * 8 - scalar_bits::<E>().min(coord_len * 8);
+
+    for ctr in 0..=u8::MAX {
+        let mut hasher = hasher.clone();
+        hasher.update(&[ctr]);
+        let mut expander = hasher.finalize(coord_len + 1)?;
+
+        let mut x = E::CoordinateArray::zeroes();
+        expander.fill_bytes(x.as_mut());
+        // Truncate excess bits, otherwise most of candidates will be larger than field order
+        // for curves like Stark
+        let x_bytes = x.as_mut();
+        x_bytes[..excess_bits / 8].fill(0);
+        if let Some(byte) = x_bytes.get_mut(excess_bits / 8) {
+            *byte &= 0xff >> (excess_bits % 8);
+        }
+
+        let mut parity = [0u8];
+        expander.fill_bytes(&mut parity);
+        let parity = if parity[0] & 1 == 1 {
+            Parity::Odd
+        } else {
+            Parity::Even
+        };
+
+        if let Some(point) = E::from_x_and_parity(&x, parity) {
+            if bool::from(point.is_torsion_free() & !Zero::is_zero(&point)) {
+                return Ok(point);
+            }
+        }
+    }
+
+    Err(Error)
+}
+
+/// Hashes the message absorbed by `hasher` into a scalar
+///
+/// Expands the message into twice as many bytes as needed to encode a scalar, and reduces
+/// it modulo group order.
+pub fn hash_to_scalar<E, H>(hasher: H) -> Result<E::Scalar, Error>
+where
+    E: Curve,
+    H: MessageHasher,
+{
+    let mut expander = hasher.finalize(2 * E::ScalarArray::zeroes().as_ref().len())?;
+    let mut hi = E::ScalarArray::zeroes();
+    let mut lo = E::ScalarArray::zeroes();
+    expander.fill_bytes(hi.as_mut());
+    expander.fill_bytes(lo.as_mut());
+
+    // `shift = 2^(8 * scalar_len) mod q`
+    let mut shift_div_0x100 = E::ScalarArray::zeroes();
+    let mut scalar_0x100 = E::ScalarArray::zeroes();
+    if let Some(byte) = shift_div_0x100.as_mut().first_mut() {
+        *byte = 1;
+    }
+    if let Some(byte) = scalar_0x100.as_mut().iter_mut().rev().nth(1) {
+        *byte = 1;
+    }
+    let shift = E::Scalar::mul(
+        &E::Scalar::from_be_bytes(&shift_div_0x100),
+        &E::Scalar::from_be_bytes(&scalar_0x100),
+    );
+
+    let hi = E::Scalar::mul(&E::Scalar::from_be_bytes(&hi), &shift);
+    Ok(E::Scalar::add(&hi, &E::Scalar::from_be_bytes(&lo)))
+}
+
+/// Bit length of the group order
+fn scalar_bits<E: Curve>() -> usize {
+    // `q - 1`
+    let max = E::Scalar::negate(&E::Scalar::one()).to_be_bytes();
+    let max = max.as_ref();
+    match max.iter().position(|byte| *byte != 0) {
+        Some(i) => (max.len() - i) * 8 - max[i].leading_zeros() as usize,
+        None => 0,
+    }
+}
@@ -0,0 +1,240 @@
+//! # Procedural macros for `generic-ec`
+//!
+//! This crate implements compile-time validation of scalar and point literals. It's not supposed
+//! to be used directly, use `scalar!` and `point!` macros exported by [`generic-ec` crate] instead.
+//!
+//! [`generic-ec` crate]: https://docs.rs/generic-ec
+
+#![forbid(unsafe_code)]
+// Without curve features, there are no curves to validate literals on
+#![cfg_attr(
+    not(any(
+        feature = "secp256k1",
+        feature = "secp256r1",
+        feature = "stark",
+        feature = "ed25519"
+    )),
+    allow(dead_code)
+)]
+
+use generic_ec_core::{
+    ByteArray, CompressedEncoding, Curve, Decode, IntegerEncoding, OnCurve, SmallFactor,
+    UncompressedEncoding,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+
+/// Validates scalar literal and expands into expression evaluating to `Scalar<E>`
+///
+/// Input: `$crate, $curve, $literal`
+#[doc(hidden)]
+#[proc_macro]
+pub fn scalar_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as Input);
+    let bytes = match parse_integer(&input.literal.value()) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            return syn::Error::new_spanned(&input.literal, err)
+                .into_compile_error()
+                .into()
+        }
+    };
+    let table = literal_table(&input.krate, &bytes, |curve| curve.scalar);
+    let Input { krate, curve, .. } = input;
+    quote! {{
+        struct Literal;
+        impl #krate::__private::LiteralTable for Literal {
+            const TABLE: &'static [#krate::__private::Literal] = &[#(#table),*];
+        }
+        #krate::__private::scalar_from_validated_be_bytes::<#curve, Literal>()
+    }}
+    .into()
+}
+
+/// Validates point literal and expands into expression evaluating to `Point<E>`
+///
+/// Input: `$crate, $curve, $literal`
+#[doc(hidden)]
+#[proc_macro]
+pub fn point_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as Input);
+    let literal = input.literal.value();
+    let bytes = match decode_hex(strip_hex_prefix(&literal).unwrap_or(&literal)) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            return syn::Error::new_spanned(&input.literal, err)
+                .into_compile_error()
+                .into()
+        }
+    };
+    let table = literal_table(&input.krate, &bytes, |curve| curve.point);
+    let Input { krate, curve, .. } = input;
+    quote! {{
+        struct Literal;
+        impl #krate::__private::LiteralTable for Literal {
+            const TABLE: &'static [#krate::__private::Literal] = &[#(#table),*];
+        }
+        #krate::__private::point_from_validated_bytes::<#curve, Literal>()
+    }}
+    .into()
+}
+
+struct Input {
+    krate: TokenStream,
+    curve: syn::Type,
+    literal: syn::LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // `$crate` is passed as a single identifier
+        let krate: proc_macro2::TokenTree = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let curve = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let literal = input.parse()?;
+        input.parse::<Option<syn::Token![,]>>()?;
+        Ok(Self {
+            krate: krate.into(),
+            curve,
+            literal,
+        })
+    }
+}
+
+type Validate = fn(&[u8]) -> Result<Vec<u8>, String>;
+
+/// Validation of literal on a specific curve
+struct CurveValidate {
+    curve_name: &'static str,
+    scalar: Validate,
+    point: Validate,
+}
+
+impl CurveValidate {
+    fn new<E: Curve>() -> Self {
+        Self {
+            curve_name: E::CURVE_NAME,
+            scalar: validate_scalar::<E>,
+            point: validate_point::<E>,
+        }
+    }
+}
+
+/// Curves enabled via crate features
+///
+/// Proc-macro can't resolve the curve type, so literal is validated on each of these curves.
+/// Then, `generic-ec` picks the validation result by `E::CURVE_NAME` at compile time.
+fn curves() -> Vec<CurveValidate> {
+    vec![
+        #[cfg(feature = "secp256k1")]
+        CurveValidate::new::<generic_ec_curves::Secp256k1>(),
+        #[cfg(feature = "secp256r1")]
+        CurveValidate::new::<generic_ec_curves::Secp256r1>(),
+        #[cfg(feature = "stark")]
+        CurveValidate::new::<generic_ec_curves::Stark>(),
+        #[cfg(feature = "ed25519")]
+        CurveValidate::new::<generic_ec_curves::Ed25519>(),
+    ]
+}
+
+/// Validates literal on every enabled curve, returns entries of `__private::Literal` table
+fn literal_table(
+    krate: &TokenStream,
+    bytes: &[u8],
+    validate: fn(&CurveValidate) -> Validate,
+) -> Vec<TokenStream> {
+    curves()
+        .iter()
+        .map(|curve| {
+            let curve_name = curve.curve_name;
+            match validate(curve)(bytes) {
+                Ok(bytes) => quote! {
+                    #krate::__private::Literal {
+                        curve: #curve_name,
+                        bytes: &[#(#bytes),*],
+                        error: None,
+                    }
+                },
+                Err(err) => quote! {
+                    #krate::__private::Literal {
+                        curve: #curve_name,
+                        bytes: &[],
+                        error: Some(#err),
+                    }
+                },
+            }
+        })
+        .collect()
+}
+
+/// Parses an integer from decimal or `0x`-prefixed hex, returns its big-endian bytes
+fn parse_integer(literal: &str) -> Result<Vec<u8>, String> {
+    if let Some(hex) = strip_hex_prefix(literal) {
+        return decode_hex(hex);
+    }
+    if literal.is_empty() || !literal.bytes().all(|c| c.is_ascii_digit()) {
+        return Err("scalar must be a decimal integer or hex prefixed with `0x`".into());
+    }
+    let mut bytes = Vec::new();
+    for digit in literal.bytes() {
+        let mut carry = u16::from(digit - b'0');
+        for byte in bytes.iter_mut().rev() {
+            let value = u16::from(*byte) * 10 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            bytes.insert(0, carry as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+/// Checks that integer is a valid scalar, returns its big-endian bytes padded to scalar size
+fn validate_scalar<E: Curve>(integer: &[u8]) -> Result<Vec<u8>, String> {
+    let mut bytes = E::ScalarArray::zeroes();
+    let len = bytes.as_ref().len();
+    let integer = &integer[integer.iter().take_while(|b| **b == 0).count()..];
+    if integer.len() > len {
+        return Err("scalar is not less than group order".into());
+    }
+    bytes.as_mut()[len - integer.len()..].copy_from_slice(integer);
+
+    E::Scalar::from_be_bytes_exact(&bytes).ok_or("scalar is not less than group order")?;
+    Ok(bytes.as_ref().to_vec())
+}
+
+/// Checks that bytes are a canonical compressed or uncompressed encoding of a valid point
+fn validate_point<E: Curve>(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let point = E::Point::decode(bytes).ok_or("invalid point")?;
+    if !bool::from(point.is_on_curve()) {
+        return Err("point is not on curve".into());
+    }
+    if !bool::from(point.is_torsion_free()) {
+        return Err("point has torsion component".into());
+    }
+    let is_canonical = point.to_bytes_compressed().as_ref() == bytes
+        || point.to_bytes_uncompressed().as_ref() == bytes;
+    if !is_canonical {
+        return Err("point encoding is not canonical".into());
+    }
+    Ok(bytes.to_vec())
+}
+
+fn strip_hex_prefix(s: &str) -> Option<&str> {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))
+}
+
+// `usize::is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn decode_hex(s: &str) -> Result<Vec<u8>, String> {
+    if s.is_empty() || s.len() % 2 != 0 || !s.is_ascii() {
+        return Err("malformed hex".into());
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| "malformed hex".into()))
+        .collect()
+}
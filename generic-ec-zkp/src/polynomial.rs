@@ -281,6 +281,10 @@ mod requires_alloc {
         }
     }
 
+    /// Deserializes a polynomial of any degree
+    ///
+    /// Use [`Polynomial::deserialize_with_max_degree`] or [`BoundedPolynomial`] when polynomial
+    /// is received from untrusted party.
     #[cfg(feature = "serde")]
     impl<'de, C: IsZero> serde::Deserialize<'de> for Polynomial<C>
     where
@@ -294,6 +298,149 @@ mod requires_alloc {
             Ok(Self::from_coefs(coefs))
         }
     }
+
+    #[cfg(feature = "serde")]
+    impl<C: IsZero> Polynomial<C> {
+        /// Deserializes a polynomial of degree at most `max_degree`
+        ///
+        /// Default [`Deserialize`](serde::Deserialize) implementation accepts a list of coefficients
+        /// of any length. If polynomial comes from untrusted source, it can be used to make
+        /// us allocate and evaluate an arbitrarily large polynomial. This function returns
+        /// an error as soon as more than `max_degree + 1` coefficients are encountered.
+        ///
+        /// Note that the bound is applied to the number of serialized coefficients, so
+        /// a list with more than `max_degree + 1` elements is rejected even if it has
+        /// trailing zeroes. Serialized polynomials never have trailing zeroes.
+        ///
+        /// Use [`BoundedPolynomial`] to enforce the bound within `#[derive(Deserialize)]`.
+        ///
+        /// ## Example
+        /// ```rust
+        /// use generic_ec::{Scalar, curves::Secp256k1};
+        /// use generic_ec_zkp::polynomial::Polynomial;
+        /// # use rand_core::OsRng;
+        ///
+        /// let f = Polynomial::<Scalar<Secp256k1>>::sample(&mut OsRng, 3);
+        /// let serialized = serde_json::to_value(&f)?;
+        ///
+        /// let g = Polynomial::<Scalar<Secp256k1>>::deserialize_with_max_degree(&serialized, 3)?;
+        /// assert_eq!(f.coefs(), g.coefs());
+        ///
+        /// let too_large = Polynomial::<Scalar<Secp256k1>>::deserialize_with_max_degree(&serialized, 2);
+        /// assert!(too_large.is_err());
+        /// # Ok::<_, serde_json::Error>(())
+        /// ```
+        pub fn deserialize_with_max_degree<'de, D>(
+            deserializer: D,
+            max_degree: usize,
+        ) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+            C: serde::Deserialize<'de>,
+        {
+            let coefs = generic_ec::serde::deserialize_bounded_vec(
+                deserializer,
+                max_degree.saturating_add(1),
+            )?;
+            Ok(Self::from_coefs(coefs))
+        }
+    }
+
+    /// Polynomial of degree at most `MAX`
+    ///
+    /// Serialized in the same way as [`Polynomial`], but deserialization fails if polynomial
+    /// has more than `MAX + 1` coefficients (see [`Polynomial::deserialize_with_max_degree`]).
+    /// Use it instead of `Polynomial` when polynomial is received from untrusted party.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use generic_ec_zkp::polynomial::{BoundedPolynomial, Polynomial};
+    /// # use rand_core::OsRng;
+    ///
+    /// let f = Polynomial::<Scalar<Secp256k1>>::sample(&mut OsRng, 3);
+    /// assert!(BoundedPolynomial::<_, 2>::new(f.clone()).is_none());
+    ///
+    /// let f = BoundedPolynomial::<_, 3>::new(f).unwrap();
+    /// assert_eq!(f.as_polynomial().degree(), 3);
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct BoundedPolynomial<C, const MAX: usize>(Polynomial<C>);
+
+    impl<C, const MAX: usize> BoundedPolynomial<C, MAX> {
+        /// Wraps a polynomial
+        ///
+        /// Returns `None` if polynomial degree is greater than `MAX`
+        pub fn new(polynomial: Polynomial<C>) -> Option<Self> {
+            if polynomial.coefs.len() > MAX.saturating_add(1) {
+                None
+            } else {
+                Some(Self(polynomial))
+            }
+        }
+
+        /// Returns wrapped polynomial
+        pub fn as_polynomial(&self) -> &Polynomial<C> {
+            &self.0
+        }
+
+        /// Unwraps polynomial
+        pub fn into_polynomial(self) -> Polynomial<C> {
+            self.0
+        }
+    }
+
+    impl<C, const MAX: usize> AsRef<Polynomial<C>> for BoundedPolynomial<C, MAX> {
+        fn as_ref(&self) -> &Polynomial<C> {
+            &self.0
+        }
+    }
+
+    impl<C, const MAX: usize> From<BoundedPolynomial<C, MAX>> for Polynomial<C> {
+        fn from(polynomial: BoundedPolynomial<C, MAX>) -> Self {
+            polynomial.0
+        }
+    }
+
+    /// Bounded polynomial is digested in the same way as [`Polynomial`]
+    #[cfg(feature = "udigest")]
+    impl<C, const MAX: usize> udigest::Digestable for BoundedPolynomial<C, MAX>
+    where
+        C: udigest::Digestable,
+    {
+        fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
+        where
+            B: udigest::Buffer,
+        {
+            self.0.unambiguously_encode(encoder)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<C, const MAX: usize> serde::Serialize for BoundedPolynomial<C, MAX>
+    where
+        C: serde::Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de, C: IsZero, const MAX: usize> serde::Deserialize<'de> for BoundedPolynomial<C, MAX>
+    where
+        C: serde::Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            Polynomial::deserialize_with_max_degree(deserializer, MAX).map(Self)
+        }
+    }
 }
 
 use generic_ec::{Curve, NonZero, Scalar};
@@ -0,0 +1,817 @@
+//! Points and scalars on a curve chosen at runtime
+//!
+//! [`Point<E>`], [`Scalar<E>`] and [`SecretScalar<E>`] require the curve to be known at compile time.
+//! When the curve is only known at runtime (e.g. it's read from a config or a database),
+//! [`AnyPoint`], [`AnyScalar`] and [`AnySecretScalar`] can be used instead. They wrap a point/scalar
+//! on any of the curves enabled via crate features, listed in [`AnyCurve`].
+//!
+//! Arithmetic operations on values from different curves fail with [`CurveMismatch`] error.
+//!
+//! ```rust
+//! use generic_ec::any::{AnyCurve, AnyPoint, AnyScalar};
+//! # let mut rng = rand::rngs::OsRng;
+//!
+//! let curve: AnyCurve = "secp256k1".parse()?;
+//! let s = AnyScalar::random(curve, &mut rng);
+//! let p = AnyPoint::generator(curve).try_mul(&s)?;
+//! assert_eq!(p.curve(), AnyCurve::Secp256k1);
+//!
+//! // Mixing curves is an error
+//! let q = AnyPoint::generator(AnyCurve::Ed25519);
+//! assert!(p.try_add(&q).is_err());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ## Serialization
+//!
+//! With `serde` feature enabled, `AnyPoint`, `AnyScalar` and `AnySecretScalar` are serialized in
+//! exactly the same way as the wrapped `Point<E>`, `Scalar<E>` and `SecretScalar<E>`. When
+//! deserializing, the curve is determined by `curve` field of serialized value. Note that `curve`
+//! field must precede the point/scalar field, which is always the case for values serialized by
+//! this crate.
+//!
+//! ```rust
+//! use generic_ec::{Point, curves::Secp256r1, any::{AnyCurve, AnyPoint}};
+//!
+//! let json = serde_json::to_string(&Point::<Secp256r1>::generator().to_point())?;
+//! let point: AnyPoint = serde_json::from_str(&json)?;
+//! assert_eq!(point.curve(), AnyCurve::Secp256r1);
+//! assert_eq!(point, AnyPoint::generator(AnyCurve::Secp256r1));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    errors::{CurveMismatch, InvalidPoint, InvalidScalar, UnknownCurve},
+    Point, Scalar, SecretScalar,
+};
+
+/// Matches `$value` against every variant of `$ty`, binding the wrapped value to `$x`
+macro_rules! dispatch {
+    ($ty:ident, $value:expr, |$x:ident| $body:expr) => {
+        match $value {
+            #[cfg(feature = "curve-secp256k1")]
+            $ty::Secp256k1($x) => $body,
+            #[cfg(feature = "curve-secp256r1")]
+            $ty::Secp256r1($x) => $body,
+            #[cfg(feature = "curve-stark")]
+            $ty::Stark($x) => $body,
+            #[cfg(feature = "curve-ed25519")]
+            $ty::Ed25519($x) => $body,
+        }
+    };
+}
+
+/// Matches two values on the same curve, or returns [`CurveMismatch`] error
+macro_rules! dispatch2 {
+    ($ty_a:ident, $a:expr, $ty_b:ident, $b:expr, |$x:ident, $y:ident| $body:expr) => {
+        match ($a, $b) {
+            #[cfg(feature = "curve-secp256k1")]
+            ($ty_a::Secp256k1($x), $ty_b::Secp256k1($y)) => Ok($body),
+            #[cfg(feature = "curve-secp256r1")]
+            ($ty_a::Secp256r1($x), $ty_b::Secp256r1($y)) => Ok($body),
+            #[cfg(feature = "curve-stark")]
+            ($ty_a::Stark($x), $ty_b::Stark($y)) => Ok($body),
+            #[cfg(feature = "curve-ed25519")]
+            ($ty_a::Ed25519($x), $ty_b::Ed25519($y)) => Ok($body),
+            #[allow(unreachable_patterns)]
+            (a, b) => Err(CurveMismatch {
+                expected: a.curve(),
+                got: b.curve(),
+            }),
+        }
+    };
+}
+
+/// Matches `$curve`, defining `$E` as the corresponding curve type
+macro_rules! with_curve {
+    ($curve:expr, |$E:ident| $body:expr) => {
+        match $curve {
+            #[cfg(feature = "curve-secp256k1")]
+            AnyCurve::Secp256k1 => {
+                type $E = crate::curves::Secp256k1;
+                $body
+            }
+            #[cfg(feature = "curve-secp256r1")]
+            AnyCurve::Secp256r1 => {
+                type $E = crate::curves::Secp256r1;
+                $body
+            }
+            #[cfg(feature = "curve-stark")]
+            AnyCurve::Stark => {
+                type $E = crate::curves::Stark;
+                $body
+            }
+            #[cfg(feature = "curve-ed25519")]
+            AnyCurve::Ed25519 => {
+                type $E = crate::curves::Ed25519;
+                $body
+            }
+        }
+    };
+}
+
+/// Curve chosen at runtime
+///
+/// Lists all curves enabled via crate features. Converts from/to [curve name](crate::Curve::CURVE_NAME),
+/// e.g. `"secp256k1"`, which is also how it's serialized.
+///
+/// Note that curves that only differ in hash to curve/scalar primitive (like
+/// [`Secp256k1Sha512`](crate::curves::Secp256k1Sha512)) share the same name, so the
+/// default one (like [`Secp256k1`](crate::curves::Secp256k1)) is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AnyCurve {
+    /// [`Secp256k1`](crate::curves::Secp256k1) curve
+    #[cfg(feature = "curve-secp256k1")]
+    Secp256k1,
+    /// [`Secp256r1`](crate::curves::Secp256r1) curve
+    #[cfg(feature = "curve-secp256r1")]
+    Secp256r1,
+    /// [`Stark`](crate::curves::Stark) curve
+    #[cfg(feature = "curve-stark")]
+    Stark,
+    /// [`Ed25519`](crate::curves::Ed25519) curve
+    #[cfg(feature = "curve-ed25519")]
+    Ed25519,
+}
+
+impl AnyCurve {
+    /// All enabled curves
+    pub const ALL: &'static [AnyCurve] = &[
+        #[cfg(feature = "curve-secp256k1")]
+        AnyCurve::Secp256k1,
+        #[cfg(feature = "curve-secp256r1")]
+        AnyCurve::Secp256r1,
+        #[cfg(feature = "curve-stark")]
+        AnyCurve::Stark,
+        #[cfg(feature = "curve-ed25519")]
+        AnyCurve::Ed25519,
+    ];
+
+    /// Names of all enabled curves
+    pub const NAMES: &'static [&'static str] = &[
+        #[cfg(feature = "curve-secp256k1")]
+        <crate::curves::Secp256k1 as crate::Curve>::CURVE_NAME,
+        #[cfg(feature = "curve-secp256r1")]
+        <crate::curves::Secp256r1 as crate::Curve>::CURVE_NAME,
+        #[cfg(feature = "curve-stark")]
+        <crate::curves::Stark as crate::Curve>::CURVE_NAME,
+        #[cfg(feature = "curve-ed25519")]
+        <crate::curves::Ed25519 as crate::Curve>::CURVE_NAME,
+    ];
+
+    /// Curve name, e.g. `"secp256k1"`
+    pub fn name(&self) -> &'static str {
+        with_curve!(self, |E| <E as crate::Curve>::CURVE_NAME)
+    }
+
+    /// Looks up a curve by its name
+    ///
+    /// Returns `None` if curve is unknown or not enabled
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|curve| curve.name() == name)
+    }
+}
+
+impl fmt::Display for AnyCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for AnyCurve {
+    type Err = UnknownCurve;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or(UnknownCurve)
+    }
+}
+
+/// Point on a curve chosen at runtime
+///
+/// See [module level docs](self) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnyPoint {
+    /// Point on secp256k1 curve
+    #[cfg(feature = "curve-secp256k1")]
+    Secp256k1(Point<crate::curves::Secp256k1>),
+    /// Point on secp256r1 curve
+    #[cfg(feature = "curve-secp256r1")]
+    Secp256r1(Point<crate::curves::Secp256r1>),
+    /// Point on stark curve
+    #[cfg(feature = "curve-stark")]
+    Stark(Point<crate::curves::Stark>),
+    /// Point on ed25519 curve
+    #[cfg(feature = "curve-ed25519")]
+    Ed25519(Point<crate::curves::Ed25519>),
+}
+
+impl AnyPoint {
+    /// Curve the point is on
+    pub fn curve(&self) -> AnyCurve {
+        match self {
+            #[cfg(feature = "curve-secp256k1")]
+            Self::Secp256k1(_) => AnyCurve::Secp256k1,
+            #[cfg(feature = "curve-secp256r1")]
+            Self::Secp256r1(_) => AnyCurve::Secp256r1,
+            #[cfg(feature = "curve-stark")]
+            Self::Stark(_) => AnyCurve::Stark,
+            #[cfg(feature = "curve-ed25519")]
+            Self::Ed25519(_) => AnyCurve::Ed25519,
+        }
+    }
+
+    /// Curve generator
+    pub fn generator(curve: AnyCurve) -> Self {
+        with_curve!(curve, |E| Point::<E>::generator().to_point().into())
+    }
+
+    /// Point at infinity
+    pub fn zero(curve: AnyCurve) -> Self {
+        with_curve!(curve, |E| Point::<E>::zero().into())
+    }
+
+    /// Decodes a point on `curve` from its bytes representation
+    ///
+    /// Same as [`Point::from_bytes`]
+    pub fn from_bytes(curve: AnyCurve, bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
+        with_curve!(curve, |E| Point::<E>::from_bytes(bytes).map(Self::from))
+    }
+
+    /// Encodes a point as bytes
+    ///
+    /// Same as [`Point::to_bytes`]
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes(&self, compressed: bool) -> alloc::vec::Vec<u8> {
+        dispatch!(Self, self, |p| p.to_bytes(compressed).to_vec())
+    }
+
+    /// Adds two points
+    ///
+    /// Returns error if points are on different curves
+    pub fn try_add(&self, other: &Self) -> Result<Self, CurveMismatch> {
+        dispatch2!(Self, self, Self, other, |a, b| (a + b).into())
+    }
+
+    /// Subtracts `other` from `self`
+    ///
+    /// Returns error if points are on different curves
+    pub fn try_sub(&self, other: &Self) -> Result<Self, CurveMismatch> {
+        dispatch2!(Self, self, Self, other, |a, b| (a - b).into())
+    }
+
+    /// Multiplies a point at scalar
+    ///
+    /// Returns error if point and scalar are on different curves
+    pub fn try_mul(&self, scalar: &AnyScalar) -> Result<Self, CurveMismatch> {
+        dispatch2!(Self, self, AnyScalar, scalar, |p, s| (p * s).into())
+    }
+
+    /// Multiplies a point at secret scalar
+    ///
+    /// Returns error if point and scalar are on different curves
+    pub fn try_mul_secret(&self, scalar: &AnySecretScalar) -> Result<Self, CurveMismatch> {
+        dispatch2!(Self, self, AnySecretScalar, scalar, |p, s| (p * s).into())
+    }
+}
+
+impl crate::traits::IsZero for AnyPoint {
+    fn is_zero(&self) -> bool {
+        dispatch!(Self, self, |p| p.is_zero())
+    }
+}
+
+impl core::ops::Neg for AnyPoint {
+    type Output = AnyPoint;
+    fn neg(self) -> Self::Output {
+        dispatch!(Self, self, |p| (-p).into())
+    }
+}
+
+impl core::ops::Neg for &AnyPoint {
+    type Output = AnyPoint;
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl fmt::Display for AnyPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        dispatch!(Self, self, |p| fmt::Display::fmt(p, f))
+    }
+}
+
+/// Scalar on a curve chosen at runtime
+///
+/// See [module level docs](self) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnyScalar {
+    /// Scalar on secp256k1 curve
+    #[cfg(feature = "curve-secp256k1")]
+    Secp256k1(Scalar<crate::curves::Secp256k1>),
+    /// Scalar on secp256r1 curve
+    #[cfg(feature = "curve-secp256r1")]
+    Secp256r1(Scalar<crate::curves::Secp256r1>),
+    /// Scalar on stark curve
+    #[cfg(feature = "curve-stark")]
+    Stark(Scalar<crate::curves::Stark>),
+    /// Scalar on ed25519 curve
+    #[cfg(feature = "curve-ed25519")]
+    Ed25519(Scalar<crate::curves::Ed25519>),
+}
+
+impl AnyScalar {
+    /// Curve the scalar is on
+    pub fn curve(&self) -> AnyCurve {
+        match self {
+            #[cfg(feature = "curve-secp256k1")]
+            Self::Secp256k1(_) => AnyCurve::Secp256k1,
+            #[cfg(feature = "curve-secp256r1")]
+            Self::Secp256r1(_) => AnyCurve::Secp256r1,
+            #[cfg(feature = "curve-stark")]
+            Self::Stark(_) => AnyCurve::Stark,
+            #[cfg(feature = "curve-ed25519")]
+            Self::Ed25519(_) => AnyCurve::Ed25519,
+        }
+    }
+
+    /// Scalar equal to 0
+    pub fn zero(curve: AnyCurve) -> Self {
+        with_curve!(curve, |E| Scalar::<E>::zero().into())
+    }
+
+    /// Scalar equal to 1
+    pub fn one(curve: AnyCurve) -> Self {
+        with_curve!(curve, |E| Scalar::<E>::one().into())
+    }
+
+    /// Generates random scalar on `curve`
+    ///
+    /// Same as [`Scalar::random`]
+    pub fn random<R: RngCore>(curve: AnyCurve, rng: &mut R) -> Self {
+        with_curve!(curve, |E| Scalar::<E>::random(rng).into())
+    }
+
+    /// Decodes a scalar on `curve` from its bytes representation in big-endian order
+    ///
+    /// Same as [`Scalar::from_be_bytes`]
+    pub fn from_be_bytes(curve: AnyCurve, bytes: impl AsRef<[u8]>) -> Result<Self, InvalidScalar> {
+        with_curve!(curve, |E| Scalar::<E>::from_be_bytes(bytes).map(Self::from))
+    }
+
+    /// Encodes a scalar as bytes in big-endian order
+    ///
+    /// Same as [`Scalar::to_be_bytes`]
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes(&self) -> alloc::vec::Vec<u8> {
+        dispatch!(Self, self, |s| s.to_be_bytes().to_vec())
+    }
+
+    /// Returns scalar inverse $S^{-1}$, or `None` if scalar is zero
+    pub fn invert(&self) -> Option<Self> {
+        dispatch!(Self, self, |s| s.invert().map(Self::from))
+    }
+
+    /// Adds two scalars
+    ///
+    /// Returns error if scalars are on different curves
+    pub fn try_add(&self, other: &Self) -> Result<Self, CurveMismatch> {
+        dispatch2!(Self, self, Self, other, |a, b| (a + b).into())
+    }
+
+    /// Subtracts `other` from `self`
+    ///
+    /// Returns error if scalars are on different curves
+    pub fn try_sub(&self, other: &Self) -> Result<Self, CurveMismatch> {
+        dispatch2!(Self, self, Self, other, |a, b| (a - b).into())
+    }
+
+    /// Multiplies two scalars
+    ///
+    /// Returns error if scalars are on different curves
+    pub fn try_mul(&self, other: &Self) -> Result<Self, CurveMismatch> {
+        dispatch2!(Self, self, Self, other, |a, b| (a * b).into())
+    }
+}
+
+impl crate::traits::IsZero for AnyScalar {
+    fn is_zero(&self) -> bool {
+        dispatch!(Self, self, |s| crate::traits::IsZero::is_zero(s))
+    }
+}
+
+impl core::ops::Neg for AnyScalar {
+    type Output = AnyScalar;
+    fn neg(self) -> Self::Output {
+        dispatch!(Self, self, |s| (-s).into())
+    }
+}
+
+impl core::ops::Neg for &AnyScalar {
+    type Output = AnyScalar;
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl fmt::Display for AnyScalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        dispatch!(Self, self, |s| fmt::Display::fmt(s, f))
+    }
+}
+
+/// Secret scalar on a curve chosen at runtime
+///
+/// See [module level docs](self) for more details.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum AnySecretScalar {
+    /// Secret scalar on secp256k1 curve
+    #[cfg(feature = "curve-secp256k1")]
+    Secp256k1(SecretScalar<crate::curves::Secp256k1>),
+    /// Secret scalar on secp256r1 curve
+    #[cfg(feature = "curve-secp256r1")]
+    Secp256r1(SecretScalar<crate::curves::Secp256r1>),
+    /// Secret scalar on stark curve
+    #[cfg(feature = "curve-stark")]
+    Stark(SecretScalar<crate::curves::Stark>),
+    /// Secret scalar on ed25519 curve
+    #[cfg(feature = "curve-ed25519")]
+    Ed25519(SecretScalar<crate::curves::Ed25519>),
+}
+
+impl AnySecretScalar {
+    /// Curve the scalar is on
+    pub fn curve(&self) -> AnyCurve {
+        match self {
+            #[cfg(feature = "curve-secp256k1")]
+            Self::Secp256k1(_) => AnyCurve::Secp256k1,
+            #[cfg(feature = "curve-secp256r1")]
+            Self::Secp256r1(_) => AnyCurve::Secp256r1,
+            #[cfg(feature = "curve-stark")]
+            Self::Stark(_) => AnyCurve::Stark,
+            #[cfg(feature = "curve-ed25519")]
+            Self::Ed25519(_) => AnyCurve::Ed25519,
+        }
+    }
+
+    /// Generates random secret scalar on `curve`
+    ///
+    /// Same as [`SecretScalar::random`]
+    pub fn random<R: RngCore + CryptoRng>(curve: AnyCurve, rng: &mut R) -> Self {
+        with_curve!(curve, |E| SecretScalar::<E>::random(rng).into())
+    }
+
+    /// Decodes a secret scalar on `curve` from its bytes representation in big-endian order
+    ///
+    /// Same as [`SecretScalar::from_be_bytes`]
+    pub fn from_be_bytes(curve: AnyCurve, bytes: &[u8]) -> Result<Self, InvalidScalar> {
+        with_curve!(curve, |E| SecretScalar::<E>::from_be_bytes(bytes)
+            .map(Self::from))
+    }
+
+    /// Returns scalar inverse $S^{-1}$, or `None` if scalar is zero
+    pub fn invert(&self) -> Option<Self> {
+        dispatch!(Self, self, |s| s.invert().map(Self::from))
+    }
+
+    /// Computes public key $P = G \cdot S$
+    pub fn public_key(&self) -> AnyPoint {
+        dispatch!(Self, self, |s| (Point::generator() * s).into())
+    }
+}
+
+/// Conversions between runtime and compile-time curve types
+macro_rules! impl_conversions {
+    ($feature:literal, $curve:ident) => {
+        #[cfg(feature = $feature)]
+        impl From<Point<crate::curves::$curve>> for AnyPoint {
+            fn from(point: Point<crate::curves::$curve>) -> Self {
+                Self::$curve(point)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl TryFrom<AnyPoint> for Point<crate::curves::$curve> {
+            type Error = CurveMismatch;
+            fn try_from(point: AnyPoint) -> Result<Self, Self::Error> {
+                match point {
+                    AnyPoint::$curve(point) => Ok(point),
+                    #[allow(unreachable_patterns)]
+                    point => Err(CurveMismatch {
+                        expected: AnyCurve::$curve,
+                        got: point.curve(),
+                    }),
+                }
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl From<Scalar<crate::curves::$curve>> for AnyScalar {
+            fn from(scalar: Scalar<crate::curves::$curve>) -> Self {
+                Self::$curve(scalar)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl TryFrom<AnyScalar> for Scalar<crate::curves::$curve> {
+            type Error = CurveMismatch;
+            fn try_from(scalar: AnyScalar) -> Result<Self, Self::Error> {
+                match scalar {
+                    AnyScalar::$curve(scalar) => Ok(scalar),
+                    #[allow(unreachable_patterns)]
+                    scalar => Err(CurveMismatch {
+                        expected: AnyCurve::$curve,
+                        got: scalar.curve(),
+                    }),
+                }
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl From<SecretScalar<crate::curves::$curve>> for AnySecretScalar {
+            fn from(scalar: SecretScalar<crate::curves::$curve>) -> Self {
+                Self::$curve(scalar)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl TryFrom<AnySecretScalar> for SecretScalar<crate::curves::$curve> {
+            type Error = CurveMismatch;
+            fn try_from(scalar: AnySecretScalar) -> Result<Self, Self::Error> {
+                match scalar {
+                    AnySecretScalar::$curve(scalar) => Ok(scalar),
+                    #[allow(unreachable_patterns)]
+                    scalar => Err(CurveMismatch {
+                        expected: AnyCurve::$curve,
+                        got: scalar.curve(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_conversions!("curve-secp256k1", Secp256k1);
+impl_conversions!("curve-secp256r1", Secp256r1);
+impl_conversions!("curve-stark", Stark);
+impl_conversions!("curve-ed25519", Ed25519);
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use core::fmt;
+
+    use phantom_type::PhantomType;
+
+    use serde::{
+        de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+    use serde_with::de::DeserializeAsWrap;
+
+    use super::{AnyCurve, AnyPoint, AnyScalar, AnySecretScalar};
+    use crate::{serde::utils::Bytes, Curve, Point, Scalar, SecretScalar};
+
+    impl Serialize for AnyCurve {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.name())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AnyCurve {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct CurveVisitor;
+            impl Visitor<'_> for CurveVisitor {
+                type Value = AnyCurve;
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("curve name")
+                }
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                    AnyCurve::from_name(v).ok_or_else(|| E::unknown_variant(v, AnyCurve::NAMES))
+                }
+            }
+            deserializer.deserialize_str(CurveVisitor)
+        }
+    }
+
+    impl Serialize for AnyPoint {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            dispatch!(Self, self, |p| p.serialize(serializer))
+        }
+    }
+
+    impl Serialize for AnyScalar {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            dispatch!(Self, self, |s| s.serialize(serializer))
+        }
+    }
+
+    impl Serialize for AnySecretScalar {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            dispatch!(Self, self, |s| s.serialize(serializer))
+        }
+    }
+
+    /// Value serialized as a struct `{ curve, <FIELD> }`, where the curve determines how `<FIELD>`
+    /// is deserialized
+    trait CurveTagged: Sized {
+        const NAME: &'static str;
+        const FIELD: &'static str;
+
+        fn deserialize_on<'de, D: Deserializer<'de>>(
+            curve: AnyCurve,
+            deserializer: D,
+        ) -> Result<Self, D::Error>;
+    }
+
+    impl CurveTagged for AnyPoint {
+        const NAME: &'static str = "PointUncompressed";
+        const FIELD: &'static str = "point";
+
+        fn deserialize_on<'de, D: Deserializer<'de>>(
+            curve: AnyCurve,
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            with_curve!(curve, |E| {
+                let bytes =
+                    DeserializeAsWrap::<<E as Curve>::UncompressedPointArray, Bytes>::deserialize(
+                        deserializer,
+                    )?
+                    .into_inner();
+                Point::<E>::from_bytes(bytes)
+                    .map(Self::from)
+                    .map_err(<D::Error as de::Error>::custom)
+            })
+        }
+    }
+
+    impl CurveTagged for AnyScalar {
+        const NAME: &'static str = "ScalarUncompressed";
+        const FIELD: &'static str = "scalar";
+
+        fn deserialize_on<'de, D: Deserializer<'de>>(
+            curve: AnyCurve,
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            with_curve!(curve, |E| {
+                let bytes = DeserializeAsWrap::<<E as Curve>::ScalarArray, Bytes>::deserialize(
+                    deserializer,
+                )?
+                .into_inner();
+                Scalar::<E>::from_be_bytes(bytes)
+                    .map(Self::from)
+                    .map_err(<D::Error as de::Error>::custom)
+            })
+        }
+    }
+
+    impl CurveTagged for AnySecretScalar {
+        const NAME: &'static str = AnyScalar::NAME;
+        const FIELD: &'static str = AnyScalar::FIELD;
+
+        fn deserialize_on<'de, D: Deserializer<'de>>(
+            curve: AnyCurve,
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let scalar = AnyScalar::deserialize_on(curve, deserializer)?;
+            Ok(dispatch!(AnyScalar, scalar, |s| {
+                let mut s = s;
+                SecretScalar::new(&mut s).into()
+            }))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AnyPoint {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_tagged(deserializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AnyScalar {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_tagged(deserializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AnySecretScalar {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_tagged(deserializer)
+        }
+    }
+
+    fn deserialize_tagged<'de, T: CurveTagged, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_struct(
+            T::NAME,
+            &["curve", T::FIELD],
+            TaggedVisitor::<T>(PhantomType::new()),
+        )
+    }
+
+    struct TaggedVisitor<T>(PhantomType<T>);
+
+    impl<'de, T: CurveTagged> Visitor<'de> for TaggedVisitor<T> {
+        type Value = T;
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "struct {}", T::NAME)
+        }
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let curve: AnyCurve = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            seq.next_element_seed(ValueSeed::<T>(curve, PhantomType::new()))?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))
+        }
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut curve = None;
+            let mut value = None;
+            while let Some(key) = map.next_key_seed(FieldSeed(T::FIELD))? {
+                match key {
+                    Field::Curve => {
+                        if curve.is_some() {
+                            return Err(de::Error::duplicate_field("curve"));
+                        }
+                        curve = Some(map.next_value::<AnyCurve>()?);
+                    }
+                    Field::Value => {
+                        if value.is_some() {
+                            return Err(de::Error::duplicate_field(T::FIELD));
+                        }
+                        let curve = curve.ok_or_else(|| {
+                            de::Error::custom(format_args!(
+                                "`curve` field must precede `{}` field",
+                                T::FIELD
+                            ))
+                        })?;
+                        value =
+                            Some(map.next_value_seed(ValueSeed::<T>(curve, PhantomType::new()))?);
+                    }
+                    Field::Other => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+            }
+            if curve.is_none() {
+                return Err(de::Error::missing_field("curve"));
+            }
+            value.ok_or_else(|| de::Error::missing_field(T::FIELD))
+        }
+    }
+
+    struct ValueSeed<T>(AnyCurve, PhantomType<T>);
+
+    impl<'de, T: CurveTagged> DeserializeSeed<'de> for ValueSeed<T> {
+        type Value = T;
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+            T::deserialize_on(self.0, deserializer)
+        }
+    }
+
+    enum Field {
+        Curve,
+        Value,
+        Other,
+    }
+
+    /// Deserializes a struct key, `.0` is the name of the field holding point/scalar
+    struct FieldSeed(&'static str);
+
+    impl<'de> DeserializeSeed<'de> for FieldSeed {
+        type Value = Field;
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Field, D::Error> {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    impl Visitor<'_> for FieldSeed {
+        type Value = Field;
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("field identifier")
+        }
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Field, E> {
+            Ok(match v {
+                0 => Field::Curve,
+                1 => Field::Value,
+                _ => Field::Other,
+            })
+        }
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+            self.visit_bytes(v.as_bytes())
+        }
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Field, E> {
+            Ok(if v == b"curve" {
+                Field::Curve
+            } else if v == self.0.as_bytes() {
+                Field::Value
+            } else {
+                Field::Other
+            })
+        }
+    }
+}
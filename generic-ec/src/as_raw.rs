@@ -10,6 +10,8 @@
 use subtle::CtOption;
 
 mod sealed {
+    // Not used as a bound yet, newer toolchains report it as dead code
+    #[allow(dead_code)]
     pub trait Sealed {}
     impl<E: crate::Curve> Sealed for crate::Point<E> {}
     impl<E: crate::Curve> Sealed for crate::Scalar<E> {}
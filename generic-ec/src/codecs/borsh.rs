@@ -0,0 +1,77 @@
+use borsh::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+use zeroize::Zeroize;
+
+use crate::{core::ByteArray, Curve, NonZero, Point, Scalar, SecretScalar};
+
+impl<E: Curve> BorshSerialize for Point<E> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(self.to_bytes_compressed().as_ref())
+    }
+}
+
+impl<E: Curve> BorshDeserialize for Point<E> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = E::CompressedPointArray::zeroes();
+        reader.read_exact(bytes.as_mut())?;
+        Point::from_bytes_strict(&bytes).map_err(|_| invalid_data("invalid point"))
+    }
+}
+
+impl<E: Curve> BorshSerialize for Scalar<E> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.to_be_bytes())
+    }
+}
+
+impl<E: Curve> BorshDeserialize for Scalar<E> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = E::ScalarArray::zeroes();
+        reader.read_exact(bytes.as_mut())?;
+        Scalar::from_be_bytes_strict(&bytes).map_err(|_| invalid_data("invalid scalar"))
+    }
+}
+
+impl<E: Curve> BorshSerialize for SecretScalar<E> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let mut bytes = self.as_ref().to_be_bytes();
+        let result = writer.write_all(&bytes);
+        bytes.as_mut().zeroize();
+        result
+    }
+}
+
+impl<E: Curve> BorshDeserialize for SecretScalar<E> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = E::ScalarArray::zeroes();
+        let result = reader.read_exact(bytes.as_mut()).and_then(|()| {
+            SecretScalar::from_be_bytes_strict(bytes.as_ref())
+                .map_err(|_| invalid_data("invalid scalar"))
+        });
+        bytes.as_mut().zeroize();
+        result
+    }
+}
+
+impl<T: BorshSerialize> BorshSerialize for NonZero<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_ref().serialize(writer)
+    }
+}
+
+impl<T> BorshDeserialize for NonZero<T>
+where
+    T: BorshDeserialize,
+    NonZero<T>: TryFrom<T>,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let value = T::deserialize_reader(reader)?;
+        NonZero::try_from(value).map_err(|_| invalid_data("value is zero"))
+    }
+}
+
+fn invalid_data(msg: &'static str) -> Error {
+    Error::new(ErrorKind::InvalidData, msg)
+}
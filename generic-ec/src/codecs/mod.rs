@@ -0,0 +1,17 @@
+//! Binary codecs: Borsh, SCALE and rkyv
+//!
+//! All codecs share the same encoding: point is encoded as [`Point::COMPRESSED_LEN`](crate::Point::COMPRESSED_LEN)
+//! bytes of its compressed form, scalar is encoded as [`Scalar::SERIALIZED_LEN`](crate::Scalar::SERIALIZED_LEN)
+//! bytes in big-endian order. Borsh and SCALE write the bytes as a fixed-size array (without
+//! length prefix), rkyv archives them as `ArchivedVec<u8>`.
+//!
+//! Decoding performs the same checks as [`Point::from_bytes_strict`](crate::Point::from_bytes_strict)
+//! and [`Scalar::from_be_bytes_strict`](crate::Scalar::from_be_bytes_strict). Decoding
+//! [`NonZero`](crate::NonZero) additionally checks that value is not zero.
+
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "scale")]
+mod scale;
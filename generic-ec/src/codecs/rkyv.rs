@@ -0,0 +1,127 @@
+use core::fmt;
+
+use rkyv::{
+    rancor::{Fallible, Source},
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, Place, Serialize,
+};
+use zeroize::Zeroize;
+
+use crate::{Curve, NonZero, Point, Scalar, SecretScalar};
+
+impl<E: Curve> Archive for Point<E> {
+    type Archived = ArchivedVec<u8>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_len(Self::COMPRESSED_LEN, resolver, out)
+    }
+}
+
+impl<E: Curve, S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for Point<E> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(self.to_bytes_compressed().as_ref(), serializer)
+    }
+}
+
+impl<E: Curve, D: Fallible + ?Sized> Deserialize<Point<E>, D> for ArchivedVec<u8>
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _deserializer: &mut D) -> Result<Point<E>, D::Error> {
+        Point::from_bytes_strict(self.as_slice()).map_err(|err| D::Error::new(DecodeError(err)))
+    }
+}
+
+impl<E: Curve> Archive for Scalar<E> {
+    type Archived = ArchivedVec<u8>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_len(Self::SERIALIZED_LEN, resolver, out)
+    }
+}
+
+impl<E: Curve, S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for Scalar<E> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(&self.to_be_bytes(), serializer)
+    }
+}
+
+impl<E: Curve, D: Fallible + ?Sized> Deserialize<Scalar<E>, D> for ArchivedVec<u8>
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _deserializer: &mut D) -> Result<Scalar<E>, D::Error> {
+        Scalar::from_be_bytes_strict(self.as_slice()).map_err(|err| D::Error::new(DecodeError(err)))
+    }
+}
+
+impl<E: Curve> Archive for SecretScalar<E> {
+    type Archived = ArchivedVec<u8>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_len(Scalar::<E>::SERIALIZED_LEN, resolver, out)
+    }
+}
+
+impl<E: Curve, S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for SecretScalar<E> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let mut bytes = self.as_ref().to_be_bytes();
+        let result = ArchivedVec::serialize_from_slice(&bytes, serializer);
+        bytes.as_mut().zeroize();
+        result
+    }
+}
+
+impl<E: Curve, D: Fallible + ?Sized> Deserialize<SecretScalar<E>, D> for ArchivedVec<u8>
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _deserializer: &mut D) -> Result<SecretScalar<E>, D::Error> {
+        SecretScalar::from_be_bytes_strict(self.as_slice())
+            .map_err(|err| D::Error::new(DecodeError(err)))
+    }
+}
+
+impl<T: Archive> Archive for NonZero<T> {
+    type Archived = T::Archived;
+    type Resolver = T::Resolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        self.as_ref().resolve(resolver, out)
+    }
+}
+
+impl<T: Serialize<S>, S: Fallible + ?Sized> Serialize<S> for NonZero<T> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+impl<T, D: Fallible + ?Sized> Deserialize<NonZero<T>, D> for ArchivedVec<u8>
+where
+    ArchivedVec<u8>: Deserialize<T, D>,
+    NonZero<T>: TryFrom<T>,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<NonZero<T>, D::Error> {
+        let value: T = self.deserialize(deserializer)?;
+        NonZero::try_from(value).map_err(|_| D::Error::new(DecodeError("value is zero")))
+    }
+}
+
+/// Wraps decoding error, as [`Source`] requires errors to implement [`core::error::Error`]
+/// which is not implemented by this crate errors in `no_std`
+#[derive(Debug)]
+struct DecodeError<T>(T);
+
+impl<T: fmt::Display> fmt::Display for DecodeError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> core::error::Error for DecodeError<T> {}
@@ -0,0 +1,164 @@
+use parity_scale_codec::{
+    Decode, DecodeWithMemTracking, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output,
+};
+use zeroize::Zeroize;
+
+use crate::{core::ByteArray, Curve, NonZero, Point, Scalar, SecretScalar};
+
+impl<E: Curve> Encode for Point<E> {
+    fn size_hint(&self) -> usize {
+        Self::COMPRESSED_LEN
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        dest.write(self.to_bytes_compressed().as_ref())
+    }
+
+    fn encoded_size(&self) -> usize {
+        Self::COMPRESSED_LEN
+    }
+}
+
+impl<E: Curve> EncodeLike for Point<E> {}
+
+impl<E: Curve> MaxEncodedLen for Point<E> {
+    fn max_encoded_len() -> usize {
+        Self::COMPRESSED_LEN
+    }
+}
+
+impl<E: Curve> Decode for Point<E> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = E::CompressedPointArray::zeroes();
+        input.read(bytes.as_mut())?;
+        Point::from_bytes_strict(&bytes).map_err(|_| "invalid point".into())
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(Self::COMPRESSED_LEN)
+    }
+}
+
+impl<E: Curve> DecodeWithMemTracking for Point<E> {}
+
+impl<E: Curve> Encode for Scalar<E> {
+    fn size_hint(&self) -> usize {
+        Self::SERIALIZED_LEN
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        dest.write(&self.to_be_bytes())
+    }
+
+    fn encoded_size(&self) -> usize {
+        Self::SERIALIZED_LEN
+    }
+}
+
+impl<E: Curve> EncodeLike for Scalar<E> {}
+
+impl<E: Curve> MaxEncodedLen for Scalar<E> {
+    fn max_encoded_len() -> usize {
+        Self::SERIALIZED_LEN
+    }
+}
+
+impl<E: Curve> Decode for Scalar<E> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = E::ScalarArray::zeroes();
+        input.read(bytes.as_mut())?;
+        Scalar::from_be_bytes_strict(&bytes).map_err(|_| "invalid scalar".into())
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(Scalar::<E>::SERIALIZED_LEN)
+    }
+}
+
+impl<E: Curve> DecodeWithMemTracking for Scalar<E> {}
+
+impl<E: Curve> Encode for SecretScalar<E> {
+    fn size_hint(&self) -> usize {
+        Scalar::<E>::SERIALIZED_LEN
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        let mut bytes = self.as_ref().to_be_bytes();
+        dest.write(&bytes);
+        bytes.as_mut().zeroize();
+    }
+
+    fn encoded_size(&self) -> usize {
+        Scalar::<E>::SERIALIZED_LEN
+    }
+}
+
+impl<E: Curve> EncodeLike for SecretScalar<E> {}
+
+impl<E: Curve> MaxEncodedLen for SecretScalar<E> {
+    fn max_encoded_len() -> usize {
+        Scalar::<E>::SERIALIZED_LEN
+    }
+}
+
+impl<E: Curve> Decode for SecretScalar<E> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = E::ScalarArray::zeroes();
+        let result = input.read(bytes.as_mut()).and_then(|()| {
+            SecretScalar::from_be_bytes_strict(bytes.as_ref()).map_err(|_| "invalid scalar".into())
+        });
+        bytes.as_mut().zeroize();
+        result
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(Scalar::<E>::SERIALIZED_LEN)
+    }
+}
+
+impl<E: Curve> DecodeWithMemTracking for SecretScalar<E> {}
+
+impl<T: Encode> Encode for NonZero<T> {
+    fn size_hint(&self) -> usize {
+        self.as_ref().size_hint()
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.as_ref().encode_to(dest)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.as_ref().encoded_size()
+    }
+}
+
+impl<T: Encode> EncodeLike for NonZero<T> {}
+impl<T: Encode> EncodeLike<T> for NonZero<T> {}
+
+impl<T: MaxEncodedLen> MaxEncodedLen for NonZero<T> {
+    fn max_encoded_len() -> usize {
+        T::max_encoded_len()
+    }
+}
+
+impl<T> Decode for NonZero<T>
+where
+    T: Decode,
+    NonZero<T>: TryFrom<T>,
+{
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let value = T::decode(input)?;
+        NonZero::try_from(value).map_err(|_| "value is zero".into())
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        T::encoded_fixed_size()
+    }
+}
+
+impl<T> DecodeWithMemTracking for NonZero<T>
+where
+    T: DecodeWithMemTracking,
+    NonZero<T>: TryFrom<T>,
+{
+}
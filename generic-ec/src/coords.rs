@@ -80,6 +80,23 @@ impl<E: Curve> Coordinate<E> {
         Ok(Self(coord))
     }
 
+    /// Little-endian bytes representation of a coordinate
+    ///
+    /// Useful for formats that encode coordinates in little-endian, e.g. Ed25519 point encoding
+    /// defined in RFC 8032.
+    pub fn to_le_bytes(&self) -> E::CoordinateArray {
+        let mut bytes = self.0.clone();
+        bytes.as_mut().reverse();
+        bytes
+    }
+
+    /// Parses little-endian bytes representation of a coordinate
+    pub fn from_le_bytes(bytes: &[u8]) -> Result<Self, InvalidCoordinate> {
+        let mut coord = Self::from_be_bytes(bytes)?;
+        coord.0.as_mut().reverse();
+        Ok(coord)
+    }
+
     /// Converts coordinate into scalar (coordinate is reduced modulo curve order)
     pub fn to_scalar(&self) -> Scalar<E> {
         Scalar::from_be_bytes_mod_order(self.as_be_bytes())
@@ -152,6 +169,34 @@ impl<E: Curve> core::hash::Hash for Coordinate<E> {
     }
 }
 
+#[cfg(feature = "udigest")]
+impl<E: Curve> udigest::Digestable for Coordinate<E> {
+    fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
+    where
+        B: udigest::Buffer,
+    {
+        let mut s = encoder.encode_struct();
+        s.add_field("curve").encode_leaf_value(E::CURVE_NAME);
+        s.add_field("coordinate")
+            .encode_leaf_value(self.as_be_bytes());
+        s.finish();
+    }
+}
+
+#[cfg(feature = "udigest")]
+impl<E: Curve> udigest::Digestable for Coordinates<E> {
+    fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
+    where
+        B: udigest::Buffer,
+    {
+        let mut s = encoder.encode_struct();
+        s.add_field("curve").encode_leaf_value(E::CURVE_NAME);
+        s.add_field("x").encode_leaf_value(self.x.as_be_bytes());
+        s.add_field("y").encode_leaf_value(self.y.as_be_bytes());
+        s.finish();
+    }
+}
+
 mod sealed {
     pub trait Sealed {}
 
@@ -191,6 +236,12 @@ where
 }
 
 /// Point is uniquely represented by affine $x, y$ coordinates
+///
+/// Coordinates are always encoded as big-endian integers, including Ed25519 ones, even though
+/// [RFC 8032] encodes Ed25519 points in little-endian. Use [`Coordinate::to_le_bytes`] and
+/// [`Coordinate::from_le_bytes`] to convert coordinates to and from RFC 8032 byte order.
+///
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.2
 pub trait HasAffineXY<E: Curve>: HasAffineX<E> + HasAffineY<E>
 where
     Self: Sized,
@@ -0,0 +1,399 @@
+//! COSE_Key import and export
+//!
+//! Keys are encoded as CBOR maps defined in [RFC 9052] and [RFC 9053]. Curves are identified by
+//! [`Curve::CURVE_NAME`]:
+//!
+//! | Curve     | `kty`   | `crv`           | Specification |
+//! |-----------|---------|-----------------|---------------|
+//! | secp256r1 | EC2 (2) | P-256 (1)       | [RFC 9053]    |
+//! | secp256k1 | EC2 (2) | secp256k1 (8)   | [RFC 8812]    |
+//! | ed25519   | OKP (1) | Ed25519 (6)     | [RFC 9053]    |
+//!
+//! Keys are encoded deterministically as defined in [RFC 8949]. EC2 public keys are represented
+//! by affine $x, y$ coordinates (compressed form with boolean $y$ is not supported), OKP public
+//! key is represented by its compressed encoding. When decoding, labels that don't describe
+//! key material (e.g. `kid` or `alg`) are ignored.
+//!
+//! Secret key is stored under `d` label. Same as with [JWK](crate::jwk), Ed25519 secret key is
+//! a seed which is expanded on import, and exporting [`SecretScalar<Ed25519>`](crate::curves::Ed25519)
+//! returns [`KeyFormatError::Unsupported`].
+//!
+//! ```rust
+//! use generic_ec::{Point, curves::Secp256r1};
+//! # let mut rng = rand::rngs::OsRng;
+//!
+//! let public_key = Point::<Secp256r1>::generator() * generic_ec::Scalar::random(&mut rng);
+//! let cose_key = public_key.to_cose_key()?;
+//! assert_eq!(Point::<Secp256r1>::from_cose_key(&cose_key)?, public_key);
+//! # Ok::<_, generic_ec::errors::KeyFormatError>(())
+//! ```
+//!
+//! [RFC 8812]: https://www.rfc-editor.org/rfc/rfc8812#section-3.1
+//! [RFC 8949]: https://www.rfc-editor.org/rfc/rfc8949#section-4.2.1
+//! [RFC 9052]: https://www.rfc-editor.org/rfc/rfc9052#section-7
+//! [RFC 9053]: https://www.rfc-editor.org/rfc/rfc9053#section-7
+
+use alloc::vec::Vec;
+
+use zeroize::Zeroizing;
+
+use crate::{
+    coords::HasAffineXY,
+    errors::KeyFormatError,
+    key_format::{self, KeyType, NamedCurve, PublicKeyMembers},
+    Curve, Point, SecretScalar,
+};
+
+const LABEL_KTY: i64 = 1;
+const LABEL_CRV: i64 = -1;
+const LABEL_X: i64 = -2;
+const LABEL_Y: i64 = -3;
+const LABEL_D: i64 = -4;
+
+const KTY_OKP: i64 = 1;
+const KTY_EC2: i64 = 2;
+
+impl<E: Curve> Point<E>
+where
+    Point<E>: HasAffineXY<E>,
+{
+    /// Exports public key as COSE_Key
+    ///
+    /// Returns error if curve is not supported, or if point is zero. See [`cose`](crate::cose) module.
+    pub fn to_cose_key(&self) -> Result<Vec<u8>, KeyFormatError> {
+        let curve = NamedCurve::of::<E>().ok_or(KeyFormatError::UnsupportedCurve)?;
+        let public_key = PublicKeyMembers::from_point(self, curve)?;
+        Ok(encode_key(curve, &public_key, None))
+    }
+
+    /// Imports public key from COSE_Key
+    ///
+    /// Secret part of the key, if present, is ignored.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, KeyFormatError> {
+        let curve = NamedCurve::of::<E>().ok_or(KeyFormatError::UnsupportedCurve)?;
+        let key = CoseKey::decode(bytes)?;
+        key.check_curve(curve)?;
+        key.public_key(curve)?
+            .ok_or(KeyFormatError::Malformed)?
+            .to_point(curve)
+    }
+}
+
+impl<E: Curve> SecretScalar<E>
+where
+    Point<E>: HasAffineXY<E>,
+{
+    /// Exports secret key as COSE_Key
+    ///
+    /// Resulting key also contains the public key. Returns [`KeyFormatError::Unsupported`] for
+    /// Ed25519, see [`cose`](crate::cose) module.
+    pub fn to_cose_key(&self) -> Result<Zeroizing<Vec<u8>>, KeyFormatError> {
+        let curve = NamedCurve::of::<E>().ok_or(KeyFormatError::UnsupportedCurve)?;
+        let d = key_format::encode_secret_key(self, curve)?;
+        let public_key = PublicKeyMembers::from_point(&(Point::generator() * self), curve)?;
+        Ok(Zeroizing::new(encode_key(curve, &public_key, Some(&d))))
+    }
+
+    /// Imports secret key from COSE_Key
+    ///
+    /// Public key is optional in COSE_Key. If it's present, it's checked to correspond to the
+    /// secret key.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, KeyFormatError> {
+        let curve = NamedCurve::of::<E>().ok_or(KeyFormatError::UnsupportedCurve)?;
+        let key = CoseKey::decode(bytes)?;
+        key.check_curve(curve)?;
+        let d = key.d.ok_or(KeyFormatError::MissingSecretKey)?;
+        let public_key = key
+            .public_key(curve)?
+            .map(|pk| pk.to_point(curve))
+            .transpose()?;
+        key_format::decode_secret_key(d, public_key, curve)
+    }
+}
+
+fn encode_key(curve: NamedCurve, public_key: &PublicKeyMembers, d: Option<&[u8]>) -> Vec<u8> {
+    let len = 3 + u64::from(public_key.y.is_some()) + u64::from(d.is_some());
+
+    let mut out = Vec::with_capacity(160);
+    cbor::write_head(&mut out, cbor::MAP, len);
+    // Labels are sorted as required by deterministic encoding
+    cbor::write_int(&mut out, LABEL_KTY);
+    cbor::write_int(&mut out, kty(curve.key_type));
+    cbor::write_int(&mut out, LABEL_CRV);
+    cbor::write_int(&mut out, curve.cose_id);
+    cbor::write_int(&mut out, LABEL_X);
+    cbor::write_bytes(&mut out, &public_key.x);
+    if let Some(y) = &public_key.y {
+        cbor::write_int(&mut out, LABEL_Y);
+        cbor::write_bytes(&mut out, y);
+    }
+    if let Some(d) = d {
+        cbor::write_int(&mut out, LABEL_D);
+        cbor::write_bytes(&mut out, d);
+    }
+    out
+}
+
+/// Members of decoded COSE_Key which are relevant to key material
+struct CoseKey<'b> {
+    kty: i64,
+    crv: i64,
+    x: Option<&'b [u8]>,
+    y: Option<&'b [u8]>,
+    d: Option<&'b [u8]>,
+}
+
+impl<'b> CoseKey<'b> {
+    fn decode(bytes: &'b [u8]) -> Result<Self, KeyFormatError> {
+        let mut reader = cbor::Reader::new(bytes);
+        let len = reader.read_len(cbor::MAP)?;
+
+        let (mut kty, mut crv, mut x, mut y, mut d) = (None, None, None, None, None);
+        for _ in 0..len {
+            let label = match reader.peek_major()? {
+                cbor::UNSIGNED | cbor::NEGATIVE => Some(reader.read_int()?),
+                // Text labels are not used for key material
+                _ => {
+                    reader.skip()?;
+                    None
+                }
+            };
+            let slot = match label {
+                Some(LABEL_KTY) => {
+                    set_once(&mut kty, reader.read_int()?)?;
+                    continue;
+                }
+                Some(LABEL_CRV) => {
+                    set_once(&mut crv, reader.read_int()?)?;
+                    continue;
+                }
+                Some(LABEL_X) => &mut x,
+                Some(LABEL_Y) => {
+                    if reader.peek_major()? == cbor::SIMPLE {
+                        // Compressed point is not supported
+                        return Err(KeyFormatError::Unsupported);
+                    }
+                    &mut y
+                }
+                Some(LABEL_D) => &mut d,
+                _ => {
+                    reader.skip()?;
+                    continue;
+                }
+            };
+            set_once(slot, reader.read_bytes()?)?;
+        }
+        reader.finish()?;
+
+        Ok(Self {
+            kty: kty.ok_or(KeyFormatError::Malformed)?,
+            crv: crv.ok_or(KeyFormatError::Malformed)?,
+            x,
+            y,
+            d,
+        })
+    }
+
+    fn check_curve(&self, curve: NamedCurve) -> Result<(), KeyFormatError> {
+        if self.kty != kty(curve.key_type) || self.crv != curve.cose_id {
+            return Err(KeyFormatError::CurveMismatch);
+        }
+        Ok(())
+    }
+
+    /// Returns public key members if the key has them
+    fn public_key(&self, curve: NamedCurve) -> Result<Option<PublicKeyMembers>, KeyFormatError> {
+        let x = match self.x {
+            Some(x) => x.to_vec(),
+            None if self.y.is_none() => return Ok(None),
+            None => return Err(KeyFormatError::Malformed),
+        };
+        let y = match (curve.key_type, self.y) {
+            (KeyType::Ec, Some(y)) => Some(y.to_vec()),
+            (KeyType::Okp, None) => None,
+            _ => return Err(KeyFormatError::Malformed),
+        };
+        Ok(Some(PublicKeyMembers { x, y }))
+    }
+}
+
+fn kty(key_type: KeyType) -> i64 {
+    match key_type {
+        KeyType::Ec => KTY_EC2,
+        KeyType::Okp => KTY_OKP,
+    }
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T) -> Result<(), KeyFormatError> {
+    if slot.is_some() {
+        // Duplicate labels are not allowed
+        return Err(KeyFormatError::Malformed);
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Minimal CBOR encoder and decoder sufficient for COSE_Key
+mod cbor {
+    use alloc::vec::Vec;
+
+    use crate::errors::KeyFormatError;
+
+    pub const UNSIGNED: u8 = 0;
+    pub const NEGATIVE: u8 = 1;
+    pub const BYTES: u8 = 2;
+    pub const TEXT: u8 = 3;
+    pub const ARRAY: u8 = 4;
+    pub const MAP: u8 = 5;
+    pub const TAG: u8 = 6;
+    pub const SIMPLE: u8 = 7;
+
+    /// Maximum nesting of skipped items
+    const MAX_DEPTH: usize = 16;
+
+    pub fn write_head(out: &mut Vec<u8>, major: u8, arg: u64) {
+        let major = major << 5;
+        if arg < 24 {
+            out.push(major | arg as u8);
+        } else if arg <= u8::MAX.into() {
+            out.extend_from_slice(&[major | 24, arg as u8]);
+        } else if arg <= u16::MAX.into() {
+            out.push(major | 25);
+            out.extend_from_slice(&(arg as u16).to_be_bytes());
+        } else if arg <= u32::MAX.into() {
+            out.push(major | 26);
+            out.extend_from_slice(&(arg as u32).to_be_bytes());
+        } else {
+            out.push(major | 27);
+            out.extend_from_slice(&arg.to_be_bytes());
+        }
+    }
+
+    pub fn write_int(out: &mut Vec<u8>, n: i64) {
+        if n >= 0 {
+            write_head(out, UNSIGNED, n as u64)
+        } else {
+            write_head(out, NEGATIVE, !n as u64)
+        }
+    }
+
+    pub fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+        write_head(out, BYTES, bytes.len() as u64);
+        out.extend_from_slice(bytes);
+    }
+
+    pub struct Reader<'b> {
+        bytes: &'b [u8],
+    }
+
+    impl<'b> Reader<'b> {
+        pub fn new(bytes: &'b [u8]) -> Self {
+            Self { bytes }
+        }
+
+        pub fn peek_major(&self) -> Result<u8, KeyFormatError> {
+            self.bytes
+                .first()
+                .map(|b| b >> 5)
+                .ok_or(KeyFormatError::Malformed)
+        }
+
+        /// Reads major type and argument of the next item
+        ///
+        /// Indefinite lengths are not supported
+        fn read_head(&mut self) -> Result<(u8, u64), KeyFormatError> {
+            let (&first, rest) = self.bytes.split_first().ok_or(KeyFormatError::Malformed)?;
+            let (major, info) = (first >> 5, first & 0x1f);
+            let len = match info {
+                0..=23 => {
+                    self.bytes = rest;
+                    return Ok((major, info.into()));
+                }
+                24 => 1,
+                25 => 2,
+                26 => 4,
+                27 => 8,
+                _ => return Err(KeyFormatError::Malformed),
+            };
+            if rest.len() < len {
+                return Err(KeyFormatError::Malformed);
+            }
+            let (arg, rest) = rest.split_at(len);
+            self.bytes = rest;
+            Ok((
+                major,
+                arg.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b)),
+            ))
+        }
+
+        pub fn read_len(&mut self, expected_major: u8) -> Result<u64, KeyFormatError> {
+            match self.read_head()? {
+                (major, len) if major == expected_major => Ok(len),
+                _ => Err(KeyFormatError::Malformed),
+            }
+        }
+
+        pub fn read_int(&mut self) -> Result<i64, KeyFormatError> {
+            let (major, arg) = self.read_head()?;
+            let arg = i64::try_from(arg).map_err(|_| KeyFormatError::Malformed)?;
+            match major {
+                UNSIGNED => Ok(arg),
+                NEGATIVE => Ok(-1 - arg),
+                _ => Err(KeyFormatError::Malformed),
+            }
+        }
+
+        pub fn read_bytes(&mut self) -> Result<&'b [u8], KeyFormatError> {
+            let len = self.read_len(BYTES)?;
+            self.take(len)
+        }
+
+        fn take(&mut self, len: u64) -> Result<&'b [u8], KeyFormatError> {
+            let len = usize::try_from(len).map_err(|_| KeyFormatError::Malformed)?;
+            if self.bytes.len() < len {
+                return Err(KeyFormatError::Malformed);
+            }
+            let (taken, rest) = self.bytes.split_at(len);
+            self.bytes = rest;
+            Ok(taken)
+        }
+
+        /// Skips the next item
+        pub fn skip(&mut self) -> Result<(), KeyFormatError> {
+            self.skip_nested(0)
+        }
+
+        fn skip_nested(&mut self, depth: usize) -> Result<(), KeyFormatError> {
+            if depth > MAX_DEPTH {
+                return Err(KeyFormatError::Malformed);
+            }
+            let (major, arg) = self.read_head()?;
+            match major {
+                UNSIGNED | NEGATIVE | SIMPLE => Ok(()),
+                BYTES | TEXT => self.take(arg).map(|_| ()),
+                ARRAY | MAP => {
+                    let items = if major == MAP {
+                        arg.checked_mul(2)
+                    } else {
+                        Some(arg)
+                    };
+                    for _ in 0..items.ok_or(KeyFormatError::Malformed)? {
+                        self.skip_nested(depth + 1)?;
+                    }
+                    Ok(())
+                }
+                TAG => self.skip_nested(depth + 1),
+                _ => Err(KeyFormatError::Malformed),
+            }
+        }
+
+        /// Checks that all input was read
+        pub fn finish(self) -> Result<(), KeyFormatError> {
+            if self.bytes.is_empty() {
+                Ok(())
+            } else {
+                Err(KeyFormatError::Malformed)
+            }
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! String representation of points, scalars and coordinates
+//!
+//! Points are displayed as hex of their compressed encoding, coordinates are displayed as hex of
+//! their bytes representation. Scalars are displayed as decimal integers, and as hex of their
+//! big-endian encoding when formatted via [`LowerHex`](fmt::LowerHex) or [`UpperHex`](fmt::UpperHex).
+//!
+//! All formatting and parsing is done without allocations.
+
+use core::{fmt, str::FromStr};
+
+use crate::{
+    as_raw::AsRaw,
+    coords::Coordinate,
+    core::{ByteArray, IntegerEncoding},
+    errors::{InvalidPoint, InvalidScalar, ParseError},
+    Curve, NonZero, Point, Scalar, SecretScalar,
+};
+
+impl<E: Curve> fmt::Display for Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.to_bytes_compressed().as_ref(), false, false)
+    }
+}
+
+impl<E: Curve> fmt::LowerHex for Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.to_bytes_compressed().as_ref(), false, f.alternate())
+    }
+}
+
+impl<E: Curve> fmt::UpperHex for Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.to_bytes_compressed().as_ref(), true, f.alternate())
+    }
+}
+
+/// Parses a point from hex of its compressed or uncompressed encoding
+///
+/// Hex may be prefixed with `0x`.
+impl<E: Curve> FromStr for Point<E> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = E::UncompressedPointArray::zeroes();
+        let len = decode_hex(s, bytes.as_mut()).map_err(|err| match err {
+            DecodeHexError::Malformed => ParseError::MalformedHex,
+            DecodeHexError::TooLong => ParseError::InvalidPoint(InvalidPoint::InvalidLength),
+        })?;
+        Ok(Point::from_bytes(&bytes.as_ref()[..len])?)
+    }
+}
+
+/// Displays scalar as decimal integer
+impl<E: Curve> fmt::Display for Scalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bytes = self.as_raw().to_be_bytes();
+        write_decimal(f, bytes.as_mut())
+    }
+}
+
+impl<E: Curve> fmt::LowerHex for Scalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(
+            f,
+            self.as_raw().to_be_bytes().as_ref(),
+            false,
+            f.alternate(),
+        )
+    }
+}
+
+impl<E: Curve> fmt::UpperHex for Scalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.as_raw().to_be_bytes().as_ref(), true, f.alternate())
+    }
+}
+
+/// Parses a scalar from decimal integer, or from hex of its big-endian encoding if string
+/// is prefixed with `0x`
+///
+/// Returns error if integer is not less than group order.
+impl<E: Curve> FromStr for Scalar<E> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = E::ScalarArray::zeroes();
+        if let Some(hex) = strip_hex_prefix(s) {
+            let len = decode_hex(hex, bytes.as_mut()).map_err(|err| match err {
+                DecodeHexError::Malformed => ParseError::MalformedHex,
+                DecodeHexError::TooLong => ParseError::InvalidScalar(InvalidScalar::InvalidLength),
+            })?;
+            Ok(Scalar::from_be_bytes(&bytes.as_ref()[..len])?)
+        } else {
+            decode_decimal(s, bytes.as_mut())?;
+            Ok(Scalar::from_be_bytes(&bytes)?)
+        }
+    }
+}
+
+/// Never reveals the secret, always displayed as `<redacted>`
+impl<E: Curve> fmt::Display for SecretScalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl<E: Curve> fmt::Display for Coordinate<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.as_be_bytes(), false, false)
+    }
+}
+
+impl<E: Curve> fmt::LowerHex for Coordinate<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.as_be_bytes(), false, f.alternate())
+    }
+}
+
+impl<E: Curve> fmt::UpperHex for Coordinate<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.as_be_bytes(), true, f.alternate())
+    }
+}
+
+/// Parses a coordinate from hex of its bytes representation
+///
+/// Hex may be prefixed with `0x`.
+impl<E: Curve> FromStr for Coordinate<E> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = E::CoordinateArray::zeroes();
+        let len = decode_hex(s, bytes.as_mut()).map_err(|err| match err {
+            DecodeHexError::Malformed => ParseError::MalformedHex,
+            DecodeHexError::TooLong => ParseError::InvalidCoordinate,
+        })?;
+        if len != bytes.as_ref().len() {
+            return Err(ParseError::InvalidCoordinate);
+        }
+        Ok(Coordinate::new(bytes))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for NonZero<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), f)
+    }
+}
+
+impl<T: fmt::LowerHex> fmt::LowerHex for NonZero<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self.as_ref(), f)
+    }
+}
+
+impl<T: fmt::UpperHex> fmt::UpperHex for NonZero<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self.as_ref(), f)
+    }
+}
+
+/// Same as [`Point::from_str`], but returns [`InvalidPoint::Identity`] if point is zero
+impl<E: Curve> FromStr for NonZero<Point<E>> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NonZero::from_point(s.parse()?).ok_or(ParseError::InvalidPoint(InvalidPoint::Identity))
+    }
+}
+
+/// Same as [`Scalar::from_str`], but returns [`ParseError::ZeroScalar`] if scalar is zero
+impl<E: Curve> FromStr for NonZero<Scalar<E>> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NonZero::from_scalar(s.parse()?).ok_or(ParseError::ZeroScalar)
+    }
+}
+
+fn write_hex(f: &mut fmt::Formatter, bytes: &[u8], upper: bool, prefix: bool) -> fmt::Result {
+    if prefix {
+        f.write_str("0x")?;
+    }
+    bytes.iter().try_for_each(|byte| {
+        if upper {
+            write!(f, "{byte:02X}")
+        } else {
+            write!(f, "{byte:02x}")
+        }
+    })
+}
+
+/// Writes big-endian integer as decimal, `be_bytes` are used as scratch space
+fn write_decimal(f: &mut fmt::Formatter, be_bytes: &mut [u8]) -> fmt::Result {
+    /// Largest power of 10 that fits into `u64`
+    const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+    // Divide integer by `CHUNK` in place, remainder is the lowest 19 decimal digits
+    let mut remainder: u128 = 0;
+    for byte in be_bytes.iter_mut() {
+        let current = (remainder << 8) | u128::from(*byte);
+        // `current < CHUNK * 256`, so quotient always fits into a byte
+        *byte = (current / u128::from(CHUNK)) as u8;
+        remainder = current % u128::from(CHUNK);
+    }
+
+    if be_bytes.iter().all(|byte| *byte == 0) {
+        write!(f, "{remainder}")
+    } else {
+        write_decimal(f, be_bytes)?;
+        write!(f, "{remainder:019}")
+    }
+}
+
+/// Parses decimal integer into big-endian bytes `out`
+///
+/// Returns [`InvalidScalar::OutOfRange`] if integer doesn't fit into `out`
+fn decode_decimal(s: &str, out: &mut [u8]) -> Result<(), ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::MalformedDecimal);
+    }
+    for digit in s.bytes() {
+        if !digit.is_ascii_digit() {
+            return Err(ParseError::MalformedDecimal);
+        }
+        // out = out * 10 + digit
+        let mut carry = u16::from(digit - b'0');
+        for byte in out.iter_mut().rev() {
+            let value = u16::from(*byte) * 10 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            return Err(ParseError::InvalidScalar(InvalidScalar::OutOfRange));
+        }
+    }
+    Ok(())
+}
+
+enum DecodeHexError {
+    Malformed,
+    TooLong,
+}
+
+fn strip_hex_prefix(s: &str) -> Option<&str> {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))
+}
+
+/// Decodes hex (optionally prefixed with `0x`) into the beginning of `out`, returns amount
+/// of decoded bytes
+// `usize::is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn decode_hex(s: &str, out: &mut [u8]) -> Result<usize, DecodeHexError> {
+    let s = strip_hex_prefix(s).unwrap_or(s).as_bytes();
+    if s.is_empty() || s.len() % 2 != 0 {
+        return Err(DecodeHexError::Malformed);
+    }
+    let len = s.len() / 2;
+    let out = out.get_mut(..len).ok_or(DecodeHexError::TooLong)?;
+    for (byte, pair) in out.iter_mut().zip(s.chunks_exact(2)) {
+        *byte = (hex_digit(pair[0])? << 4) | hex_digit(pair[1])?;
+    }
+    Ok(len)
+}
+
+fn hex_digit(c: u8) -> Result<u8, DecodeHexError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(DecodeHexError::Malformed),
+    }
+}
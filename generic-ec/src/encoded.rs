@@ -67,6 +67,19 @@ impl<E: Curve> AsRef<[u8]> for EncodedPoint<E> {
     }
 }
 
+#[cfg(feature = "udigest")]
+impl<E: Curve> udigest::Digestable for EncodedPoint<E> {
+    fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
+    where
+        B: udigest::Buffer,
+    {
+        let mut s = encoder.encode_struct();
+        s.add_field("curve").encode_leaf_value(E::CURVE_NAME);
+        s.add_field("point").encode_leaf_value(self.as_bytes());
+        s.finish();
+    }
+}
+
 /// Bytes representation of a scalar (either in big-endian or in little-endian)
 #[derive(Clone)]
 pub struct EncodedScalar<E: Curve>(E::ScalarArray);
@@ -133,3 +146,16 @@ impl<E: Curve> AsRaw for EncodedScalar<E> {
         &self.0
     }
 }
+
+#[cfg(feature = "udigest")]
+impl<E: Curve> udigest::Digestable for EncodedScalar<E> {
+    fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
+    where
+        B: udigest::Buffer,
+    {
+        let mut s = encoder.encode_struct();
+        s.add_field("curve").encode_leaf_value(E::CURVE_NAME);
+        s.add_field("scalar").encode_leaf_value(self.as_bytes());
+        s.finish();
+    }
+}
@@ -19,12 +19,37 @@ impl fmt::Display for InvalidCoordinate {
 impl Error for InvalidCoordinate {}
 
 /// Indicates that point is not valid
-#[derive(Debug, Clone, Copy)]
-pub struct InvalidPoint;
+///
+/// Specifies a reason why point was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidPoint {
+    /// Encoded point has unexpected length
+    InvalidLength,
+    /// Bytes do not encode a point on the curve
+    ///
+    /// It's also returned when encoding is malformed (e.g. has unknown prefix), as backends
+    /// don't distinguish these cases.
+    NotOnCurve,
+    /// Point is on curve, but it has a small order (torsion) component
+    HasTorsion,
+    /// Point is valid, but its encoding is not canonical
+    ///
+    /// Only returned in strict decoding mode, e.g. by [`Point::from_bytes_strict`](crate::Point::from_bytes_strict)
+    NonCanonical,
+    /// Point is identity, which is not allowed
+    Identity,
+}
 
 impl fmt::Display for InvalidPoint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("invalid point")
+        f.write_str(match self {
+            Self::InvalidLength => "invalid point: invalid length",
+            Self::NotOnCurve => "invalid point: not on curve",
+            Self::HasTorsion => "invalid point: has torsion component",
+            Self::NonCanonical => "invalid point: non-canonical encoding",
+            Self::Identity => "invalid point: identity point is not allowed",
+        })
     }
 }
 
@@ -32,18 +57,376 @@ impl fmt::Display for InvalidPoint {
 impl Error for InvalidPoint {}
 
 /// Indicates that scalar is not valid
-#[derive(Debug, Clone, Copy)]
-pub struct InvalidScalar;
+///
+/// Specifies a reason why scalar was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidScalar {
+    /// Encoded scalar has unexpected length
+    InvalidLength,
+    /// Encoded integer is not less than group order
+    OutOfRange,
+}
 
 impl fmt::Display for InvalidScalar {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("invalid scalar")
+        f.write_str(match self {
+            Self::InvalidLength => "invalid scalar: invalid length",
+            Self::OutOfRange => "invalid scalar: integer is not less than group order",
+        })
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for InvalidScalar {}
 
+/// Indicates that provided output buffer is too small to fit encoded value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    pub(crate) required: usize,
+    pub(crate) provided: usize,
+}
+
+impl BufferTooSmall {
+    /// Minimal size of the buffer that can fit the value
+    pub fn required_len(&self) -> usize {
+        self.required
+    }
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer is too small: required {} bytes, provided {} bytes",
+            self.required, self.provided
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BufferTooSmall {}
+
+/// Indicates that string couldn't be parsed into point, scalar or coordinate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// String is not a valid hex
+    MalformedHex,
+    /// String is not a valid decimal integer
+    MalformedDecimal,
+    /// String encodes invalid point
+    InvalidPoint(InvalidPoint),
+    /// String encodes invalid scalar
+    InvalidScalar(InvalidScalar),
+    /// String encodes invalid coordinate
+    InvalidCoordinate,
+    /// String encodes zero scalar, which is not allowed
+    ZeroScalar,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MalformedHex => f.write_str("malformed hex"),
+            Self::MalformedDecimal => f.write_str("malformed decimal integer"),
+            Self::InvalidPoint(err) => fmt::Display::fmt(err, f),
+            Self::InvalidScalar(err) => fmt::Display::fmt(err, f),
+            Self::InvalidCoordinate => fmt::Display::fmt(&InvalidCoordinate, f),
+            Self::ZeroScalar => fmt::Display::fmt(&ZeroScalar, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidPoint(err) => Some(err),
+            Self::InvalidScalar(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<InvalidPoint> for ParseError {
+    fn from(err: InvalidPoint) -> Self {
+        Self::InvalidPoint(err)
+    }
+}
+
+impl From<InvalidScalar> for ParseError {
+    fn from(err: InvalidScalar) -> Self {
+        Self::InvalidScalar(err)
+    }
+}
+
+/// Error returned when encoding or decoding a key in SEC1, SPKI or PKCS#8 format
+///
+/// See [`pkcs`](crate::pkcs) module
+#[cfg(feature = "pkcs")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PkcsError {
+    /// DER structure is malformed or doesn't match the expected ASN.1 schema
+    MalformedDer,
+    /// PEM document is malformed
+    MalformedPem,
+    /// PEM document has unexpected label, e.g. private key was given while public key was expected
+    UnexpectedPemLabel,
+    /// Algorithm identifier or curve OID doesn't match the expected curve
+    AlgorithmMismatch,
+    /// Key contains invalid point
+    InvalidPoint(InvalidPoint),
+    /// Key contains invalid scalar
+    InvalidScalar(InvalidScalar),
+    /// Public key embedded into private key doesn't correspond to the private key
+    PublicKeyMismatch,
+    /// Key can't be represented in the requested format
+    Unsupported,
+}
+
+#[cfg(feature = "pkcs")]
+impl fmt::Display for PkcsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MalformedDer => f.write_str("malformed der"),
+            Self::MalformedPem => f.write_str("malformed pem"),
+            Self::UnexpectedPemLabel => f.write_str("unexpected pem label"),
+            Self::AlgorithmMismatch => f.write_str("key algorithm doesn't match the curve"),
+            Self::InvalidPoint(err) => fmt::Display::fmt(err, f),
+            Self::InvalidScalar(err) => fmt::Display::fmt(err, f),
+            Self::PublicKeyMismatch => {
+                f.write_str("public key doesn't correspond to the private key")
+            }
+            Self::Unsupported => f.write_str("key can't be represented in this format"),
+        }
+    }
+}
+
+#[cfg(all(feature = "pkcs", feature = "std"))]
+impl Error for PkcsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidPoint(err) => Some(err),
+            Self::InvalidScalar(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "pkcs")]
+impl From<InvalidPoint> for PkcsError {
+    fn from(err: InvalidPoint) -> Self {
+        Self::InvalidPoint(err)
+    }
+}
+
+#[cfg(feature = "pkcs")]
+impl From<InvalidScalar> for PkcsError {
+    fn from(err: InvalidScalar) -> Self {
+        Self::InvalidScalar(err)
+    }
+}
+
+#[cfg(feature = "pkcs")]
+impl From<der::Error> for PkcsError {
+    fn from(_err: der::Error) -> Self {
+        Self::MalformedDer
+    }
+}
+
+/// Error returned when converting a key to or from JWK or COSE_Key
+///
+/// See [`jwk`](crate::jwk) and [`cose`](crate::cose) modules
+#[cfg(any(feature = "jwk", feature = "cose"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeyFormatError {
+    /// Curve is not registered in JOSE/COSE registries
+    UnsupportedCurve,
+    /// Key type or curve specified in the key doesn't match the expected curve
+    CurveMismatch,
+    /// Key is malformed: invalid base64 or CBOR, or a required member is missing
+    Malformed,
+    /// Key contains invalid point
+    InvalidPoint(InvalidPoint),
+    /// Key contains invalid scalar
+    InvalidScalar(InvalidScalar),
+    /// Secret key was expected, but the key only contains public key
+    MissingSecretKey,
+    /// Public key doesn't correspond to the secret key
+    PublicKeyMismatch,
+    /// Key can't be represented in this format
+    Unsupported,
+}
+
+#[cfg(any(feature = "jwk", feature = "cose"))]
+impl fmt::Display for KeyFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedCurve => f.write_str("curve is not supported by key format"),
+            Self::CurveMismatch => f.write_str("key type or curve doesn't match the curve"),
+            Self::Malformed => f.write_str("malformed key"),
+            Self::InvalidPoint(err) => fmt::Display::fmt(err, f),
+            Self::InvalidScalar(err) => fmt::Display::fmt(err, f),
+            Self::MissingSecretKey => f.write_str("secret key is missing"),
+            Self::PublicKeyMismatch => {
+                f.write_str("public key doesn't correspond to the secret key")
+            }
+            Self::Unsupported => f.write_str("key can't be represented in this format"),
+        }
+    }
+}
+
+#[cfg(all(any(feature = "jwk", feature = "cose"), feature = "std"))]
+impl Error for KeyFormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidPoint(err) => Some(err),
+            Self::InvalidScalar(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(any(feature = "jwk", feature = "cose"))]
+impl From<InvalidPoint> for KeyFormatError {
+    fn from(err: InvalidPoint) -> Self {
+        Self::InvalidPoint(err)
+    }
+}
+
+#[cfg(any(feature = "jwk", feature = "cose"))]
+impl From<InvalidScalar> for KeyFormatError {
+    fn from(err: InvalidScalar) -> Self {
+        Self::InvalidScalar(err)
+    }
+}
+
+/// Error returned when encoding or decoding a key in OpenSSH format
+///
+/// See [`ssh`](crate::ssh) module
+#[cfg(feature = "ssh")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SshError {
+    /// Key is malformed
+    Malformed,
+    /// Key type doesn't match the expected curve
+    KeyTypeMismatch,
+    /// Private key is encrypted, which is not supported
+    Encrypted,
+    /// Key contains invalid point
+    InvalidPoint(InvalidPoint),
+    /// Key contains invalid scalar
+    InvalidScalar(InvalidScalar),
+    /// Public key doesn't correspond to the private key
+    PublicKeyMismatch,
+    /// Key can't be represented in this format
+    Unsupported,
+}
+
+#[cfg(feature = "ssh")]
+impl fmt::Display for SshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("malformed openssh key"),
+            Self::KeyTypeMismatch => f.write_str("key type doesn't match the curve"),
+            Self::Encrypted => f.write_str("encrypted openssh keys are not supported"),
+            Self::InvalidPoint(err) => fmt::Display::fmt(err, f),
+            Self::InvalidScalar(err) => fmt::Display::fmt(err, f),
+            Self::PublicKeyMismatch => {
+                f.write_str("public key doesn't correspond to the private key")
+            }
+            Self::Unsupported => f.write_str("key can't be represented in this format"),
+        }
+    }
+}
+
+#[cfg(all(feature = "ssh", feature = "std"))]
+impl Error for SshError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidPoint(err) => Some(err),
+            Self::InvalidScalar(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "ssh")]
+impl From<InvalidPoint> for SshError {
+    fn from(err: InvalidPoint) -> Self {
+        Self::InvalidPoint(err)
+    }
+}
+
+#[cfg(feature = "ssh")]
+impl From<InvalidScalar> for SshError {
+    fn from(err: InvalidScalar) -> Self {
+        Self::InvalidScalar(err)
+    }
+}
+
+/// Error returned when encrypting or decrypting a keystore
+///
+/// See [`keystore`](crate::keystore) module
+#[cfg(feature = "keystore")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeystoreError {
+    /// Keystore is malformed
+    Malformed,
+    /// Keystore version is not supported
+    UnsupportedVersion,
+    /// Key derivation function is not supported
+    UnsupportedKdf,
+    /// Cipher is not supported
+    UnsupportedCipher,
+    /// Key derivation function parameters are invalid
+    InvalidKdfParams,
+    /// Keystore holds a key on a different curve
+    CurveMismatch,
+    /// MAC doesn't match: either password is wrong or keystore is corrupted
+    WrongPassword,
+    /// Decrypted secret key is invalid
+    InvalidScalar(InvalidScalar),
+}
+
+#[cfg(feature = "keystore")]
+impl fmt::Display for KeystoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("malformed keystore"),
+            Self::UnsupportedVersion => f.write_str("unsupported keystore version"),
+            Self::UnsupportedKdf => f.write_str("unsupported key derivation function"),
+            Self::UnsupportedCipher => f.write_str("unsupported cipher"),
+            Self::InvalidKdfParams => f.write_str("invalid key derivation function parameters"),
+            Self::CurveMismatch => f.write_str("keystore holds a key on a different curve"),
+            Self::WrongPassword => f.write_str("wrong password or corrupted keystore"),
+            Self::InvalidScalar(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(all(feature = "keystore", feature = "std"))]
+impl Error for KeystoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidScalar(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "keystore")]
+impl From<InvalidScalar> for KeystoreError {
+    fn from(err: InvalidScalar) -> Self {
+        Self::InvalidScalar(err)
+    }
+}
+
 /// Indicates that [hash_to_curve](crate::hash_to_curve) primitive returned error
 #[derive(Debug, Clone, Copy)]
 pub struct HashError(pub(crate) HashErrorReason);
@@ -57,6 +440,8 @@ impl fmt::Display for HashError {
             HashErrorReason::ProducedValueInvalid => {
                 f.write_str("hash to curve/scalar produced invalid point/scalar")
             }
+            HashErrorReason::TagTooLong => f.write_str("tag is too long"),
+            HashErrorReason::TooManyGenerators => f.write_str("too many generators requested"),
         }
     }
 }
@@ -68,6 +453,10 @@ impl Error for HashError {}
 pub(crate) enum HashErrorReason {
     HashFailed,
     ProducedValueInvalid,
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    TagTooLong,
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    TooManyGenerators,
 }
 
 /// Appeared zero point is not expected/accepted
@@ -95,3 +484,97 @@ impl fmt::Display for ZeroScalar {
 
 #[cfg(feature = "std")]
 impl Error for ZeroScalar {}
+
+/// Indicates that values on different curves were mixed together
+///
+/// Returned by [`AnyPoint`](crate::any::AnyPoint), [`AnyScalar`](crate::any::AnyScalar) operations
+/// when operands are on different curves, or when converting `AnyPoint`/`AnyScalar` into
+/// `Point<E>`/`Scalar<E>` on a different curve.
+#[cfg(any(
+    feature = "curve-secp256k1",
+    feature = "curve-secp256r1",
+    feature = "curve-stark",
+    feature = "curve-ed25519"
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurveMismatch {
+    pub(crate) expected: crate::any::AnyCurve,
+    pub(crate) got: crate::any::AnyCurve,
+}
+
+#[cfg(any(
+    feature = "curve-secp256k1",
+    feature = "curve-secp256r1",
+    feature = "curve-stark",
+    feature = "curve-ed25519"
+))]
+impl CurveMismatch {
+    /// Curve that was expected
+    pub fn expected(&self) -> crate::any::AnyCurve {
+        self.expected
+    }
+    /// Curve that was actually given
+    pub fn got(&self) -> crate::any::AnyCurve {
+        self.got
+    }
+}
+
+#[cfg(any(
+    feature = "curve-secp256k1",
+    feature = "curve-secp256r1",
+    feature = "curve-stark",
+    feature = "curve-ed25519"
+))]
+impl fmt::Display for CurveMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "curve mismatch: expected {}, got {}",
+            self.expected, self.got
+        )
+    }
+}
+
+#[cfg(all(
+    any(
+        feature = "curve-secp256k1",
+        feature = "curve-secp256r1",
+        feature = "curve-stark",
+        feature = "curve-ed25519"
+    ),
+    feature = "std"
+))]
+impl Error for CurveMismatch {}
+
+/// Curve name is unknown, or the curve is not enabled via crate features
+#[cfg(any(
+    feature = "curve-secp256k1",
+    feature = "curve-secp256r1",
+    feature = "curve-stark",
+    feature = "curve-ed25519"
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownCurve;
+
+#[cfg(any(
+    feature = "curve-secp256k1",
+    feature = "curve-secp256r1",
+    feature = "curve-stark",
+    feature = "curve-ed25519"
+))]
+impl fmt::Display for UnknownCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown curve")
+    }
+}
+
+#[cfg(all(
+    any(
+        feature = "curve-secp256k1",
+        feature = "curve-secp256r1",
+        feature = "curve-stark",
+        feature = "curve-ed25519"
+    ),
+    feature = "std"
+))]
+impl Error for UnknownCurve {}
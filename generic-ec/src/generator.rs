@@ -54,3 +54,14 @@ impl<E: Curve> Default for Generator<E> {
         Self(PhantomType::new())
     }
 }
+
+/// Generator is digested in the same way as [`Point`]
+#[cfg(feature = "udigest")]
+impl<E: Curve> udigest::Digestable for Generator<E> {
+    fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
+    where
+        B: udigest::Buffer,
+    {
+        self.to_point().unambiguously_encode(encoder)
+    }
+}
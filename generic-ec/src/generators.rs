@@ -0,0 +1,166 @@
+//! Nothing-up-my-sleeve generators
+
+use alloc::vec::Vec;
+
+use crate::{
+    core::hash_to_curve::HashToCurve,
+    errors::{HashError, HashErrorReason},
+    hash_to_curve::{FromHash, Tag, TagBuf},
+    Curve, Point,
+};
+
+/// Independent generators with unknown discrete logs
+///
+/// Protocols like Pedersen commitments, Bulletproofs and vector commitments need extra
+/// generators $H_0, H_1, \dots$ such that nobody knows discrete log of any of them with respect
+/// to the curve generator or to each other. Such generators are derived deterministically via
+/// hash to curve, so anyone can verify that they were generated honestly.
+///
+/// ## Derivation
+/// Generator $H_i$ is derived as:
+///
+/// ```text
+/// H_i = hash_to_curve(msg = I2OSP(i, 8), DST = tag)
+/// ```
+///
+/// where `I2OSP(i, 8)` is the index encoded as 8 bytes big-endian integer, and `hash_to_curve`
+/// is the hash to curve suite used by the curve (see [`hash_to_curve`](crate::hash_to_curve)
+/// module), i.e. $H_i$ is the same as `Point::<E>::hash(tag, &i.to_be_bytes())`. Tag should be
+/// dedicated to generators derivation, and must not be used to hash anything else.
+///
+/// ## Example
+/// ```rust
+/// use generic_ec::{Generators, Point, Scalar, curves::Secp256k1};
+/// use generic_ec::hash_to_curve::{FromHash, Tag};
+///
+/// const TAG: Tag = Tag::new_unwrap(b"MYAPP-v0.1.0-pedersen-generators");
+///
+/// // Precompute 2 generators
+/// let generators = Generators::<Secp256k1>::new(TAG, 2)?;
+/// let h = generators[1];
+/// assert_eq!(h, Point::hash(TAG, &1u64.to_be_bytes())?);
+/// assert_eq!(h, Generators::derive(TAG, 1)?);
+///
+/// // Pedersen commitment to `x` with randomness `r`
+/// # let mut rng = rand::rngs::OsRng;
+/// let (x, r) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+/// let commitment = Point::generator() * x + h * r;
+/// # Ok::<_, generic_ec::errors::HashError>(())
+/// ```
+#[derive(Clone)]
+pub struct Generators<E: Curve> {
+    tag: TagBuf,
+    points: Vec<Point<E>>,
+}
+
+impl<E: Curve + HashToCurve> Generators<E> {
+    /// Derives first `n` generators
+    ///
+    /// Returns error if hash to curve failed, or if `tag` is longer than [`TagBuf::MAX_LEN`]
+    pub fn new(tag: Tag, n: usize) -> Result<Self, HashError> {
+        let tag = TagBuf::new(tag.as_bytes()).ok_or(HashError(HashErrorReason::TagTooLong))?;
+        let mut generators = Self {
+            tag,
+            points: Vec::new(),
+        };
+        generators.extend_to(n)?;
+        Ok(generators)
+    }
+
+    /// Derives $i$-th generator without precomputing the others
+    ///
+    /// Output is the same as `Generators::new(tag, index + 1)?[index]` would produce. Returns error
+    /// if hash to curve failed or produced the identity point.
+    pub fn derive(tag: Tag, index: u64) -> Result<Point<E>, HashError> {
+        let point = Point::hash(tag, &index.to_be_bytes())?;
+        if point.is_zero() {
+            return Err(HashError(HashErrorReason::ProducedValueInvalid));
+        }
+        Ok(point)
+    }
+
+    /// Makes sure that at least `n` generators are precomputed
+    ///
+    /// Derives missing generators, does nothing if `n` generators are already known
+    pub fn extend_to(&mut self, n: usize) -> Result<(), HashError> {
+        for i in self.points.len()..n {
+            let i = u64::try_from(i).map_err(|_| HashError(HashErrorReason::TooManyGenerators))?;
+            let point = Self::derive(self.tag.as_tag(), i)?;
+            self.points.push(point);
+        }
+        Ok(())
+    }
+
+    /// Returns $i$-th generator, derives it (and all generators before it) if it's not precomputed yet
+    ///
+    /// Returns error if hash to curve failed, or if `index` is `usize::MAX`
+    pub fn get_or_derive(&mut self, index: usize) -> Result<Point<E>, HashError> {
+        let n = index
+            .checked_add(1)
+            .ok_or(HashError(HashErrorReason::TooManyGenerators))?;
+        self.extend_to(n)?;
+        Ok(self.points[index])
+    }
+}
+
+impl<E: Curve> Generators<E> {
+    /// Tag that's used to derive generators
+    pub fn tag(&self) -> Tag<'_> {
+        self.tag.as_tag()
+    }
+
+    /// Returns $i$-th generator if it's precomputed
+    pub fn get(&self, index: usize) -> Option<Point<E>> {
+        self.points.get(index).copied()
+    }
+
+    /// Returns all precomputed generators
+    pub fn as_slice(&self) -> &[Point<E>] {
+        &self.points
+    }
+
+    /// Amount of precomputed generators
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Indicates whether no generators are precomputed
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Iterates over precomputed generators
+    pub fn iter(&self) -> core::slice::Iter<'_, Point<E>> {
+        self.points.iter()
+    }
+}
+
+impl<E: Curve> core::ops::Index<usize> for Generators<E> {
+    type Output = Point<E>;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.points[index]
+    }
+}
+
+impl<E: Curve> AsRef<[Point<E>]> for Generators<E> {
+    fn as_ref(&self) -> &[Point<E>] {
+        &self.points
+    }
+}
+
+impl<'a, E: Curve> IntoIterator for &'a Generators<E> {
+    type Item = &'a Point<E>;
+    type IntoIter = core::slice::Iter<'a, Point<E>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<E: Curve> core::fmt::Debug for Generators<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Generators")
+            .field("curve", &E::CURVE_NAME)
+            .field("len", &self.points.len())
+            .finish()
+    }
+}
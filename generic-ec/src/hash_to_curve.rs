@@ -16,15 +16,73 @@
 //!
 //! # Ok::<_, generic_ec::errors::HashError>(())
 //! ```
+//!
+//! ## Tag hierarchies
+//! Protocols that use hashing in several places should use distinct tag in each of them. [`TagBuf`]
+//! can be used to derive sub-tags from the protocol tag at compile time:
+//!
+//! ```rust
+//! use generic_ec::{Scalar, curves::Secp256k1};
+//! use generic_ec::hash_to_curve::{FromHash, TagBuf};
+//!
+//! const PROTOCOL: TagBuf = TagBuf::new_unwrap(b"MYPROTOCOL").with_version(1);
+//! const ROUND1: TagBuf = PROTOCOL.derive("round1");
+//!
+//! let challenge = Scalar::<Secp256k1>::hash(ROUND1.as_tag(), b"round 1 transcript")?;
+//! # Ok::<_, generic_ec::errors::HashError>(())
+//! ```
+//!
+//! ## Choosing hash to curve suite
+//! Hash function used by the primitives is determined by the curve. For instance, [`Secp256k1`] uses
+//! `expand_message_xmd` with SHA-256, while [`Secp256k1Sha512`] and [`Secp256k1Shake128`] use SHA-512
+//! and SHAKE128 respectively. All of them represent the same curve, so points can be converted from
+//! one to another via their bytes representation.
+//!
+//! [`Ed25519`] implements `edwards25519_XMD:SHA-512_ELL2_RO_` suite. [`Stark`] curve doesn't have
+//! a standard suite, so hashing is provided by [`StarkTryAndIncrement`] which represents the same
+//! curve and uses try-and-increment method with `expand_message_xmd` based on SHA-256.
+//! Try-and-increment is not constant-time, so it must not be used to hash secret data.
+//!
+//! ## Incremental hashing
+//! When the message isn't known upfront (e.g. it's a protocol transcript built piece by
+//! piece), it can be hashed incrementally via [`ScalarHasher`] and [`PointHasher`]. They
+//! output exactly the same values as [`FromHash::hash_concat`] would for the same chunks.
+//!
+//! ```rust
+//! use generic_ec::{Scalar, curves::Secp256k1};
+//! use generic_ec::hash_to_curve::{FromHash, ScalarHasher, Tag};
+//!
+//! const TAG: Tag = Tag::new_unwrap(b"MYAPP-v0.1.0");
+//!
+//! let mut hasher = ScalarHasher::<Secp256k1>::new(TAG);
+//! hasher.update(b"first chunk");
+//! hasher.update(b"second chunk");
+//! let scalar = hasher.finalize()?;
+//!
+//! assert_eq!(scalar, Scalar::hash_concat(TAG, &[b"first chunk", b"second chunk"])?);
+//! # Ok::<_, generic_ec::errors::HashError>(())
+//! ```
+//!
+//! Some protocols require nonuniform `encode_to_curve` primitive instead of `hash_to_curve`. It's
+//! provided by [`EncodeToCurve`] trait.
+//!
+//! [`Secp256k1`]: crate::curves::Secp256k1
+//! [`Ed25519`]: crate::curves::Ed25519
+//! [`Stark`]: crate::curves::Stark
+//! [`StarkTryAndIncrement`]: crate::curves::StarkTryAndIncrement
+//! [`Secp256k1Sha512`]: crate::curves::Secp256k1Sha512
+//! [`Secp256k1Shake128`]: crate::curves::Secp256k1Shake128
+
+use rand_core::RngCore;
 
 use crate::as_raw::{FromRaw, TryFromRaw};
-use crate::core::hash_to_curve::HashToCurve;
+use crate::core::hash_to_curve::{self as core_h2c, HashToCurve, IncrementalHashToCurve};
 use crate::core::Curve;
 use crate::errors::{HashError, HashErrorReason};
 use crate::{Point, Scalar};
 
 #[doc(inline)]
-pub use crate::core::hash_to_curve::Tag;
+pub use crate::core::hash_to_curve::{Tag, TagBuf};
 
 /// Hash to curve primitive
 pub trait FromHash
@@ -63,3 +121,154 @@ where
         Ok(Scalar::from_raw(scalar))
     }
 }
+
+impl<E: Curve + HashToCurve> Point<E> {
+    /// Deterministically maps a field element to the point on the curve
+    ///
+    /// Corresponds to `map_to_curve` followed by `clear_cofactor` defined in the spec. Field element
+    /// `u` is given as big-endian integer which is reduced modulo field order. Useful for protocols
+    /// (e.g. OPRFs) that compute field elements on their own.
+    ///
+    /// Returns error if the curve doesn't expose `map_to_curve` primitive (e.g.
+    /// [`StarkTryAndIncrement`] which uses try-and-increment method for hashing), or if `u` is
+    /// too long.
+    ///
+    /// [`StarkTryAndIncrement`]: crate::curves::StarkTryAndIncrement
+    pub fn map_to_curve(u: &[u8]) -> Result<Self, HashError> {
+        let point = E::map_to_curve(u).or(Err(HashError(HashErrorReason::HashFailed)))?;
+        Point::try_from_raw(point).ok_or(HashError(HashErrorReason::ProducedValueInvalid))
+    }
+
+    /// Samples a random point with unknown discrete log
+    ///
+    /// Unlike `Point::generator() * Scalar::random(rng)`, discrete log of the output is not known
+    /// to anyone, including the caller. Point is obtained by hashing 32 random bytes to the curve.
+    ///
+    /// Returns error if hash to curve failed, or if it produced the identity point 100 times in
+    /// a row.
+    pub fn random_unknown_dlog<R: RngCore>(rng: &mut R) -> Result<Self, HashError> {
+        const TAG: Tag = Tag::new_unwrap(b"generic-ec/random-unknown-dlog");
+        for _ in 0..100 {
+            let mut seed = [0u8; 32];
+            rng.fill_bytes(&mut seed);
+            let point = Point::hash(TAG, &seed)?;
+            if !point.is_zero() {
+                return Ok(point);
+            }
+        }
+        Err(HashError(HashErrorReason::ProducedValueInvalid))
+    }
+}
+
+/// Encode to curve primitive
+///
+/// Unlike [`FromHash`], output of encoding is not uniformly distributed over the curve, so
+/// it must not be used as random oracle. Only use it when protocol explicitly requires
+/// nonuniform `encode_to_curve` primitive.
+///
+/// ```rust
+/// use generic_ec::{Point, curves::Secp256r1};
+/// use generic_ec::hash_to_curve::{EncodeToCurve, Tag};
+///
+/// const TAG: Tag = Tag::new_unwrap(b"MYAPP-v0.1.0");
+///
+/// let point = Point::<Secp256r1>::encode(TAG, b"data to be encoded")?;
+/// # Ok::<_, generic_ec::errors::HashError>(())
+/// ```
+pub trait EncodeToCurve
+where
+    Self: Sized,
+{
+    /// Computes `encode_to_curve(message)`
+    #[inline]
+    fn encode(tag: Tag, message: &[u8]) -> Result<Self, HashError> {
+        Self::encode_concat(tag, &[message])
+    }
+    /// Computes `encode_to_curve(message[0] || ... || message[len - 1])`
+    fn encode_concat(tag: Tag, message: &[&[u8]]) -> Result<Self, HashError>;
+}
+
+impl<E> EncodeToCurve for Point<E>
+where
+    E: Curve + core_h2c::EncodeToCurve,
+{
+    #[inline]
+    fn encode_concat(tag: Tag, message: &[&[u8]]) -> Result<Self, HashError> {
+        let point =
+            E::encode_to_curve(tag, message).or(Err(HashError(HashErrorReason::HashFailed)))?;
+        Point::try_from_raw(point).ok_or(HashError(HashErrorReason::ProducedValueInvalid))
+    }
+}
+
+/// Incremental hash to scalar
+///
+/// Message can be fed chunk by chunk via [`update`](Self::update). Output is the same as
+/// [`Scalar::hash_concat`](FromHash::hash_concat) would produce for the same chunks.
+pub struct ScalarHasher<E: Curve + IncrementalHashToCurve>(E::Hasher);
+
+impl<E: Curve + IncrementalHashToCurve> ScalarHasher<E> {
+    /// Constructs a hasher with domain separation tag `tag`
+    pub fn new(tag: Tag) -> Self {
+        Self(E::new_hasher(tag))
+    }
+
+    /// Appends `data` to the hashed message
+    pub fn update(&mut self, data: &[u8]) {
+        E::update_hasher(&mut self.0, data)
+    }
+
+    /// Appends `data` to the hashed message, returns updated hasher
+    pub fn chain(mut self, data: &[u8]) -> Self {
+        self.update(data);
+        self
+    }
+
+    /// Outputs a scalar
+    pub fn finalize(self) -> Result<Scalar<E>, HashError> {
+        let scalar =
+            E::finalize_to_scalar(self.0).or(Err(HashError(HashErrorReason::HashFailed)))?;
+        Ok(Scalar::from_raw(scalar))
+    }
+}
+
+impl<E: Curve + IncrementalHashToCurve> Clone for ScalarHasher<E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Incremental hash to curve
+///
+/// Message can be fed chunk by chunk via [`update`](Self::update). Output is the same as
+/// [`Point::hash_concat`](FromHash::hash_concat) would produce for the same chunks.
+pub struct PointHasher<E: Curve + IncrementalHashToCurve>(E::Hasher);
+
+impl<E: Curve + IncrementalHashToCurve> PointHasher<E> {
+    /// Constructs a hasher with domain separation tag `tag`
+    pub fn new(tag: Tag) -> Self {
+        Self(E::new_hasher(tag))
+    }
+
+    /// Appends `data` to the hashed message
+    pub fn update(&mut self, data: &[u8]) {
+        E::update_hasher(&mut self.0, data)
+    }
+
+    /// Appends `data` to the hashed message, returns updated hasher
+    pub fn chain(mut self, data: &[u8]) -> Self {
+        self.update(data);
+        self
+    }
+
+    /// Outputs a point
+    pub fn finalize(self) -> Result<Point<E>, HashError> {
+        let point = E::finalize_to_curve(self.0).or(Err(HashError(HashErrorReason::HashFailed)))?;
+        Point::try_from_raw(point).ok_or(HashError(HashErrorReason::ProducedValueInvalid))
+    }
+}
+
+impl<E: Curve + IncrementalHashToCurve> Clone for PointHasher<E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
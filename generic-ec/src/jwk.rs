@@ -0,0 +1,243 @@
+//! JSON Web Key (JWK) import and export
+//!
+//! Keys are converted to and from [`Jwk`] which can be (de)serialized with any serde format.
+//! Curves are identified by [`Curve::CURVE_NAME`]:
+//!
+//! | Curve     | `kty` | `crv`       | Specification |
+//! |-----------|-------|-------------|---------------|
+//! | secp256r1 | `EC`  | `P-256`     | [RFC 7518]    |
+//! | secp256k1 | `EC`  | `secp256k1` | [RFC 8812]    |
+//! | ed25519   | `OKP` | `Ed25519`   | [RFC 8037]    |
+//!
+//! EC public keys are represented by affine $x, y$ coordinates, Ed25519 public key is represented
+//! by its compressed encoding. Secret key is stored in `d` member: EC secret key is a big-endian
+//! scalar, and Ed25519 secret key is a seed. Ed25519 seed is expanded into a secret scalar on
+//! import, and the expansion can't be reverted, so exporting [`SecretScalar<Ed25519>`](crate::curves::Ed25519)
+//! returns [`KeyFormatError::Unsupported`].
+//!
+//! ```rust
+//! use generic_ec::{Point, SecretScalar, curves::Secp256r1};
+//! # let mut rng = rand::rngs::OsRng;
+//!
+//! let secret_key = SecretScalar::<Secp256r1>::random(&mut rng);
+//! let public_key = Point::generator() * &secret_key;
+//!
+//! let jwk = public_key.to_jwk()?;
+//! assert_eq!(jwk.kty, "EC");
+//! assert_eq!(jwk.crv, "P-256");
+//! assert_eq!(Point::<Secp256r1>::from_jwk(&jwk)?, public_key);
+//!
+//! // Thumbprint identifies the key, and it's the same for public and secret JWKs
+//! let secret_jwk = secret_key.to_jwk()?;
+//! assert_eq!(secret_jwk.thumbprint()?, jwk.thumbprint()?);
+//! # Ok::<_, generic_ec::errors::KeyFormatError>(())
+//! ```
+//!
+//! [RFC 7518]: https://www.rfc-editor.org/rfc/rfc7518#section-6.2
+//! [RFC 8812]: https://www.rfc-editor.org/rfc/rfc8812#section-3.1
+//! [RFC 8037]: https://www.rfc-editor.org/rfc/rfc8037#section-2
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use zeroize::Zeroizing;
+
+use crate::{
+    coords::HasAffineXY,
+    errors::KeyFormatError,
+    key_format::{self, KeyType, NamedCurve, PublicKeyMembers},
+    Curve, Point, SecretScalar,
+};
+
+/// JSON Web Key
+///
+/// Only members that describe the key material are supported. Other members (e.g. `kid` or
+/// `alg`) are ignored when deserializing.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Jwk {
+    /// Key type: `EC` or `OKP`
+    pub kty: String,
+    /// Curve name
+    pub crv: String,
+    /// Base64url-encoded $x$ coordinate (for EC keys) or public key (for OKP keys)
+    pub x: String,
+    /// Base64url-encoded $y$ coordinate, present only in EC keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    /// Base64url-encoded secret key, present only in secret keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<Zeroizing<String>>,
+}
+
+impl Jwk {
+    /// Computes JWK thumbprint as defined in [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638)
+    ///
+    /// Thumbprint is SHA-256 hash of required public key members serialized into JSON in
+    /// lexicographic order. Secret key doesn't affect the thumbprint. Returns error if key
+    /// type is unknown or a required member is missing.
+    pub fn thumbprint(&self) -> Result<[u8; 32], KeyFormatError> {
+        use sha2::Digest;
+
+        let mut json = String::from("{");
+        push_member(&mut json, "crv", &self.crv);
+        json.push(',');
+        push_member(&mut json, "kty", &self.kty);
+        json.push(',');
+        push_member(&mut json, "x", &self.x);
+        match (self.kty.as_str(), &self.y) {
+            ("EC", Some(y)) => {
+                json.push(',');
+                push_member(&mut json, "y", y);
+            }
+            ("OKP", _) => (),
+            _ => return Err(KeyFormatError::Malformed),
+        }
+        json.push('}');
+
+        Ok(sha2::Sha256::digest(json.as_bytes()).into())
+    }
+
+    /// Returns the same key without secret part
+    pub fn to_public(&self) -> Self {
+        Self {
+            d: None,
+            ..self.clone()
+        }
+    }
+
+    fn check_curve<E: Curve>(&self) -> Result<NamedCurve, KeyFormatError> {
+        let curve = NamedCurve::of::<E>().ok_or(KeyFormatError::UnsupportedCurve)?;
+        if self.kty != kty(curve.key_type) || self.crv != curve.jwk_name {
+            return Err(KeyFormatError::CurveMismatch);
+        }
+        Ok(curve)
+    }
+
+    fn public_key<E: Curve>(&self, curve: NamedCurve) -> Result<Point<E>, KeyFormatError>
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        PublicKeyMembers {
+            x: decode_base64(&self.x)?,
+            y: self.y.as_deref().map(decode_base64).transpose()?,
+        }
+        .to_point(curve)
+    }
+
+    fn from_members(curve: NamedCurve, public_key: PublicKeyMembers, d: Option<String>) -> Self {
+        Self {
+            kty: kty(curve.key_type).into(),
+            crv: curve.jwk_name.into(),
+            x: encode_base64(&public_key.x),
+            y: public_key.y.as_deref().map(encode_base64),
+            d: d.map(Zeroizing::new),
+        }
+    }
+}
+
+impl fmt::Debug for Jwk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jwk")
+            .field("kty", &self.kty)
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("d", &self.d.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<E: Curve> Point<E>
+where
+    Point<E>: HasAffineXY<E>,
+{
+    /// Exports public key as JWK
+    ///
+    /// Returns error if curve is not supported, or if point is zero. See [`jwk`](crate::jwk) module.
+    pub fn to_jwk(&self) -> Result<Jwk, KeyFormatError> {
+        let curve = NamedCurve::of::<E>().ok_or(KeyFormatError::UnsupportedCurve)?;
+        let public_key = PublicKeyMembers::from_point(self, curve)?;
+        Ok(Jwk::from_members(curve, public_key, None))
+    }
+
+    /// Imports public key from JWK
+    ///
+    /// Secret part of the key, if present, is ignored.
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self, KeyFormatError> {
+        let curve = jwk.check_curve::<E>()?;
+        jwk.public_key(curve)
+    }
+}
+
+impl<E: Curve> SecretScalar<E>
+where
+    Point<E>: HasAffineXY<E>,
+{
+    /// Exports secret key as JWK
+    ///
+    /// Resulting JWK also contains the public key. Returns [`KeyFormatError::Unsupported`] for
+    /// Ed25519, see [`jwk`](crate::jwk) module.
+    pub fn to_jwk(&self) -> Result<Jwk, KeyFormatError> {
+        let curve = NamedCurve::of::<E>().ok_or(KeyFormatError::UnsupportedCurve)?;
+        let d = key_format::encode_secret_key(self, curve)?;
+        let public_key = PublicKeyMembers::from_point(&(Point::generator() * self), curve)?;
+        Ok(Jwk::from_members(
+            curve,
+            public_key,
+            Some(encode_base64(&d)),
+        ))
+    }
+
+    /// Imports secret key from JWK
+    ///
+    /// Checks that public key contained in JWK corresponds to the secret key.
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self, KeyFormatError> {
+        let curve = jwk.check_curve::<E>()?;
+        let d = jwk.d.as_ref().ok_or(KeyFormatError::MissingSecretKey)?;
+        let d = Zeroizing::new(decode_base64(d)?);
+        key_format::decode_secret_key(&d, Some(jwk.public_key(curve)?), curve)
+    }
+}
+
+fn kty(key_type: KeyType) -> &'static str {
+    match key_type {
+        KeyType::Ec => "EC",
+        KeyType::Okp => "OKP",
+    }
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)
+}
+
+fn decode_base64(s: &str) -> Result<Vec<u8>, KeyFormatError> {
+    // Padding is not allowed in JWK
+    if s.contains('=') {
+        return Err(KeyFormatError::Malformed);
+    }
+    base64::decode_config(s, base64::URL_SAFE_NO_PAD).map_err(|_| KeyFormatError::Malformed)
+}
+
+/// Appends `"name":"value"` to JSON, escaping the value as required by RFC 8259
+fn push_member(json: &mut String, name: &str, value: &str) {
+    json.push('"');
+    json.push_str(name);
+    json.push_str("\":\"");
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if u32::from(c) < 0x20 => {
+                json.push_str("\\u00");
+                json.push(char::from(
+                    b"0123456789abcdef"[(u32::from(c) >> 4) as usize],
+                ));
+                json.push(char::from(
+                    b"0123456789abcdef"[(u32::from(c) & 0xf) as usize],
+                ));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
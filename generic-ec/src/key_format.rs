@@ -0,0 +1,250 @@
+//! Helpers shared by key formats (PKCS, JWK, COSE_Key, OpenSSH)
+
+#[cfg(any(feature = "pkcs", feature = "ssh"))]
+use alloc::string::String;
+#[cfg(any(feature = "pkcs", feature = "jwk", feature = "cose", feature = "ssh"))]
+use alloc::vec::Vec;
+
+use zeroize::Zeroize;
+#[cfg(any(feature = "pkcs", feature = "jwk", feature = "cose", feature = "ssh"))]
+use zeroize::Zeroizing;
+
+#[cfg(any(feature = "jwk", feature = "cose"))]
+use crate::{
+    coords::{Coordinate, Coordinates, HasAffineXY},
+    errors::{InvalidPoint, KeyFormatError},
+    Point,
+};
+use crate::{errors::InvalidScalar, Curve, Scalar, SecretScalar};
+
+/// Type of the key, as distinguished by JOSE and COSE
+#[cfg(any(feature = "jwk", feature = "cose"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyType {
+    /// Short Weierstrass curve, public key is represented by affine $x, y$ coordinates
+    Ec,
+    /// Octet key pair, public key is represented by its compressed encoding
+    Okp,
+}
+
+/// Curve registered in JOSE and COSE registries
+#[cfg(any(feature = "jwk", feature = "cose"))]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NamedCurve {
+    pub key_type: KeyType,
+    /// `crv` value in JWK
+    #[cfg(feature = "jwk")]
+    pub jwk_name: &'static str,
+    /// `crv` value in COSE_Key
+    #[cfg(feature = "cose")]
+    pub cose_id: i64,
+}
+
+#[cfg(any(feature = "jwk", feature = "cose"))]
+impl NamedCurve {
+    /// Looks up the curve by its [name](Curve::CURVE_NAME)
+    pub fn of<E: Curve>() -> Option<Self> {
+        // Depending on enabled features, some of the names are not used
+        #[allow(unused_variables)]
+        let (key_type, jwk_name, cose_id) = match E::CURVE_NAME {
+            "secp256r1" => (KeyType::Ec, "P-256", 1),
+            "secp256k1" => (KeyType::Ec, "secp256k1", 8),
+            "ed25519" => (KeyType::Okp, "Ed25519", 6),
+            _ => return None,
+        };
+        Some(Self {
+            key_type,
+            #[cfg(feature = "jwk")]
+            jwk_name,
+            #[cfg(feature = "cose")]
+            cose_id,
+        })
+    }
+}
+
+/// Public key members of the key: `x` and, for EC keys, `y`
+#[cfg(any(feature = "jwk", feature = "cose"))]
+pub(crate) struct PublicKeyMembers {
+    pub x: Vec<u8>,
+    pub y: Option<Vec<u8>>,
+}
+
+#[cfg(any(feature = "jwk", feature = "cose"))]
+impl PublicKeyMembers {
+    pub fn from_point<E: Curve>(point: &Point<E>, curve: NamedCurve) -> Result<Self, KeyFormatError>
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        if point.is_zero() {
+            return Err(InvalidPoint::Identity.into());
+        }
+        match curve.key_type {
+            KeyType::Ec => {
+                let coords = point.coords().ok_or(InvalidPoint::Identity)?;
+                Ok(Self {
+                    x: coords.x.as_be_bytes().to_vec(),
+                    y: Some(coords.y.as_be_bytes().to_vec()),
+                })
+            }
+            KeyType::Okp => Ok(Self {
+                x: point.to_bytes(true).to_vec(),
+                y: None,
+            }),
+        }
+    }
+
+    pub fn to_point<E: Curve>(&self, curve: NamedCurve) -> Result<Point<E>, KeyFormatError>
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let point = match (curve.key_type, &self.y) {
+            (KeyType::Ec, Some(y)) => {
+                let coords = Coordinates {
+                    x: Coordinate::from_be_bytes(&self.x)
+                        .map_err(|_| InvalidPoint::InvalidLength)?,
+                    y: Coordinate::from_be_bytes(y).map_err(|_| InvalidPoint::InvalidLength)?,
+                };
+                Point::from_coords(&coords).ok_or(InvalidPoint::NotOnCurve)?
+            }
+            (KeyType::Okp, None) => {
+                if self.x.len() != Point::<E>::COMPRESSED_LEN {
+                    return Err(InvalidPoint::InvalidLength.into());
+                }
+                Point::from_bytes_strict(&self.x)?
+            }
+            _ => return Err(KeyFormatError::Malformed),
+        };
+        if point.is_zero() {
+            return Err(InvalidPoint::Identity.into());
+        }
+        Ok(point)
+    }
+}
+
+/// Encodes secret key as `d` member
+///
+/// EC keys are encoded as big-endian scalar. OKP keys store the seed which can't be
+/// recovered from the scalar, so encoding them is not supported.
+#[cfg(any(feature = "jwk", feature = "cose"))]
+pub(crate) fn encode_secret_key<E: Curve>(
+    secret_key: &SecretScalar<E>,
+    curve: NamedCurve,
+) -> Result<Zeroizing<Vec<u8>>, KeyFormatError> {
+    match curve.key_type {
+        KeyType::Ec => {
+            let mut bytes = secret_key.as_ref().to_be_bytes();
+            let d = Zeroizing::new(bytes.to_vec());
+            bytes.as_mut().zeroize();
+            Ok(d)
+        }
+        KeyType::Okp => Err(KeyFormatError::Unsupported),
+    }
+}
+
+/// Decodes secret key from `d` member, checks that it corresponds to the public key
+#[cfg(any(feature = "jwk", feature = "cose"))]
+pub(crate) fn decode_secret_key<E: Curve>(
+    d: &[u8],
+    public_key: Option<Point<E>>,
+    curve: NamedCurve,
+) -> Result<SecretScalar<E>, KeyFormatError> {
+    let secret_key = match curve.key_type {
+        KeyType::Ec => SecretScalar::<E>::from_be_bytes_strict(d)?,
+        KeyType::Okp => expand_ed25519_seed(d)?,
+    };
+    if crate::traits::IsZero::is_zero(secret_key.as_ref()) {
+        return Err(InvalidScalar::OutOfRange.into());
+    }
+    if let Some(public_key) = public_key {
+        if Point::generator() * &secret_key != public_key {
+            return Err(KeyFormatError::PublicKeyMismatch);
+        }
+    }
+    Ok(secret_key)
+}
+
+/// Error of decoding textual armor
+#[cfg(any(feature = "pkcs", feature = "ssh"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArmorError {
+    Malformed,
+    UnexpectedLabel,
+}
+
+/// Wraps binary data into PEM-like armor with given label and base64 lines length
+#[cfg(any(feature = "pkcs", feature = "ssh"))]
+pub(crate) fn encode_armor(label: &str, data: &[u8], line_len: usize) -> String {
+    let body = Zeroizing::new(base64::encode(data));
+    let mut armor =
+        String::with_capacity(body.len() + body.len() / line_len + 2 * label.len() + 40);
+    armor.push_str("-----BEGIN ");
+    armor.push_str(label);
+    armor.push_str("-----\n");
+    for line in body.as_bytes().chunks(line_len) {
+        // Base64 output is ASCII, so any chunk is a valid str
+        armor.extend(line.iter().map(|&c| char::from(c)));
+        armor.push('\n');
+    }
+    armor.push_str("-----END ");
+    armor.push_str(label);
+    armor.push_str("-----\n");
+    armor
+}
+
+/// Extracts binary data from PEM-like armor with given label
+///
+/// Headers (e.g. legacy encrypted PEM keys) are not supported.
+#[cfg(any(feature = "pkcs", feature = "ssh"))]
+pub(crate) fn decode_armor(label: &str, armor: &str) -> Result<Zeroizing<Vec<u8>>, ArmorError> {
+    let mut lines = armor.trim().lines().map(str::trim);
+    let begin = lines
+        .next()
+        .and_then(|l| l.strip_prefix("-----BEGIN "))
+        .and_then(|l| l.strip_suffix("-----"))
+        .ok_or(ArmorError::Malformed)?;
+
+    let mut body = Zeroizing::new(String::new());
+    let mut end = None;
+    for line in lines.by_ref() {
+        if let Some(l) = line.strip_prefix("-----END ") {
+            end = Some(l.strip_suffix("-----").ok_or(ArmorError::Malformed)?);
+            break;
+        }
+        if line.contains(':') {
+            return Err(ArmorError::Malformed);
+        }
+        body.push_str(line);
+    }
+    if end != Some(begin) || lines.next().is_some() {
+        return Err(ArmorError::Malformed);
+    }
+    if begin != label {
+        return Err(ArmorError::UnexpectedLabel);
+    }
+
+    base64::decode(body.as_bytes())
+        .map(Zeroizing::new)
+        .map_err(|_| ArmorError::Malformed)
+}
+
+/// Expands Ed25519 seed into secret scalar as defined in [RFC 8032](https://www.rfc-editor.org/rfc/rfc8032#section-5.1.5)
+///
+/// Ed25519 key formats store the seed rather than the scalar. `E` must be Ed25519 curve.
+pub(crate) fn expand_ed25519_seed<E: Curve>(seed: &[u8]) -> Result<SecretScalar<E>, InvalidScalar> {
+    use sha2::Digest;
+
+    if seed.len() != 32 {
+        return Err(InvalidScalar::InvalidLength);
+    }
+    let mut hash = sha2::Sha512::digest(seed);
+    let mut k = [0u8; 32];
+    k.copy_from_slice(&hash[..32]);
+    k[0] &= 0b1111_1000;
+    k[31] &= 0b0111_1111;
+    k[31] |= 0b0100_0000;
+
+    let mut scalar = Scalar::<E>::from_le_bytes_mod_order(k);
+    hash.as_mut_slice().zeroize();
+    k.zeroize();
+    Ok(SecretScalar::new(&mut scalar))
+}
@@ -0,0 +1,557 @@
+//! Password-encrypted keystore
+//!
+//! [`Keystore`] holds a [`SecretScalar`] encrypted with a password. Format follows [Web3 Secret
+//! Storage Definition] (Ethereum keystore v3): the password is stretched with scrypt (or
+//! PBKDF2-HMAC-SHA256, only supported on decryption), secret key is encrypted with AES-128-CTR
+//! and authenticated with Keccak-256 MAC. Secret key is stored as big-endian scalar.
+//!
+//! Alternatively, keystore can be encrypted with Argon2id and ChaCha20-Poly1305 AEAD (see
+//! [`SecretScalar::to_keystore_argon2id`]). Such keystore has the same structure, with `kdf`
+//! set to `argon2id` and `cipher` set to `chacha20-poly1305`, and `mac` member holding Poly1305
+//! tag. KDF and cipher are picked on decryption by `kdf` and `cipher` members. Note that Ethereum
+//! wallets only support scrypt/PBKDF2 and AES-128-CTR, so they can't decrypt such keystores.
+//!
+//! Keystore additionally carries the [curve name](Curve::CURVE_NAME) in `curve` member, which is
+//! ignored by Ethereum wallets. Keystores without `curve` member are assumed to hold a secp256k1
+//! key, so secp256k1 keystores are interchangeable with Ethereum wallets.
+//!
+//! ```rust
+//! use generic_ec::{SecretScalar, curves::Secp256k1, keystore::ScryptParams};
+//! # let mut rng = rand::rngs::OsRng;
+//!
+//! let secret_key = SecretScalar::<Secp256k1>::random(&mut rng);
+//! let keystore = secret_key.to_keystore_with_params(b"password", ScryptParams::LIGHT, &mut rng)?;
+//! assert_eq!(keystore.curve.as_deref(), Some("secp256k1"));
+//!
+//! let decrypted = SecretScalar::<Secp256k1>::from_keystore(&keystore, b"password")?;
+//! assert_eq!(decrypted.as_ref(), secret_key.as_ref());
+//! # Ok::<_, generic_ec::errors::KeystoreError>(())
+//! ```
+//!
+//! Keystore is (de)serialized with serde, use any JSON library to store it in a file.
+//!
+//! Scrypt parameters must satisfy [RFC 7914] (in particular, $n < 2^{16r}$), keystores with
+//! other parameters are rejected with [`KeystoreError::InvalidKdfParams`]. KDF parameters are
+//! also bounded by [`KdfLimits`], so a keystore from untrusted source can't make us spend
+//! arbitrary amount of time and memory. [`SecretScalar::from_keystore`] uses
+//! [default limits](KdfLimits::DEFAULT), use [`SecretScalar::from_keystore_with_limits`] to
+//! decrypt keystores with heavier parameters.
+//!
+//! [RFC 7914]: https://www.rfc-editor.org/rfc/rfc7914#section-2
+//! [Web3 Secret Storage Definition]: https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use rand_core::{CryptoRng, RngCore};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{errors::KeystoreError, traits::IsZero, Curve, SecretScalar};
+
+/// Keystore version
+const VERSION: u32 = 3;
+/// AES-128-CTR cipher with Keccak-256 MAC defined in keystore v3
+const CIPHER_AES_128_CTR: &str = "aes-128-ctr";
+/// ChaCha20-Poly1305 AEAD
+const CIPHER_CHACHA20_POLY1305: &str = "chacha20-poly1305";
+/// Length of derived key
+const DKLEN: u32 = 32;
+
+/// Password-encrypted secret key
+///
+/// See [`keystore`](crate::keystore) module.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Keystore {
+    /// Keystore version, always `3`
+    pub version: u32,
+    /// Random UUID identifying the keystore
+    pub id: String,
+    /// Curve name, absent in keystores created by Ethereum wallets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub curve: Option<String>,
+    /// Encrypted secret key
+    #[serde(alias = "Crypto")]
+    pub crypto: KeystoreCrypto,
+}
+
+/// Encrypted secret key and parameters needed to decrypt it
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct KeystoreCrypto {
+    /// Cipher name: `aes-128-ctr` or `chacha20-poly1305`
+    pub cipher: String,
+    /// Cipher parameters
+    pub cipherparams: CipherParams,
+    /// Hex-encoded encrypted secret key
+    pub ciphertext: String,
+    /// Key derivation function: `scrypt`, `pbkdf2` or `argon2id`
+    pub kdf: String,
+    /// Key derivation function parameters
+    pub kdfparams: KdfParams,
+    /// Hex-encoded MAC (Poly1305 tag for `chacha20-poly1305` cipher)
+    pub mac: String,
+}
+
+/// Cipher parameters
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CipherParams {
+    /// Hex-encoded initialization vector (nonce for `chacha20-poly1305` cipher)
+    pub iv: String,
+}
+
+/// Key derivation function parameters
+///
+/// `n`, `r`, `p` are present only for scrypt, `c` and `prf` are present only for PBKDF2, `m`,
+/// `t`, `p` are present only for Argon2id.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct KdfParams {
+    /// Length of derived key, always `32`
+    pub dklen: u32,
+    /// Hex-encoded salt
+    pub salt: String,
+    /// Scrypt CPU/memory cost
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<u64>,
+    /// Scrypt block size
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r: Option<u32>,
+    /// Scrypt or Argon2id parallelization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p: Option<u32>,
+    /// Argon2id memory cost in KiB
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m: Option<u32>,
+    /// Argon2id iterations count
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub t: Option<u32>,
+    /// PBKDF2 iterations count
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub c: Option<u32>,
+    /// PBKDF2 pseudo-random function, always `hmac-sha256`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prf: Option<String>,
+}
+
+/// Scrypt parameters used to encrypt the keystore
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScryptParams {
+    /// $\log_2$ of CPU/memory cost
+    pub log_n: u8,
+    /// Block size
+    pub r: u32,
+    /// Parallelization
+    pub p: u32,
+}
+
+impl ScryptParams {
+    /// Parameters used by Ethereum wallets by default ($n = 2^{18}, r = 8, p = 1$)
+    pub const STANDARD: Self = Self {
+        log_n: 18,
+        r: 8,
+        p: 1,
+    };
+    /// Parameters for weaker but faster encryption ($n = 2^{12}, r = 8, p = 6$)
+    pub const LIGHT: Self = Self {
+        log_n: 12,
+        r: 8,
+        p: 6,
+    };
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// Argon2id parameters used to encrypt the keystore
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Argon2Params {
+    /// Memory cost in KiB
+    pub m: u32,
+    /// Iterations count
+    pub t: u32,
+    /// Parallelization
+    pub p: u32,
+}
+
+impl Argon2Params {
+    /// Parameters recommended by OWASP ($m = 19 \text{ MiB}, t = 2, p = 1$)
+    pub const STANDARD: Self = Self {
+        m: 19 * 1024,
+        t: 2,
+        p: 1,
+    };
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// Upper bounds on KDF parameters accepted on decryption
+///
+/// Keystores with parameters exceeding the limits are rejected with
+/// [`KeystoreError::InvalidKdfParams`] before running the KDF.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfLimits {
+    /// Maximum scrypt CPU/memory cost $n$
+    pub max_scrypt_n: u64,
+    /// Maximum scrypt block size $r$
+    pub max_scrypt_r: u32,
+    /// Maximum scrypt parallelization $p$
+    pub max_scrypt_p: u32,
+    /// Maximum PBKDF2 iterations count $c$
+    pub max_pbkdf2_c: u32,
+    /// Maximum Argon2id memory cost $m$ in KiB
+    pub max_argon2_m: u32,
+    /// Maximum Argon2id iterations count $t$
+    pub max_argon2_t: u32,
+    /// Maximum Argon2id parallelization $p$
+    pub max_argon2_p: u32,
+}
+
+impl KdfLimits {
+    /// Default limits
+    ///
+    /// Scrypt: $n \le 2^{20}, r \le 32, p \le 16$. PBKDF2: $c \le 10^7$. Argon2id:
+    /// $m \le 2^{21}$ (2 GiB), $t \le 64$, $p \le 16$.
+    ///
+    /// Keystores created by Ethereum wallets and by [`SecretScalar::to_keystore`] fit into the
+    /// limits.
+    pub const DEFAULT: Self = Self {
+        max_scrypt_n: 1 << 20,
+        max_scrypt_r: 32,
+        max_scrypt_p: 16,
+        max_pbkdf2_c: 10_000_000,
+        max_argon2_m: 1 << 21,
+        max_argon2_t: 64,
+        max_argon2_p: 16,
+    };
+}
+
+impl Default for KdfLimits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl<E: Curve> SecretScalar<E> {
+    /// Encrypts secret key with a password using [standard](ScryptParams::STANDARD) scrypt parameters
+    pub fn to_keystore<R: RngCore + CryptoRng>(
+        &self,
+        password: &[u8],
+        rng: &mut R,
+    ) -> Result<Keystore, KeystoreError> {
+        self.to_keystore_with_params(password, ScryptParams::STANDARD, rng)
+    }
+
+    /// Encrypts secret key with a password using given scrypt parameters
+    pub fn to_keystore_with_params<R: RngCore + CryptoRng>(
+        &self,
+        password: &[u8],
+        params: ScryptParams,
+        rng: &mut R,
+    ) -> Result<Keystore, KeystoreError> {
+        let mut salt = [0u8; 32];
+        rng.fill_bytes(&mut salt);
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let derived_key = scrypt(password, &salt, params)?;
+        let mut ciphertext = self.to_plaintext();
+        apply_keystream(&derived_key, &iv, &mut ciphertext);
+        let mac = mac(&derived_key, &ciphertext);
+
+        let kdfparams = KdfParams {
+            n: Some(1 << params.log_n),
+            r: Some(params.r),
+            p: Some(params.p),
+            ..KdfParams::new(&salt)
+        };
+        Ok(Keystore::new::<E, _>(
+            CIPHER_AES_128_CTR,
+            &iv,
+            &ciphertext,
+            "scrypt",
+            kdfparams,
+            &mac,
+            rng,
+        ))
+    }
+
+    /// Encrypts secret key with a password using Argon2id and ChaCha20-Poly1305
+    ///
+    /// Resulting keystore can't be decrypted by Ethereum wallets, see [module](crate::keystore)
+    /// docs.
+    pub fn to_keystore_argon2id<R: RngCore + CryptoRng>(
+        &self,
+        password: &[u8],
+        params: Argon2Params,
+        rng: &mut R,
+    ) -> Result<Keystore, KeystoreError> {
+        use chacha20poly1305::{aead::AeadInPlace, KeyInit};
+
+        let mut salt = [0u8; 32];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; 12];
+        rng.fill_bytes(&mut nonce);
+
+        let derived_key = argon2id(password, &salt, params)?;
+        let mut ciphertext = self.to_plaintext();
+        let tag = chacha20poly1305::ChaCha20Poly1305::new(derived_key.as_ref().into())
+            .encrypt_in_place_detached(&nonce.into(), &[], &mut ciphertext)
+            .map_err(|_| KeystoreError::Malformed)?;
+
+        let kdfparams = KdfParams {
+            m: Some(params.m),
+            t: Some(params.t),
+            p: Some(params.p),
+            ..KdfParams::new(&salt)
+        };
+        Ok(Keystore::new::<E, _>(
+            CIPHER_CHACHA20_POLY1305,
+            &nonce,
+            &ciphertext,
+            "argon2id",
+            kdfparams,
+            &tag,
+            rng,
+        ))
+    }
+
+    /// Big-endian bytes of secret scalar
+    fn to_plaintext(&self) -> Zeroizing<Vec<u8>> {
+        let mut secret_key = self.as_ref().to_be_bytes();
+        let plaintext = Zeroizing::new(Vec::from(secret_key.as_bytes()));
+        secret_key.as_mut().zeroize();
+        plaintext
+    }
+
+    /// Decrypts secret key from the keystore
+    ///
+    /// Returns [`KeystoreError::WrongPassword`] if password is wrong. KDF parameters must not
+    /// exceed [default limits](KdfLimits::DEFAULT).
+    pub fn from_keystore(keystore: &Keystore, password: &[u8]) -> Result<Self, KeystoreError> {
+        Self::from_keystore_with_limits(keystore, password, KdfLimits::DEFAULT)
+    }
+
+    /// Decrypts secret key from the keystore, KDF parameters must not exceed given limits
+    ///
+    /// Returns [`KeystoreError::WrongPassword`] if password is wrong.
+    pub fn from_keystore_with_limits(
+        keystore: &Keystore,
+        password: &[u8],
+        limits: KdfLimits,
+    ) -> Result<Self, KeystoreError> {
+        if keystore.version != VERSION {
+            return Err(KeystoreError::UnsupportedVersion);
+        }
+        // Keystores without curve name are created by Ethereum wallets
+        if keystore.curve.as_deref().unwrap_or("secp256k1") != E::CURVE_NAME {
+            return Err(KeystoreError::CurveMismatch);
+        }
+        let crypto = &keystore.crypto;
+        if crypto.cipher != CIPHER_AES_128_CTR && crypto.cipher != CIPHER_CHACHA20_POLY1305 {
+            return Err(KeystoreError::UnsupportedCipher);
+        }
+
+        let derived_key = derive_key(&crypto.kdf, &crypto.kdfparams, limits, password)?;
+
+        let ciphertext = decode_hex(&crypto.ciphertext)?;
+        let expected_mac = decode_hex(&crypto.mac)?;
+        let iv = decode_hex(&crypto.cipherparams.iv)?;
+        let mut plaintext = Zeroizing::new(ciphertext);
+        if crypto.cipher == CIPHER_AES_128_CTR {
+            if !bool::from(subtle::ConstantTimeEq::ct_eq(
+                &mac(&derived_key, &plaintext)[..],
+                &expected_mac[..],
+            )) {
+                return Err(KeystoreError::WrongPassword);
+            }
+            let iv: [u8; 16] = iv.try_into().map_err(|_| KeystoreError::Malformed)?;
+            apply_keystream(&derived_key, &iv, &mut plaintext);
+        } else {
+            use chacha20poly1305::{aead::AeadInPlace, KeyInit};
+
+            let nonce: [u8; 12] = iv.try_into().map_err(|_| KeystoreError::Malformed)?;
+            let tag: [u8; 16] = expected_mac
+                .try_into()
+                .map_err(|_| KeystoreError::Malformed)?;
+            chacha20poly1305::ChaCha20Poly1305::new(derived_key.as_ref().into())
+                .decrypt_in_place_detached(&nonce.into(), &[], &mut plaintext, &tag.into())
+                .map_err(|_| KeystoreError::WrongPassword)?;
+        }
+
+        let secret_key = SecretScalar::from_be_bytes_strict(&plaintext)?;
+        if secret_key.as_ref().is_zero() {
+            return Err(crate::errors::InvalidScalar::OutOfRange.into());
+        }
+        Ok(secret_key)
+    }
+}
+
+fn derive_key(
+    kdf: &str,
+    params: &KdfParams,
+    limits: KdfLimits,
+    password: &[u8],
+) -> Result<Zeroizing<[u8; 32]>, KeystoreError> {
+    if params.dklen != DKLEN {
+        return Err(KeystoreError::InvalidKdfParams);
+    }
+    let salt = decode_hex(&params.salt)?;
+    match kdf {
+        "scrypt" => {
+            let (Some(n), Some(r), Some(p)) = (params.n, params.r, params.p) else {
+                return Err(KeystoreError::Malformed);
+            };
+            if !n.is_power_of_two() || n < 2 {
+                return Err(KeystoreError::InvalidKdfParams);
+            }
+            if n > limits.max_scrypt_n || r > limits.max_scrypt_r || p > limits.max_scrypt_p {
+                return Err(KeystoreError::InvalidKdfParams);
+            }
+            let log_n = u8::try_from(n.trailing_zeros()).map_err(|_| KeystoreError::Malformed)?;
+            scrypt(password, &salt, ScryptParams { log_n, r, p })
+        }
+        "pbkdf2" => {
+            let Some(c) = params.c else {
+                return Err(KeystoreError::Malformed);
+            };
+            if params.prf.as_deref() != Some("hmac-sha256") {
+                return Err(KeystoreError::UnsupportedKdf);
+            }
+            if c == 0 || c > limits.max_pbkdf2_c {
+                return Err(KeystoreError::InvalidKdfParams);
+            }
+            let mut derived_key = Zeroizing::new([0u8; 32]);
+            pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password, &salt, c, derived_key.as_mut());
+            Ok(derived_key)
+        }
+        "argon2id" => {
+            let (Some(m), Some(t), Some(p)) = (params.m, params.t, params.p) else {
+                return Err(KeystoreError::Malformed);
+            };
+            if m > limits.max_argon2_m || t > limits.max_argon2_t || p > limits.max_argon2_p {
+                return Err(KeystoreError::InvalidKdfParams);
+            }
+            argon2id(password, &salt, Argon2Params { m, t, p })
+        }
+        _ => Err(KeystoreError::UnsupportedKdf),
+    }
+}
+
+fn scrypt(
+    password: &[u8],
+    salt: &[u8],
+    params: ScryptParams,
+) -> Result<Zeroizing<[u8; 32]>, KeystoreError> {
+    let params = scrypt::Params::new(params.log_n, params.r, params.p, DKLEN as usize)
+        .map_err(|_| KeystoreError::InvalidKdfParams)?;
+    let mut derived_key = Zeroizing::new([0u8; 32]);
+    scrypt::scrypt(password, salt, &params, derived_key.as_mut())
+        .map_err(|_| KeystoreError::InvalidKdfParams)?;
+    Ok(derived_key)
+}
+
+fn argon2id(
+    password: &[u8],
+    salt: &[u8],
+    params: Argon2Params,
+) -> Result<Zeroizing<[u8; 32]>, KeystoreError> {
+    let params = argon2::Params::new(params.m, params.t, params.p, Some(DKLEN as usize))
+        .map_err(|_| KeystoreError::InvalidKdfParams)?;
+    let argon2 = argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+    let mut derived_key = Zeroizing::new([0u8; 32]);
+    argon2
+        .hash_password_into(password, salt, derived_key.as_mut())
+        .map_err(|_| KeystoreError::InvalidKdfParams)?;
+    Ok(derived_key)
+}
+
+/// Encrypts or decrypts `data` in place with AES-128-CTR keyed by first half of derived key
+fn apply_keystream(derived_key: &[u8; 32], iv: &[u8; 16], data: &mut [u8]) {
+    use aes::cipher::{KeyIvInit, StreamCipher};
+
+    let mut cipher = ctr::Ctr128BE::<aes::Aes128>::new(derived_key[..16].into(), iv.into());
+    cipher.apply_keystream(data);
+}
+
+/// Computes `keccak256(derived_key[16..32] || ciphertext)`
+fn mac(derived_key: &[u8; 32], ciphertext: &[u8]) -> [u8; 32] {
+    use sha3::Digest;
+
+    sha3::Keccak256::new()
+        .chain_update(&derived_key[16..])
+        .chain_update(ciphertext)
+        .finalize()
+        .into()
+}
+
+impl Keystore {
+    fn new<E: Curve, R: RngCore>(
+        cipher: &str,
+        iv: &[u8],
+        ciphertext: &[u8],
+        kdf: &str,
+        kdfparams: KdfParams,
+        mac: &[u8],
+        rng: &mut R,
+    ) -> Self {
+        Keystore {
+            version: VERSION,
+            id: random_uuid(rng),
+            curve: Some(E::CURVE_NAME.into()),
+            crypto: KeystoreCrypto {
+                cipher: cipher.into(),
+                cipherparams: CipherParams {
+                    iv: hex::encode(iv),
+                },
+                ciphertext: hex::encode(ciphertext),
+                kdf: kdf.into(),
+                kdfparams,
+                mac: hex::encode(mac),
+            },
+        }
+    }
+}
+
+impl KdfParams {
+    /// KDF parameters with given salt and no KDF-specific parameters set
+    fn new(salt: &[u8]) -> Self {
+        Self {
+            dklen: DKLEN,
+            salt: hex::encode(salt),
+            n: None,
+            r: None,
+            p: None,
+            m: None,
+            t: None,
+            c: None,
+            prf: None,
+        }
+    }
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, KeystoreError> {
+    hex::decode(s).map_err(|_| KeystoreError::Malformed)
+}
+
+/// Generates random UUID v4
+fn random_uuid<R: RngCore>(rng: &mut R) -> String {
+    let mut bytes = [0u8; 16];
+    rng.fill_bytes(&mut bytes);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let mut uuid = String::with_capacity(36);
+    for (i, byte) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            uuid.push('-');
+        }
+        // Writing to a string never fails
+        let _ = fmt::Write::write_fmt(&mut uuid, format_args!("{byte:02x}"));
+    }
+    uuid
+}
@@ -95,6 +95,11 @@
 //! let random_point: Point<Secp256k1> = Point::generator() * Scalar::random(&mut rng);
 //! ```
 //!
+//! ### Curve chosen at runtime
+//!
+//! If the curve is only known at runtime, points and scalars on any of the enabled curves can be
+//! handled via [`AnyPoint`](any::AnyPoint), [`AnyScalar`](any::AnyScalar) from [`any`] module.
+//!
 //! ### Adding support for other curves
 //!
 //! Adding new curve is as easy as implementing [`Curve` trait](Curve)! If you're missing some curve support,
@@ -106,6 +111,19 @@
 //! * `curve-{name}` enables specified curve support. See list of [supported curves].
 //! * `all-curves` enables all supported curves
 //! * `serde` enables points/scalar (de)serialization support. (enabled by default)
+//! * `base64`, `base58` enable [`serde::Base64`] and [`serde::Base58`] helpers that serialize
+//!   points/scalars as base64/base58 strings
+//! * `schemars` implements [`JsonSchema`](https://docs.rs/schemars) for serialized points/scalars,
+//!   see [`serde::SchemaAs`] for schemas of compact formats
+//! * `borsh`, `scale`, `rkyv` enable points/scalars encoding via [Borsh](https://borsh.io),
+//!   [SCALE](https://docs.rs/parity-scale-codec) and [rkyv](https://rkyv.org) codecs. Points are
+//!   encoded in compressed form, scalars are encoded as big-endian fixed-size bytes.
+//! * `pkcs` enables SEC1, SPKI and PKCS#8 key encodings in DER and PEM forms, see [`pkcs`] module
+//! * `jwk` enables JSON Web Key import and export, see [`jwk`] module
+//! * `cose` enables `COSE_Key` import and export, see [`cose`] module
+//! * `ssh` enables OpenSSH public and private key formats, see [`ssh`] module
+//! * `keystore` enables password-encrypted keystores, see [`keystore`] module
+//! * `macros` enables [`scalar!`] and [`point!`] macros that validate literals at compile time
 //! * `std` enables support of standard library (enabled by default)
 //! * `wasm` eanbles support for `wasm32-unknown-unknown` target
 //!
@@ -186,17 +204,45 @@ extern crate alloc;
 
 pub use generic_ec_core as core;
 
+#[cfg(any(
+    feature = "curve-secp256k1",
+    feature = "curve-secp256r1",
+    feature = "curve-stark",
+    feature = "curve-ed25519"
+))]
+pub mod any;
 mod arithmetic;
 pub mod as_raw;
+#[cfg(any(feature = "borsh", feature = "scale", feature = "rkyv"))]
+mod codecs;
 pub mod coords;
+#[cfg(feature = "cose")]
+pub mod cose;
+mod display;
 mod encoded;
 pub mod errors;
 mod generator;
+#[cfg(feature = "alloc")]
+mod generators;
 pub mod hash_to_curve;
+#[cfg(feature = "jwk")]
+pub mod jwk;
+#[cfg(any(feature = "pkcs", feature = "jwk", feature = "cose", feature = "ssh"))]
+mod key_format;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+#[cfg(feature = "macros")]
+mod macros;
 mod non_zero;
+#[cfg(feature = "pkcs")]
+pub mod pkcs;
 mod point;
 mod scalar;
 mod secret_scalar;
+#[cfg(feature = "ssh")]
+pub mod ssh;
+#[cfg(feature = "curve-ed25519")]
+pub mod x25519;
 
 /// Common traits for points and scalars
 pub mod traits {
@@ -212,12 +258,25 @@ pub mod traits {
 
 pub mod serde;
 
+/// Implementation details used by macros, not a part of public API
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::{
+        point_from_validated_bytes, scalar_from_validated_be_bytes, Literal, LiteralCurve,
+        LiteralTable,
+    };
+    pub use generic_ec_macros::{point_impl, scalar_impl};
+}
+
+#[cfg(feature = "alloc")]
+pub use self::generators::Generators;
 pub use self::{
     core::Curve,
     encoded::{EncodedPoint, EncodedScalar},
     generator::Generator,
     non_zero::definition::NonZero,
-    point::definition::Point,
+    point::{definition::Point, full_group::FullGroupPoint, x_only::XOnlyPoint},
     scalar::Scalar,
     secret_scalar::definition::SecretScalar,
 };
@@ -229,11 +288,11 @@ pub mod curves {
     pub use generic_ec_curves::Ed25519;
     #[cfg(feature = "curve-secp256k1")]
     #[cfg_attr(docsrs, doc(cfg(feature = "curve-secp256k1")))]
-    pub use generic_ec_curves::Secp256k1;
+    pub use generic_ec_curves::{Secp256k1, Secp256k1Sha512, Secp256k1Shake128, Secp256k1Shake256};
     #[cfg(feature = "curve-secp256r1")]
     #[cfg_attr(docsrs, doc(cfg(feature = "curve-secp256r1")))]
-    pub use generic_ec_curves::Secp256r1;
+    pub use generic_ec_curves::{Secp256r1, Secp256r1Sha512, Secp256r1Shake128, Secp256r1Shake256};
     #[cfg(feature = "curve-stark")]
     #[cfg_attr(docsrs, doc(cfg(feature = "curve-stark")))]
-    pub use generic_ec_curves::Stark;
+    pub use generic_ec_curves::{Stark, StarkTryAndIncrement};
 }
@@ -0,0 +1,203 @@
+//! Compile-time scalar and point literals
+
+use core::marker::PhantomData;
+
+use crate::as_raw::FromRaw;
+use crate::core::{ByteArray, Decode, IntegerEncoding, OnCurve, SmallFactor};
+use crate::{Curve, Point, Scalar};
+
+/// Scalar literal validated at compile time
+///
+/// Takes a curve and a string literal, which is either a decimal integer or hex of scalar
+/// big-endian bytes representation prefixed with `0x`. Expands into an expression of type
+/// [`Scalar<E>`](crate::Scalar). Literal is validated at build time, so no runtime error
+/// handling is needed.
+///
+/// ```rust
+/// use generic_ec::{scalar, Scalar, curves::Secp256k1};
+///
+/// let forty_two = scalar!(Secp256k1, "42");
+/// assert_eq!(forty_two, Scalar::<Secp256k1>::from(42));
+///
+/// let minus_one = scalar!(
+///     Secp256k1,
+///     "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"
+/// );
+/// assert_eq!(minus_one, -Scalar::<Secp256k1>::one());
+/// ```
+///
+/// Literal that's not a valid scalar is a compile error:
+///
+/// ```rust,compile_fail
+/// use generic_ec::{scalar, curves::Secp256k1};
+///
+/// // Group order is not a valid scalar
+/// let q = scalar!(
+///     Secp256k1,
+///     "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+/// );
+/// ```
+///
+/// Only curves supported out of the box (and enabled via `curve-*` features) can be used with
+/// this macro. Any path or type alias referring to the curve works.
+#[macro_export]
+macro_rules! scalar {
+    ($curve:ty, $literal:literal $(,)?) => {
+        $crate::__private::scalar_impl!($crate, $curve, $literal)
+    };
+}
+
+/// Point literal validated at compile time
+///
+/// Takes a curve and a string literal with hex of compressed or uncompressed point encoding,
+/// optionally prefixed with `0x`. Expands into an expression of type [`Point<E>`](crate::Point).
+/// At build time, it's validated that literal is a canonical encoding of a valid point (see
+/// [`Point::from_bytes_strict`](crate::Point::from_bytes_strict)), so no runtime error handling is needed.
+///
+/// ```rust
+/// use generic_ec::{point, Point, curves::Secp256r1};
+///
+/// let g = point!(
+///     Secp256r1,
+///     "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+/// );
+/// assert_eq!(g, Point::<Secp256r1>::generator().to_point());
+/// ```
+///
+/// Invalid point is a compile error:
+///
+/// ```rust,compile_fail
+/// use generic_ec::{point, curves::Secp256r1};
+///
+/// // x coordinate is larger than field modulus
+/// let p = point!(
+///     Secp256r1,
+///     "02ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+/// );
+/// ```
+///
+/// Same as with [`scalar!`](crate::scalar), only curves supported out of the box can be used.
+#[macro_export]
+macro_rules! point {
+    ($curve:ty, $literal:literal $(,)?) => {
+        $crate::__private::point_impl!($crate, $curve, $literal)
+    };
+}
+
+/// Result of validating a literal on a specific curve
+#[doc(hidden)]
+pub struct Literal {
+    pub curve: &'static str,
+    pub bytes: &'static [u8],
+    pub error: Option<&'static str>,
+}
+
+/// Results of validating a literal on every curve known to the proc-macro
+#[doc(hidden)]
+pub trait LiteralTable {
+    const TABLE: &'static [Literal];
+}
+
+/// Curve supported by [`scalar!`] and [`point!`] macros
+///
+/// Implemented only for curves that the proc-macro validates literals on. Validation result is
+/// picked by [`CURVE_NAME`](Curve::CURVE_NAME), and since the trait is sealed, a third-party curve
+/// that happens to reuse the name of a built-in curve can't be used with the macros.
+#[doc(hidden)]
+pub trait LiteralCurve: Curve + sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_literal_curve {
+    ($($feature:literal => [$($curve:ident),+ $(,)?]),+ $(,)?) => {$($(
+        #[cfg(feature = $feature)]
+        impl sealed::Sealed for crate::curves::$curve {}
+        #[cfg(feature = $feature)]
+        impl LiteralCurve for crate::curves::$curve {}
+    )+)+};
+}
+
+impl_literal_curve! {
+    "curve-secp256k1" => [Secp256k1, Secp256k1Sha512, Secp256k1Shake128, Secp256k1Shake256],
+    "curve-secp256r1" => [Secp256r1, Secp256r1Sha512, Secp256r1Shake128, Secp256r1Shake256],
+    "curve-stark" => [Stark, StarkTryAndIncrement],
+    "curve-ed25519" => [Ed25519],
+}
+
+struct Validated<E, L>(PhantomData<(E, L)>);
+
+impl<E: LiteralCurve, L: LiteralTable> Validated<E, L> {
+    /// Evaluated at compile time, so invalid literal is a build error
+    const BYTES: &'static [u8] = lookup(L::TABLE, E::CURVE_NAME);
+}
+
+const fn lookup(table: &'static [Literal], curve: &str) -> &'static [u8] {
+    let mut i = 0;
+    while i < table.len() {
+        if str_eq(table[i].curve, curve) {
+            return match table[i].error {
+                Some(err) => panic!("{}", err),
+                None => table[i].bytes,
+            };
+        }
+        i += 1;
+    }
+    panic!("curve is not supported by `scalar!` and `point!` macros")
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Constructs scalar validated by [`scalar!`] macro
+///
+/// Proc-macro guarantees that bytes are big-endian encoding of an integer less than group order
+/// of `E`, padded to the scalar size. Reducing such integer modulo group order is a no-op, so the
+/// scalar is constructed infallibly.
+#[doc(hidden)]
+pub fn scalar_from_validated_be_bytes<E: LiteralCurve, L: LiteralTable>() -> Scalar<E> {
+    let mut bytes = E::ScalarArray::zeroes();
+    bytes
+        .as_mut()
+        .iter_mut()
+        .rev()
+        .zip(Validated::<E, L>::BYTES.iter().rev())
+        .for_each(|(out, byte)| *out = *byte);
+    Scalar::from_raw(<E::Scalar as IntegerEncoding>::from_be_bytes(&bytes))
+}
+
+/// Constructs point validated by [`point!`] macro
+///
+/// Proc-macro guarantees that bytes are a canonical encoding of a point of `E` which is on curve
+/// and free of torsion component. Proc-macro validates literals using `generic-ec-curves` that's
+/// semver-compatible with the one linked to `generic-ec`, so cargo resolves both to the same
+/// version, and decoding can't fail.
+///
+/// ## Panics
+/// Panics if the invariant above is violated, i.e. the point doesn't decode. It never happens
+/// unless there's a bug in the proc-macro.
+#[doc(hidden)]
+pub fn point_from_validated_bytes<E: LiteralCurve, L: LiteralTable>() -> Point<E> {
+    let Some(point) = <E::Point as Decode>::decode(Validated::<E, L>::BYTES) else {
+        panic!("point literal validated at compile time must decode at runtime")
+    };
+    debug_assert!(
+        bool::from(point.is_on_curve() & point.is_torsion_free()),
+        "point literal validated at compile time must be on curve and torsion-free"
+    );
+    // Point is on curve and torsion-free as it was validated by the proc-macro
+    Point::from_raw_unchecked(point)
+}
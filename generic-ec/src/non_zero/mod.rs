@@ -9,7 +9,7 @@ use subtle::{ConstantTimeEq, CtOption};
 use crate::{
     as_raw::FromRaw,
     core::Samplable,
-    errors::{ZeroPoint, ZeroScalar},
+    errors::{InvalidPoint, ZeroPoint, ZeroScalar},
     Curve, Point, Scalar, SecretScalar,
 };
 
@@ -38,6 +38,20 @@ impl<E: Curve> NonZero<Point<E>> {
         // zero value is not accessible by anyone
         CtOption::new(Self::new_unchecked(point), is_non_zero)
     }
+
+    /// Decodes non-zero point from bytes
+    ///
+    /// Same as [`Point::from_bytes`], but returns [`InvalidPoint::Identity`] if decoded point is zero
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
+        Self::from_point(Point::from_bytes(bytes)?).ok_or(InvalidPoint::Identity)
+    }
+
+    /// Decodes non-zero point from bytes in strict mode
+    ///
+    /// Same as [`Point::from_bytes_strict`], but returns [`InvalidPoint::Identity`] if decoded point is zero
+    pub fn from_bytes_strict(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
+        Self::from_point(Point::from_bytes_strict(bytes)?).ok_or(InvalidPoint::Identity)
+    }
 }
 
 impl<E: Curve> NonZero<Scalar<E>> {
@@ -0,0 +1,189 @@
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::{
+    as_raw::{AsRaw, TryFromRaw},
+    core::*,
+    errors::InvalidPoint,
+    EncodedPoint, Point, Scalar,
+};
+
+/// Point on elliptic curve `E` which may have a small order component
+///
+/// Unlike [`Point<E>`], it's only guaranteed to be on curve: it may have a torsion component,
+/// i.e. it's an element of the full group of curve points, not only of the prime order subgroup.
+/// It's only useful for curves with cofactor $h > 1$ (like [Ed25519]), and is meant for parsing
+/// and verifying data produced by other libraries, e.g. for RFC 8032 cofactored verification
+/// $\[8\]\[s\]B = \[8\]R + \[8\]\[k\]A$.
+///
+/// Torsion component can be eliminated via [`clear_cofactor`](Self::clear_cofactor) which outputs
+/// regular [`Point<E>`].
+///
+/// ```rust
+/// use generic_ec::{FullGroupPoint, Point, curves::Ed25519};
+///
+/// // Point of order 2 (y = -1)
+/// let mut bytes = [0xff; 32];
+/// bytes[0] = 0xec;
+/// bytes[31] = 0x7f;
+///
+/// assert!(Point::<Ed25519>::from_bytes(&bytes).is_err());
+/// let point = FullGroupPoint::<Ed25519>::from_bytes(&bytes)?;
+/// assert!(point.is_small_order());
+/// assert!(point.clear_cofactor().is_zero());
+/// # Ok::<_, generic_ec::errors::InvalidPoint>(())
+/// ```
+///
+/// [Ed25519]: crate::curves::Ed25519
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct FullGroupPoint<E: Curve>(E::Point);
+
+impl<E: Curve> FullGroupPoint<E> {
+    /// Returns identity point
+    pub fn zero() -> Self {
+        Self(E::Point::zero())
+    }
+
+    /// Indicates whether it's identity point
+    pub fn is_zero(&self) -> bool {
+        Zero::is_zero(&self.0).into()
+    }
+
+    /// Indicates whether point is free of torsion component
+    pub fn is_torsion_free(&self) -> bool {
+        self.0.is_torsion_free().into()
+    }
+
+    /// Converts into [`Point<E>`] if point is free of torsion component
+    ///
+    /// Returns `None` otherwise
+    pub fn to_point(&self) -> Option<Point<E>> {
+        Point::try_from_raw(self.0)
+    }
+
+    /// Encodes a point as bytes
+    ///
+    /// Same as [`Point::to_bytes`]
+    pub fn to_bytes(&self, compressed: bool) -> EncodedPoint<E> {
+        if compressed {
+            EncodedPoint::new_compressed(self.0.to_bytes_compressed())
+        } else {
+            EncodedPoint::new_uncompressed(self.0.to_bytes_uncompressed())
+        }
+    }
+
+    /// Decodes a point from bytes
+    ///
+    /// Unlike [`Point::from_bytes`], it accepts points with a torsion component
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
+        let point = E::Point::decode(bytes.as_ref())
+            .ok_or_else(|| super::decoding_error::<E>(bytes.as_ref()))?;
+        Self::try_from_raw(point).ok_or(InvalidPoint::NotOnCurve)
+    }
+}
+
+impl<E: Curve + MulByCofactor> FullGroupPoint<E> {
+    /// Multiplies the point at cofactor $h$, returns $\[h\]P$
+    pub fn mul_by_cofactor(&self) -> Self {
+        Self(E::mul_by_cofactor(&self.0))
+    }
+
+    /// Multiplies the point at cofactor $h$, returns $\[h\]P$ as [`Point<E>`]
+    ///
+    /// Note that it's not a projection onto prime order subgroup: for points which are already
+    /// torsion free, the output is still multiplied at $h$.
+    pub fn clear_cofactor(&self) -> Point<E> {
+        // Correctness:
+        // 1. Point is on curve as result of arithmetic operation on valid point
+        // 2. `mul_by_cofactor` is guaranteed to output torsion free point
+        Point::from_raw_unchecked(E::mul_by_cofactor(&self.0))
+    }
+
+    /// Indicates whether point has small order, i.e. $\[h\]P = \O$
+    pub fn is_small_order(&self) -> bool {
+        Zero::is_zero(&E::mul_by_cofactor(&self.0)).into()
+    }
+}
+
+impl<E: Curve> TryFromRaw for FullGroupPoint<E> {
+    fn ct_try_from_raw(point: E::Point) -> subtle::CtOption<Self> {
+        let is_on_curve = point.is_on_curve();
+        subtle::CtOption::new(Self(point), is_on_curve)
+    }
+}
+
+impl<E: Curve> AsRaw for FullGroupPoint<E> {
+    type Raw = E::Point;
+
+    #[inline]
+    fn as_raw(&self) -> &E::Point {
+        &self.0
+    }
+}
+
+impl<E: Curve> From<Point<E>> for FullGroupPoint<E> {
+    fn from(point: Point<E>) -> Self {
+        Self(*point.as_raw())
+    }
+}
+
+impl<E: Curve> ConstantTimeEq for FullGroupPoint<E> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<E: Curve> Add for FullGroupPoint<E> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(Additive::add(&self.0, &rhs.0))
+    }
+}
+
+impl<E: Curve> Sub for FullGroupPoint<E> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(Additive::sub(&self.0, &rhs.0))
+    }
+}
+
+impl<E: Curve> Neg for FullGroupPoint<E> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(Additive::negate(&self.0))
+    }
+}
+
+impl<E: Curve> Mul<Scalar<E>> for FullGroupPoint<E> {
+    type Output = Self;
+    fn mul(self, rhs: Scalar<E>) -> Self {
+        Self(Multiplicative::mul(rhs.as_raw(), &self.0))
+    }
+}
+
+impl<E: Curve> Mul<FullGroupPoint<E>> for Scalar<E> {
+    type Output = FullGroupPoint<E>;
+    fn mul(self, rhs: FullGroupPoint<E>) -> FullGroupPoint<E> {
+        rhs * self
+    }
+}
+
+impl<E: Curve> fmt::Debug for FullGroupPoint<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("FullGroupPoint");
+        s.field("curve", &E::CURVE_NAME);
+
+        #[cfg(feature = "std")]
+        {
+            s.field("value", &hex::encode(self.to_bytes(true)));
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            s.field("value", &"...");
+        }
+
+        s.finish()
+    }
+}
@@ -7,7 +7,7 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use crate::{
     as_raw::{AsRaw, TryFromRaw},
     core::*,
-    errors::InvalidPoint,
+    errors::{BufferTooSmall, InvalidPoint},
     EncodedPoint, Generator,
 };
 
@@ -15,6 +15,8 @@ use self::definition::Point;
 
 pub mod coords;
 pub mod definition;
+pub mod full_group;
+pub mod x_only;
 
 impl<E: Curve> Point<E> {
     /// Curve generator
@@ -91,14 +93,157 @@ impl<E: Curve> Point<E> {
         }
     }
 
+    /// Size of compressed point encoding in bytes
+    pub const COMPRESSED_LEN: usize = E::CompressedPointArray::LEN;
+    /// Size of uncompressed point encoding in bytes
+    pub const UNCOMPRESSED_LEN: usize = E::UncompressedPointArray::LEN;
+
+    /// Encodes a point in compressed form
+    ///
+    /// Unlike [`Point::to_bytes`], returns a fixed-size array of [`Point::COMPRESSED_LEN`] bytes.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let random_point = Point::<Secp256k1>::generator() * Scalar::random(&mut OsRng);
+    /// let bytes = random_point.to_bytes_compressed();
+    /// assert_eq!(bytes.len(), Point::<Secp256k1>::COMPRESSED_LEN);
+    /// let point_decoded = Point::from_compressed(&bytes)?;
+    /// assert_eq!(random_point, point_decoded);
+    ///
+    /// // Point can also be written into a buffer provided by the caller
+    /// let mut buffer = [0u8; 100];
+    /// let len = random_point.write_compressed(&mut buffer)?;
+    /// assert_eq!(&buffer[..len], bytes.as_slice());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_compressed(&self) -> E::CompressedPointArray {
+        self.as_raw().to_bytes_compressed()
+    }
+
+    /// Encodes a point in uncompressed form
+    ///
+    /// Unlike [`Point::to_bytes`], returns a fixed-size array of [`Point::UNCOMPRESSED_LEN`] bytes.
+    pub fn to_bytes_uncompressed(&self) -> E::UncompressedPointArray {
+        self.as_raw().to_bytes_uncompressed()
+    }
+
+    /// Writes compressed point encoding into `out`
+    ///
+    /// Writes exactly [`Point::COMPRESSED_LEN`] bytes to the beginning of `out`, and returns
+    /// amount of written bytes. Returns error if `out` is too small.
+    pub fn write_compressed(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        write_bytes(self.to_bytes_compressed().as_ref(), out)
+    }
+
+    /// Writes uncompressed point encoding into `out`
+    ///
+    /// Writes exactly [`Point::UNCOMPRESSED_LEN`] bytes to the beginning of `out`, and returns
+    /// amount of written bytes. Returns error if `out` is too small.
+    pub fn write_uncompressed(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        write_bytes(self.to_bytes_uncompressed().as_ref(), out)
+    }
+
+    /// Decodes a point from its compressed form
+    ///
+    /// Same as [`Point::from_bytes`] but takes a fixed-size array output by [`Point::to_bytes_compressed`]
+    pub fn from_compressed(bytes: &E::CompressedPointArray) -> Result<Self, InvalidPoint> {
+        Self::from_bytes(bytes)
+    }
+
+    /// Decodes a point from its uncompressed form
+    ///
+    /// Same as [`Point::from_bytes`] but takes a fixed-size array output by [`Point::to_bytes_uncompressed`]
+    pub fn from_uncompressed(bytes: &E::UncompressedPointArray) -> Result<Self, InvalidPoint> {
+        Self::from_bytes(bytes)
+    }
+
     /// Decodes a point from bytes
+    ///
+    /// Returns error specifying why the point was rejected. Decoding is lenient: it may accept
+    /// non-canonical encodings of the point if the backend accepts them. Use [`Point::from_bytes_strict`]
+    /// to reject them.
     pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
-        E::Point::decode(bytes.as_ref())
-            .and_then(Self::try_from_raw)
-            .ok_or(InvalidPoint)
+        let bytes = bytes.as_ref();
+        Option::from(Self::ct_from_bytes(bytes)).ok_or_else(|| decoding_error::<E>(bytes))
+    }
+
+    /// Decodes a point from bytes in strict mode
+    ///
+    /// Accepts only canonical encodings of the point, i.e. `bytes` must be exactly equal to
+    /// compressed or uncompressed encoding of the point output by [`Point::to_bytes`]. Rejects
+    /// encodings that are accepted by [`Point::from_bytes`], but have another meaning in other
+    /// implementations, like Ed25519 points with $y \ge p$ or SEC1 hybrid encoding.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Ed25519, errors::InvalidPoint};
+    ///
+    /// // Non-canonical encoding of point with $y = 1 + p$, which is identity point
+    /// let mut bytes = [0xff; 32];
+    /// bytes[0] = 0xee;
+    /// bytes[31] = 0x7f;
+    ///
+    /// assert_eq!(Point::<Ed25519>::from_bytes(&bytes), Ok(Point::zero()));
+    /// assert_eq!(
+    ///     Point::<Ed25519>::from_bytes_strict(&bytes),
+    ///     Err(InvalidPoint::NonCanonical),
+    /// );
+    /// ```
+    pub fn from_bytes_strict(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
+        let bytes = bytes.as_ref();
+        if bytes.len() != Self::COMPRESSED_LEN && bytes.len() != Self::UNCOMPRESSED_LEN {
+            return Err(InvalidPoint::InvalidLength);
+        }
+
+        let point = Self::from_bytes(bytes)?;
+        let is_canonical = (bytes.len() == Self::COMPRESSED_LEN
+            && point.to_bytes_compressed().as_ref() == bytes)
+            || (bytes.len() == Self::UNCOMPRESSED_LEN
+                && point.to_bytes_uncompressed().as_ref() == bytes);
+        if !is_canonical {
+            return Err(InvalidPoint::NonCanonical);
+        }
+        Ok(point)
+    }
+
+    /// Decodes a point from bytes (constant time)
+    ///
+    /// Same as [`Point::from_bytes`] but performs constant-time check on whether the point
+    /// is valid. Length of `bytes` is considered public.
+    pub fn ct_from_bytes(bytes: impl AsRef<[u8]>) -> CtOption<Self> {
+        E::Point::ct_decode(bytes.as_ref()).and_then(Self::ct_try_from_raw)
     }
 }
 
+/// Figures out why `bytes` couldn't be decoded into a valid [`Point<E>`]
+pub(crate) fn decoding_error<E: Curve>(bytes: &[u8]) -> InvalidPoint {
+    match E::Point::decode(bytes) {
+        Some(point) if !bool::from(point.is_on_curve()) => InvalidPoint::NotOnCurve,
+        Some(_) => InvalidPoint::HasTorsion,
+        None => {
+            if bytes.len() != Point::<E>::COMPRESSED_LEN
+                && bytes.len() != Point::<E>::UNCOMPRESSED_LEN
+            {
+                InvalidPoint::InvalidLength
+            } else {
+                InvalidPoint::NotOnCurve
+            }
+        }
+    }
+}
+
+/// Copies `bytes` to the beginning of `out`
+pub(crate) fn write_bytes(bytes: &[u8], out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    let provided = out.len();
+    let out = out.get_mut(..bytes.len()).ok_or(BufferTooSmall {
+        required: bytes.len(),
+        provided,
+    })?;
+    out.copy_from_slice(bytes);
+    Ok(bytes.len())
+}
+
 impl<E: Curve> TryFromRaw for Point<E> {
     fn ct_try_from_raw(point: E::Point) -> CtOption<Self> {
         let is_on_curve = point.is_on_curve();
@@ -201,6 +346,43 @@ impl<E: Curve> crate::traits::Zero for Point<E> {
     }
 }
 
+#[cfg(feature = "udigest")]
+impl<E: Curve + crate::core::hash_to_curve::HashToCurve> Point<E> {
+    /// Hashes structured data into point
+    ///
+    /// `value` is encoded unambiguously via [`udigest`] and digested by hash function `D`,
+    /// then the digest is mapped to the curve via [hash to curve](crate::hash_to_curve::FromHash)
+    /// with the same `tag`. Discrete logarithm of the output is unknown.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Secp256k1, hash_to_curve::Tag};
+    ///
+    /// #[derive(udigest::Digestable)]
+    /// struct Session<'a> {
+    ///     id: u64,
+    ///     #[udigest(as_bytes)]
+    ///     label: &'a [u8],
+    /// }
+    ///
+    /// const TAG: Tag = Tag::new_unwrap(b"MYAPP-v0.1.0");
+    ///
+    /// let point = Point::<Secp256k1>::hash_digestable::<sha2::Sha256>(
+    ///     TAG,
+    ///     &Session { id: 1, label: b"keygen" },
+    /// )?;
+    /// # Ok::<_, generic_ec::errors::HashError>(())
+    /// ```
+    pub fn hash_digestable<D: digest::Digest>(
+        tag: crate::hash_to_curve::Tag,
+        value: &impl udigest::Digestable,
+    ) -> Result<Self, crate::errors::HashError> {
+        use crate::hash_to_curve::FromHash;
+
+        let digest = udigest::udigest::<D>(udigest::Tag::new(tag.as_bytes()), value);
+        <Self as FromHash>::hash(tag, &digest)
+    }
+}
+
 #[cfg(feature = "udigest")]
 impl<E: Curve> udigest::Digestable for Point<E> {
     fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
@@ -0,0 +1,149 @@
+use core::fmt;
+
+use subtle::{Choice, ConditionallySelectable};
+
+use crate::{
+    coords::{Coordinate, HasAffineX, HasAffineXAndParity, Parity},
+    errors::InvalidPoint,
+    Curve, NonZero, Point, Scalar, SecretScalar,
+};
+
+/// Non-zero point represented by its $x$ coordinate with implicit even $y$
+///
+/// Corresponds to x-only public keys defined in [BIP340], which are used by Bitcoin Taproot.
+/// Point is encoded as its $x$ coordinate, which is 32 bytes for secp256k1.
+///
+/// ```rust
+/// use generic_ec::{Point, SecretScalar, XOnlyPoint, coords::Parity, curves::Secp256k1};
+/// # let mut rng = rand::rngs::OsRng;
+///
+/// let secret_key = SecretScalar::<Secp256k1>::random(&mut rng);
+/// let public_key = Point::generator() * &secret_key;
+///
+/// let (x_only, parity) = XOnlyPoint::from_point(&public_key).unwrap();
+/// if parity == Parity::Even {
+///     assert_eq!(x_only.to_point(), public_key);
+/// } else {
+///     assert_eq!(x_only.to_point(), -public_key);
+/// }
+///
+/// let parsed = XOnlyPoint::<Secp256k1>::from_bytes(x_only.to_bytes())?;
+/// assert_eq!(parsed, x_only);
+/// # Ok::<_, generic_ec::errors::InvalidPoint>(())
+/// ```
+///
+/// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+#[derive(Clone, PartialEq, Eq)]
+pub struct XOnlyPoint<E: Curve> {
+    /// Point with even $y$
+    point: NonZero<Point<E>>,
+    x: Coordinate<E>,
+}
+
+impl<E: Curve> XOnlyPoint<E>
+where
+    Point<E>: HasAffineXAndParity<E>,
+{
+    /// Lifts $x$ coordinate to the point with even $y$
+    ///
+    /// Corresponds to `lift_x` function defined in BIP340. Returns `None` if there's no point
+    /// with such $x$ coordinate.
+    pub fn lift_x(x: &Coordinate<E>) -> Option<Self> {
+        let point = Point::from_x_and_parity(x, Parity::Even)?;
+        Self::from_even_y_point(point)
+    }
+
+    /// Converts a point into x-only form
+    ///
+    /// Returns x-only point and parity of $y$ coordinate of the original point. If parity is
+    /// [`Parity::Odd`], x-only point corresponds to the negation of the original point.
+    ///
+    /// Returns `None` if `point` is zero.
+    pub fn from_point(point: &Point<E>) -> Option<(Self, Parity)> {
+        let (x, parity) = point.x_and_parity()?;
+        let point = if parity.is_odd() { -point } else { *point };
+        Some((
+            Self {
+                point: NonZero::from_point(point)?,
+                x,
+            },
+            parity,
+        ))
+    }
+
+    /// Decodes x-only point from bytes representation of $x$ coordinate
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
+        let x = Coordinate::from_be_bytes(bytes.as_ref()).or(Err(InvalidPoint::InvalidLength))?;
+        Self::lift_x(&x).ok_or(InvalidPoint::NotOnCurve)
+    }
+
+    fn from_even_y_point(point: Point<E>) -> Option<Self> {
+        let x = point.x()?;
+        Some(Self {
+            point: NonZero::from_point(point)?,
+            x,
+        })
+    }
+}
+
+impl<E: Curve> XOnlyPoint<E> {
+    /// Returns the point with even $y$ coordinate
+    pub fn to_point(&self) -> Point<E> {
+        *self.point.as_ref()
+    }
+
+    /// Returns the point with even $y$ coordinate
+    pub fn to_nonzero_point(&self) -> NonZero<Point<E>> {
+        self.point
+    }
+
+    /// Returns $x$ coordinate of the point
+    pub fn x(&self) -> &Coordinate<E> {
+        &self.x
+    }
+
+    /// Encodes x-only point as bytes representation of $x$ coordinate
+    pub fn to_bytes(&self) -> Coordinate<E> {
+        self.x.clone()
+    }
+}
+
+impl<E: Curve> AsRef<[u8]> for XOnlyPoint<E> {
+    fn as_ref(&self) -> &[u8] {
+        self.x.as_ref()
+    }
+}
+
+impl<E: Curve> From<XOnlyPoint<E>> for Point<E> {
+    fn from(point: XOnlyPoint<E>) -> Self {
+        point.to_point()
+    }
+}
+
+impl<E: Curve> fmt::Debug for XOnlyPoint<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("XOnlyPoint")
+            .field("curve", &E::CURVE_NAME)
+            .field("x", &self.x)
+            .finish()
+    }
+}
+
+impl<E: Curve> SecretScalar<E>
+where
+    Point<E>: HasAffineXAndParity<E>,
+{
+    /// Negates secret key if corresponding public key has odd $y$ coordinate
+    ///
+    /// Returns secret key $d'$ and x-only public key $P$ such that $P = d' G$ has even $y$, as
+    /// required by BIP340 signing. Returns `None` if secret key is zero.
+    pub fn to_even_y(&self) -> Option<(SecretScalar<E>, XOnlyPoint<E>)> {
+        let public_key = Point::generator() * self;
+        let (public_key, parity) = XOnlyPoint::from_point(&public_key)?;
+
+        let secret: &Scalar<E> = self.as_ref();
+        let mut secret =
+            Scalar::conditional_select(secret, &-secret, Choice::from(u8::from(parity.is_odd())));
+        Some((SecretScalar::new(&mut secret), public_key))
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
     as_raw::{AsRaw, FromRaw},
     core::*,
     encoded::EncodedScalar,
-    errors::InvalidScalar,
+    errors::{BufferTooSmall, InvalidScalar},
 };
 
 /// Scalar modulo curve `E` group order
@@ -97,9 +97,30 @@ impl<E: Curve> Scalar<E> {
         EncodedScalar::new(bytes)
     }
 
+    /// Size of scalar encoding in bytes
+    pub const SERIALIZED_LEN: usize = E::ScalarArray::LEN;
+
+    /// Writes scalar bytes representation in big-endian order into `out`
+    ///
+    /// Writes exactly [`Scalar::SERIALIZED_LEN`] bytes to the beginning of `out`, and returns
+    /// amount of written bytes. Returns error if `out` is too small.
+    pub fn write_be_bytes(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        crate::point::write_bytes(self.as_raw().to_be_bytes().as_ref(), out)
+    }
+
+    /// Writes scalar bytes representation in little-endian order into `out`
+    ///
+    /// Writes exactly [`Scalar::SERIALIZED_LEN`] bytes to the beginning of `out`, and returns
+    /// amount of written bytes. Returns error if `out` is too small.
+    pub fn write_le_bytes(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        crate::point::write_bytes(self.as_raw().to_le_bytes().as_ref(), out)
+    }
+
     /// Decodes scalar from its representation as bytes in big-endian order
     ///
-    /// Returns error if encoded integer is larger than group order.
+    /// Returns error if encoded integer is larger than group order, or if `bytes` is longer
+    /// than [`Scalar::serialized_len`]. Shorter inputs are accepted and treated as if they were
+    /// padded with leading zeroes.
     ///
     /// ```rust
     /// use generic_ec::{Scalar, curves::Secp256k1};
@@ -112,32 +133,80 @@ impl<E: Curve> Scalar<E> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn from_be_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidScalar> {
+        let bytes = bytes.as_ref();
+        if bytes.len() > Self::serialized_len() {
+            return Err(InvalidScalar::InvalidLength);
+        }
+        Option::from(Self::ct_from_be_bytes(bytes)).ok_or(InvalidScalar::OutOfRange)
+    }
+
+    /// Decodes scalar from its representation as bytes in big-endian order in strict mode
+    ///
+    /// Unlike [`Scalar::from_be_bytes`], `bytes` must be exactly [`Scalar::serialized_len`] bytes long,
+    /// i.e. only output of [`Scalar::to_be_bytes`] is accepted.
+    pub fn from_be_bytes_strict(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidScalar> {
+        let bytes = bytes.as_ref();
+        if bytes.len() != Self::serialized_len() {
+            return Err(InvalidScalar::InvalidLength);
+        }
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Decodes scalar from its representation as bytes in big-endian order (constant time)
+    ///
+    /// Same as [`Scalar::from_be_bytes`] but performs constant-time check on whether encoded
+    /// integer is less than group order. Length of `bytes` is considered public.
+    pub fn ct_from_be_bytes(bytes: impl AsRef<[u8]>) -> CtOption<Self> {
         let bytes = bytes.as_ref();
         let mut bytes_array = E::ScalarArray::zeroes();
         let bytes_array_len = bytes_array.as_ref().len();
         if bytes_array_len < bytes.len() {
-            return Err(InvalidScalar);
+            return CtOption::new(Self::zero(), Choice::from(0));
         }
         bytes_array.as_mut()[bytes_array_len - bytes.len()..].copy_from_slice(bytes);
 
-        let scalar = E::Scalar::from_be_bytes_exact(&bytes_array).ok_or(InvalidScalar)?;
-        Ok(Scalar::from_raw(scalar))
+        E::Scalar::ct_from_be_bytes_exact(&bytes_array).map(Self::from_raw)
     }
 
     /// Decodes scalar from its representation as bytes in little-endian order
     ///
-    /// Returns error if encoded integer is larger than group order.
+    /// Returns error if encoded integer is larger than group order, or if `bytes` is longer
+    /// than [`Scalar::serialized_len`]. Shorter inputs are accepted and treated as if they were
+    /// padded with trailing zeroes.
     pub fn from_le_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidScalar> {
+        let bytes = bytes.as_ref();
+        if bytes.len() > Self::serialized_len() {
+            return Err(InvalidScalar::InvalidLength);
+        }
+        Option::from(Self::ct_from_le_bytes(bytes)).ok_or(InvalidScalar::OutOfRange)
+    }
+
+    /// Decodes scalar from its representation as bytes in little-endian order in strict mode
+    ///
+    /// Unlike [`Scalar::from_le_bytes`], `bytes` must be exactly [`Scalar::serialized_len`] bytes long,
+    /// i.e. only output of [`Scalar::to_le_bytes`] is accepted.
+    pub fn from_le_bytes_strict(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidScalar> {
+        let bytes = bytes.as_ref();
+        if bytes.len() != Self::serialized_len() {
+            return Err(InvalidScalar::InvalidLength);
+        }
+        Self::from_le_bytes(bytes)
+    }
+
+    /// Decodes scalar from its representation as bytes in little-endian order (constant time)
+    ///
+    /// Same as [`Scalar::from_le_bytes`] but performs constant-time check on whether encoded
+    /// integer is less than group order. Length of `bytes` is considered public.
+    pub fn ct_from_le_bytes(bytes: impl AsRef<[u8]>) -> CtOption<Self> {
         let bytes = bytes.as_ref();
         let mut bytes_array = E::ScalarArray::zeroes();
         let bytes_array_len = bytes_array.as_ref().len();
         if bytes_array_len < bytes.len() {
-            return Err(InvalidScalar);
+            return CtOption::new(Self::zero(), Choice::from(0));
         }
         bytes_array.as_mut()[..bytes.len()].copy_from_slice(bytes);
 
-        let scalar = E::Scalar::from_le_bytes_exact(&bytes_array).ok_or(InvalidScalar)?;
-        Ok(Scalar::from_raw(scalar))
+        E::Scalar::ct_from_le_bytes_exact(&bytes_array).map(Self::from_raw)
     }
 
     /// Interprets provided bytes as integer $i$ in big-endian order, returns scalar $s = i \mod q$
@@ -177,8 +246,53 @@ impl<E: Curve> Scalar<E> {
     }
 
     /// Returns size of bytes buffer that can fit serialized scalar
+    ///
+    /// Same as [`Scalar::SERIALIZED_LEN`]
     pub fn serialized_len() -> usize {
-        E::ScalarArray::zeroes().as_ref().len()
+        Self::SERIALIZED_LEN
+    }
+}
+
+#[cfg(feature = "udigest")]
+impl<E: Curve + crate::core::hash_to_curve::HashToCurve> Scalar<E> {
+    /// Hashes structured data into scalar
+    ///
+    /// `value` is encoded unambiguously via [`udigest`] and digested by hash function `D`. The
+    /// digest is then mapped to a scalar via [`FromHash`](crate::hash_to_curve::FromHash), i.e.
+    /// `hash_to_field` from [RFC 9380] with `tag` used as domain separation tag, the same way
+    /// as [`Point::hash_digestable`](crate::Point::hash_digestable) maps it to a point.
+    ///
+    /// Can be used to derive Fiat-Shamir challenges from typed structs.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1, hash_to_curve::Tag};
+    ///
+    /// #[derive(udigest::Digestable)]
+    /// struct Transcript<'a> {
+    ///     commitment: &'a Point<Secp256k1>,
+    ///     public_key: &'a Point<Secp256k1>,
+    /// }
+    ///
+    /// const TAG: Tag = Tag::new_unwrap(b"MYAPP-v0.1.0");
+    ///
+    /// let commitment = Point::generator() * Scalar::<Secp256k1>::from(2);
+    /// let public_key = Point::generator() * Scalar::<Secp256k1>::from(3);
+    /// let challenge = Scalar::<Secp256k1>::hash_digestable::<sha2::Sha256>(
+    ///     TAG,
+    ///     &Transcript { commitment: &commitment, public_key: &public_key },
+    /// )?;
+    /// # Ok::<_, generic_ec::errors::HashError>(())
+    /// ```
+    ///
+    /// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio
+    pub fn hash_digestable<D: digest::Digest>(
+        tag: crate::hash_to_curve::Tag,
+        value: &impl udigest::Digestable,
+    ) -> Result<Self, crate::errors::HashError> {
+        use crate::hash_to_curve::FromHash;
+
+        let digest = udigest::udigest::<D>(udigest::Tag::new(tag.as_bytes()), value);
+        <Self as FromHash>::hash(tag, &digest)
     }
 }
 
@@ -44,6 +44,22 @@ impl<E: Curve> SecretScalar<E> {
         let mut scalar = Scalar::from_le_bytes(bytes)?;
         Ok(Self::new(&mut scalar))
     }
+
+    /// Decodes scalar from its bytes representation in big-endian order in strict mode
+    ///
+    /// See [`Scalar::from_be_bytes_strict`]
+    pub fn from_be_bytes_strict(bytes: &[u8]) -> Result<Self, InvalidScalar> {
+        let mut scalar = Scalar::from_be_bytes_strict(bytes)?;
+        Ok(Self::new(&mut scalar))
+    }
+
+    /// Decodes scalar from its bytes representation in little-endian order in strict mode
+    ///
+    /// See [`Scalar::from_le_bytes_strict`]
+    pub fn from_le_bytes_strict(bytes: &[u8]) -> Result<Self, InvalidScalar> {
+        let mut scalar = Scalar::from_le_bytes_strict(bytes)?;
+        Ok(Self::new(&mut scalar))
+    }
 }
 
 impl<E: Curve> ConstantTimeEq for SecretScalar<E> {
@@ -82,6 +98,17 @@ impl<E: Curve> fmt::Debug for SecretScalar<E> {
     }
 }
 
+/// Secret scalar is digested in the same way as [`Scalar`]
+#[cfg(feature = "udigest")]
+impl<E: Curve> udigest::Digestable for SecretScalar<E> {
+    fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
+    where
+        B: udigest::Buffer,
+    {
+        self.as_ref().unambiguously_encode(encoder)
+    }
+}
+
 impl<E: Curve> crate::traits::Samplable for SecretScalar<E> {
     fn random<R: RngCore>(rng: &mut R) -> Self {
         let mut scalar = Scalar::random(rng);
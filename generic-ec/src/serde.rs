@@ -73,6 +73,44 @@
 //! }"#);
 //! # Ok(()) }
 //! ```
+//!
+//! ## Other serialization formats
+//!
+//! Similarly to [`Compact`], there are other [serde_with] helpers that can be used when
+//! a specific format is required:
+//! * [`AffineXY`] serializes a point as its affine $x, y$ coordinates
+//! * [`XOnly`] serializes a point as its $x$ coordinate with implicit even $y$ (as in BIP340)
+//! * `Base64` and `Base58` serialize points/scalars in compact form, but use base64 or base58
+//!   strings instead of hex in human-readable formats. Require `base64` and `base58` features.
+//!
+//! All of them work with `Point<E>`, `NonZero<_>` and collections (e.g. `Vec<Base64>`), and
+//! `Base64`/`Base58` also work with `Scalar<E>` and `SecretScalar<E>`.
+//!
+//! ```rust
+//! # fn main() -> Result<(), serde_json::Error> {
+//! use generic_ec::{Point, NonZero, curves::Secp256k1};
+//! use serde::{Serialize, Deserialize};
+//! use serde_with::serde_as;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! pub struct Signers {
+//!     #[serde_as(as = "Vec<generic_ec::serde::XOnly>")]
+//!     public_keys: Vec<NonZero<Point<Secp256k1>>>,
+//! }
+//!
+//! let signers = Signers {
+//!     public_keys: vec![Point::generator().to_nonzero_point()],
+//! };
+//! assert_eq!(serde_json::to_string(&signers)?, r#"{"public_keys":["79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"]}"#);
+//! # Ok(()) }
+//! ```
+//!
+//! ## Untrusted input
+//!
+//! When a list of points/scalars is received from untrusted party, use [`BoundedVec`] to limit
+//! its length. Otherwise, a malicious party can make you allocate and process an arbitrarily
+//! large list.
 
 use phantom_type::PhantomType;
 
@@ -164,8 +202,15 @@ impl<'de, E: Curve> serde::Deserialize<'de> for CurveName<E> {
 
 #[cfg(feature = "serde")]
 pub use optional::*;
+#[cfg(feature = "schemars")]
+pub use schema::SchemaAs;
+#[cfg(feature = "schemars")]
+mod schema;
 #[cfg(feature = "serde")]
 mod optional {
+    #[cfg(feature = "alloc")]
+    use phantom_type::PhantomType;
+
     use crate::{core::Curve, Point, Scalar, SecretScalar};
 
     use super::CurveName;
@@ -228,7 +273,142 @@ mod optional {
         }
     }
 
+    impl<E: Curve> serde::Serialize for crate::XOnlyPoint<E> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            models::XOnlyPoint::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde::Deserialize<'de> for crate::XOnlyPoint<E>
+    where
+        Point<E>: crate::coords::HasAffineXAndParity<E>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            models::XOnlyPoint::deserialize(deserializer)?
+                .try_into()
+                .map_err(<D::Error as serde::de::Error>::custom)
+        }
+    }
+
+    impl<E: Curve> serde::Serialize for crate::coords::Coordinate<E> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            models::CoordinateUncompressed::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde::Deserialize<'de> for crate::coords::Coordinate<E> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            models::CoordinateUncompressed::deserialize(deserializer).map(Self::from)
+        }
+    }
+
+    impl<E: Curve> serde::Serialize for crate::coords::Coordinates<E> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            models::CoordinatesUncompressed::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde::Deserialize<'de> for crate::coords::Coordinates<E> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            models::CoordinatesUncompressed::deserialize(deserializer).map(Self::from)
+        }
+    }
+
+    /// Encoded point is serialized as is, i.e. either in compressed or uncompressed form
+    impl<E: Curve> serde::Serialize for crate::EncodedPoint<E> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            models::EncodedPoint::from(self).serialize(serializer)
+        }
+    }
+
+    /// Deserializes point in either compressed or uncompressed form, and preserves the form
+    impl<'de, E: Curve> serde::Deserialize<'de> for crate::EncodedPoint<E> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            models::EncodedPoint::deserialize(deserializer)?
+                .try_into()
+                .map_err(<D::Error as serde::de::Error>::custom)
+        }
+    }
+
+    /// Encoded scalar is serialized in the same format as [`Scalar`]
+    impl<E: Curve> serde::Serialize for crate::EncodedScalar<E> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            models::ScalarUncompressed::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde::Deserialize<'de> for crate::EncodedScalar<E> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            models::ScalarUncompressed::deserialize(deserializer).map(Self::from)
+        }
+    }
+
+    /// Generator is serialized in the same format as [`Point`]
+    impl<E: Curve> serde::Serialize for crate::Generator<E> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            self.to_point().serialize(serializer)
+        }
+    }
+
+    /// Deserializes a point and checks that it's a curve generator
+    impl<'de, E: Curve> serde::Deserialize<'de> for crate::Generator<E> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            generator_from_point(Point::deserialize(deserializer)?)
+        }
+    }
+
+    fn generator_from_point<E: Curve, Err: serde::de::Error>(
+        point: Point<E>,
+    ) -> Result<crate::Generator<E>, Err> {
+        let generator = crate::Generator::default();
+        if point == generator.to_point() {
+            Ok(generator)
+        } else {
+            Err(Err::custom("point is not a curve generator"))
+        }
+    }
+
     /// Compact serialization format
+    ///
+    /// Works with points, scalars, secret scalars, [`NonZero`](crate::NonZero) points/scalars,
+    /// [coordinates](crate::coords), [`EncodedPoint`](crate::EncodedPoint),
+    /// [`EncodedScalar`](crate::EncodedScalar), and [`Generator`](crate::Generator).
     pub struct Compact;
 
     impl<E: Curve> serde_with::SerializeAs<Point<E>> for Compact {
@@ -298,6 +478,120 @@ mod optional {
         }
     }
 
+    impl<E: Curve> serde_with::SerializeAs<crate::coords::Coordinate<E>> for Compact {
+        fn serialize_as<S>(
+            source: &crate::coords::Coordinate<E>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::Serialize;
+            models::CoordinateCompact::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, crate::coords::Coordinate<E>> for Compact {
+        fn deserialize_as<D>(deserializer: D) -> Result<crate::coords::Coordinate<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            models::CoordinateCompact::deserialize(deserializer).map(Into::into)
+        }
+    }
+
+    /// Coordinates are serialized in the same format as in [`AffineXY`]
+    impl<E: Curve> serde_with::SerializeAs<crate::coords::Coordinates<E>> for Compact {
+        fn serialize_as<S>(
+            source: &crate::coords::Coordinates<E>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::Serialize;
+            models::PointAffine::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, crate::coords::Coordinates<E>> for Compact {
+        fn deserialize_as<D>(deserializer: D) -> Result<crate::coords::Coordinates<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            models::PointAffine::deserialize(deserializer).map(Into::into)
+        }
+    }
+
+    impl<E: Curve> serde_with::SerializeAs<crate::EncodedPoint<E>> for Compact {
+        fn serialize_as<S>(
+            source: &crate::EncodedPoint<E>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::Serialize;
+            models::EncodedPointCompact::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, crate::EncodedPoint<E>> for Compact {
+        fn deserialize_as<D>(deserializer: D) -> Result<crate::EncodedPoint<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            models::EncodedPointCompact::deserialize(deserializer)?
+                .try_into()
+                .map_err(<D::Error as serde::de::Error>::custom)
+        }
+    }
+
+    impl<E: Curve> serde_with::SerializeAs<crate::EncodedScalar<E>> for Compact {
+        fn serialize_as<S>(
+            source: &crate::EncodedScalar<E>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::Serialize;
+            models::ScalarCompact::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, crate::EncodedScalar<E>> for Compact {
+        fn deserialize_as<D>(deserializer: D) -> Result<crate::EncodedScalar<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            models::ScalarCompact::deserialize(deserializer).map(Into::into)
+        }
+    }
+
+    impl<E: Curve> serde_with::SerializeAs<crate::Generator<E>> for Compact {
+        fn serialize_as<S>(source: &crate::Generator<E>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            Compact::serialize_as(&source.to_point(), serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, crate::Generator<E>> for Compact {
+        fn deserialize_as<D>(deserializer: D) -> Result<crate::Generator<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let point: Point<E> = Compact::deserialize_as(deserializer)?;
+            generator_from_point(point)
+        }
+    }
+
     impl<T> serde_with::SerializeAs<crate::NonZero<T>> for Compact
     where
         Compact: serde_with::SerializeAs<T>,
@@ -445,62 +739,462 @@ mod optional {
         }
     }
 
-    /// Wraps a [`serde::Deserializer`] and overrides `fn is_human_readable()`
-    struct OverrideHumanReadable<D> {
-        is_human_readable: bool,
-        deserializer: D,
-    }
-    impl<'de, D> serde::Deserializer<'de> for OverrideHumanReadable<D>
+    /// Serializes a point as its affine $x, y$ coordinates
+    ///
+    /// Point is serialized as a struct with `x` and `y` fields. In human-readable formats,
+    /// coordinates are hex-encoded. Point at infinity doesn't have affine coordinates, so
+    /// it can't be serialized in this format (see [points at infinity](crate#points-at-infinity)).
+    ///
+    /// Only available for curves that expose affine coordinates, see [`HasAffineXY`](crate::coords::HasAffineXY).
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), serde_json::Error> {
+    /// use generic_ec::{Point, curves::Secp256k1};
+    /// use serde::{Serialize, Deserialize};
+    /// use serde_with::serde_as;
+    ///
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// pub struct PublicKey(
+    ///     #[serde_as(as = "generic_ec::serde::AffineXY")] Point<Secp256k1>,
+    /// );
+    ///
+    /// let pk = PublicKey(Point::generator().to_point());
+    /// assert_eq!(serde_json::to_string_pretty(&pk)?, r#"{
+    ///   "x": "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    ///   "y": "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+    /// }"#);
+    /// # Ok(()) }
+    /// ```
+    pub struct AffineXY;
+
+    impl<E: Curve> serde_with::SerializeAs<Point<E>> for AffineXY
     where
-        D: serde::Deserializer<'de>,
+        Point<E>: crate::coords::HasAffineXY<E>,
     {
-        type Error = <D as serde::Deserializer<'de>>::Error;
-
-        fn is_human_readable(&self) -> bool {
-            self.is_human_readable
-        }
-
-        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        fn serialize_as<S>(source: &Point<E>, serializer: S) -> Result<S::Ok, S::Error>
         where
-            V: serde::de::Visitor<'de>,
+            S: serde::Serializer,
         {
-            self.deserializer.deserialize_any(visitor)
-        }
-
-        serde::forward_to_deserialize_any! {
-            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
-            bytes byte_buf option unit unit_struct newtype_struct seq tuple
-            tuple_struct map struct enum identifier ignored_any
+            use serde::Serialize;
+            models::PointAffine::try_from(source)
+                .map_err(<S::Error as serde::ser::Error>::custom)?
+                .serialize(serializer)
         }
     }
 
-    /// See [`serde::de::value`]. New type deserializer is missing in the `serde` crate.
-    struct NewTypeDeserializer<D> {
-        deserializer: D,
-    }
-    impl<D> NewTypeDeserializer<D> {
-        pub fn new(deserializer: D) -> Self {
-            Self { deserializer }
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, Point<E>> for AffineXY
+    where
+        Point<E>: crate::coords::HasAffineXY<E>,
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<Point<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            models::PointAffine::deserialize(deserializer)?
+                .try_into()
+                .map_err(<D::Error as serde::de::Error>::custom)
         }
     }
-    impl<'de, D> serde::Deserializer<'de> for NewTypeDeserializer<D>
+
+    /// Serializes a point as its $x$ coordinate with implicit even $y$
+    ///
+    /// Uses the same format as [`XOnlyPoint`](crate::XOnlyPoint), which corresponds to x-only
+    /// public keys defined in [BIP340]. Only points with even $y$ can be serialized in this
+    /// format, serializing a zero point or a point with odd $y$ results into an error. Use
+    /// [`XOnlyPoint::from_point`](crate::XOnlyPoint::from_point) to normalize a point before
+    /// serialization.
+    ///
+    /// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+    pub struct XOnly;
+
+    impl<E: Curve> serde_with::SerializeAs<Point<E>> for XOnly
     where
-        D: serde::Deserializer<'de>,
+        Point<E>: crate::coords::HasAffineXAndParity<E>,
     {
-        type Error = D::Error;
-        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        fn serialize_as<S>(source: &Point<E>, serializer: S) -> Result<S::Ok, S::Error>
         where
-            V: serde::de::Visitor<'de>,
+            S: serde::Serializer,
         {
-            visitor.visit_newtype_struct(self.deserializer)
-        }
-        fn is_human_readable(&self) -> bool {
-            self.deserializer.is_human_readable()
-        }
-        serde::forward_to_deserialize_any! {
-            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
-            bytes byte_buf option unit unit_struct newtype_struct seq tuple
-            tuple_struct map struct enum identifier ignored_any
+            use serde::Serialize;
+            match crate::XOnlyPoint::from_point(source) {
+                Some((point, crate::coords::Parity::Even)) => {
+                    models::XOnlyPoint::from(&point).serialize(serializer)
+                }
+                Some((_, crate::coords::Parity::Odd)) => {
+                    Err(<S::Error as serde::ser::Error>::custom(
+                        "point has odd y coordinate, it can't be serialized as x-only point",
+                    ))
+                }
+                None => Err(<S::Error as serde::ser::Error>::custom(
+                    "zero point can't be serialized as x-only point",
+                )),
+            }
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, Point<E>> for XOnly
+    where
+        Point<E>: crate::coords::HasAffineXAndParity<E>,
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<Point<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            let point: crate::XOnlyPoint<E> = models::XOnlyPoint::deserialize(deserializer)?
+                .try_into()
+                .map_err(<D::Error as serde::de::Error>::custom)?;
+            Ok(point.to_point())
+        }
+    }
+
+    /// Serializes point/scalar as base64 string
+    ///
+    /// Points are serialized in compressed form, scalars are serialized as big-endian bytes.
+    /// In human-readable formats, bytes are encoded using standard base64 alphabet with padding
+    /// ([RFC 4648, Section 4](https://www.rfc-editor.org/rfc/rfc4648#section-4)). Binary formats
+    /// store raw bytes.
+    ///
+    /// Requires `base64` feature.
+    #[cfg(feature = "base64")]
+    pub struct Base64;
+
+    /// Serializes point/scalar as base58 string
+    ///
+    /// Points are serialized in compressed form, scalars are serialized as big-endian bytes.
+    /// In human-readable formats, bytes are encoded using Bitcoin base58 alphabet (without
+    /// checksum). Binary formats store raw bytes.
+    ///
+    /// Requires `base58` feature.
+    #[cfg(feature = "base58")]
+    pub struct Base58;
+
+    /// Implements `SerializeAs`/`DeserializeAs` for points and scalars that are serialized
+    /// as bytes encoded with `$encoding`
+    #[cfg(any(feature = "base64", feature = "base58"))]
+    macro_rules! impl_bytes_adapter {
+        ($adapter:ty, $encoding:ty) => {
+            impl<E: Curve> serde_with::SerializeAs<Point<E>> for $adapter {
+                fn serialize_as<S>(source: &Point<E>, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    <$encoding>::serialize_as(&source.to_bytes_compressed(), serializer)
+                }
+            }
+
+            impl<'de, E: Curve> serde_with::DeserializeAs<'de, Point<E>> for $adapter {
+                fn deserialize_as<D>(deserializer: D) -> Result<Point<E>, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let bytes: E::CompressedPointArray = <$encoding>::deserialize_as(deserializer)?;
+                    Point::from_bytes(bytes).map_err(<D::Error as serde::de::Error>::custom)
+                }
+            }
+
+            impl<E: Curve> serde_with::SerializeAs<Scalar<E>> for $adapter {
+                fn serialize_as<S>(source: &Scalar<E>, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    <$encoding>::serialize_as(&source.to_be_bytes(), serializer)
+                }
+            }
+
+            impl<'de, E: Curve> serde_with::DeserializeAs<'de, Scalar<E>> for $adapter {
+                fn deserialize_as<D>(deserializer: D) -> Result<Scalar<E>, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let bytes: E::ScalarArray = <$encoding>::deserialize_as(deserializer)?;
+                    Scalar::from_be_bytes(&bytes).map_err(<D::Error as serde::de::Error>::custom)
+                }
+            }
+
+            impl<E: Curve> serde_with::SerializeAs<SecretScalar<E>> for $adapter {
+                fn serialize_as<S>(
+                    source: &SecretScalar<E>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    let mut bytes = source.as_ref().to_be_bytes();
+                    let result = <$encoding>::serialize_as(&bytes, serializer);
+                    zeroize::Zeroize::zeroize(bytes.as_mut());
+                    result
+                }
+            }
+
+            impl<'de, E: Curve> serde_with::DeserializeAs<'de, SecretScalar<E>> for $adapter {
+                fn deserialize_as<D>(deserializer: D) -> Result<SecretScalar<E>, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let mut bytes: E::ScalarArray = <$encoding>::deserialize_as(deserializer)?;
+                    let result = SecretScalar::from_be_bytes(bytes.as_ref());
+                    zeroize::Zeroize::zeroize(bytes.as_mut());
+                    result.map_err(<D::Error as serde::de::Error>::custom)
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "base64")]
+    impl_bytes_adapter!(Base64, utils::Text<utils::Base64Alphabet>);
+    #[cfg(feature = "base58")]
+    impl_bytes_adapter!(Base58, utils::Text<utils::Base58Alphabet>);
+
+    /// Implements `SerializeAs`/`DeserializeAs` for `NonZero<T>` and `&T` by forwarding
+    /// to the implementation for `T`
+    macro_rules! impl_forwarding_adapter {
+        ($($adapter:ty),+ $(,)?) => {$(
+            impl<T> serde_with::SerializeAs<crate::NonZero<T>> for $adapter
+            where
+                $adapter: serde_with::SerializeAs<T>,
+            {
+                fn serialize_as<S>(
+                    source: &crate::NonZero<T>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    <$adapter>::serialize_as(source.as_ref(), serializer)
+                }
+            }
+
+            impl<'de, T> serde_with::DeserializeAs<'de, crate::NonZero<T>> for $adapter
+            where
+                $adapter: serde_with::DeserializeAs<'de, T>,
+                crate::NonZero<T>: TryFrom<T>,
+                <crate::NonZero<T> as TryFrom<T>>::Error: core::fmt::Display,
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<crate::NonZero<T>, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let value = <$adapter>::deserialize_as(deserializer)?;
+                    crate::NonZero::try_from(value).map_err(<D::Error as serde::de::Error>::custom)
+                }
+            }
+
+            impl<'a, T> serde_with::SerializeAs<&'a T> for $adapter
+            where
+                $adapter: serde_with::SerializeAs<T>,
+            {
+                fn serialize_as<S>(source: &&'a T, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    <$adapter>::serialize_as(*source, serializer)
+                }
+            }
+        )+};
+    }
+
+    impl_forwarding_adapter!(AffineXY, XOnly);
+    #[cfg(feature = "base64")]
+    impl_forwarding_adapter!(Base64);
+    #[cfg(feature = "base58")]
+    impl_forwarding_adapter!(Base58);
+
+    /// Vector of at most `MAX` elements
+    ///
+    /// Vector is serialized as usual, but deserialization fails as soon as more than `MAX`
+    /// elements are encountered. Use it when a list of points/scalars is received from
+    /// untrusted party, so it can't make you allocate and process an arbitrarily large list.
+    ///
+    /// Elements are (de)serialized using `As` helper. By default, they're (de)serialized
+    /// in default format, but any other helper can be specified, e.g. `BoundedVec<16, Compact>`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), serde_json::Error> {
+    /// use generic_ec::{Point, curves::Secp256k1, serde::{BoundedVec, Compact}};
+    /// use serde::{Serialize, Deserialize};
+    /// use serde_with::serde_as;
+    ///
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// pub struct PublicShares {
+    ///     #[serde_as(as = "BoundedVec<3, Compact>")]
+    ///     shares: Vec<Point<Secp256k1>>,
+    /// }
+    ///
+    /// let g = Point::generator().to_point();
+    /// let shares = PublicShares { shares: vec![g; 4] };
+    /// let serialized = serde_json::to_string(&shares)?;
+    /// assert!(serde_json::from_str::<PublicShares>(&serialized).is_err());
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub struct BoundedVec<const MAX: usize, As = serde_with::Same>(PhantomType<As>);
+
+    #[cfg(feature = "alloc")]
+    impl<T, As, const MAX: usize> serde_with::SerializeAs<alloc::vec::Vec<T>> for BoundedVec<MAX, As>
+    where
+        As: serde_with::SerializeAs<T>,
+    {
+        fn serialize_as<S>(source: &alloc::vec::Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::ser::SerializeSeq;
+            let mut seq = serializer.serialize_seq(Some(source.len()))?;
+            for item in source {
+                seq.serialize_element(&serde_with::ser::SerializeAsWrap::<T, As>::new(item))?;
+            }
+            seq.end()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<'de, T, As, const MAX: usize> serde_with::DeserializeAs<'de, alloc::vec::Vec<T>>
+        for BoundedVec<MAX, As>
+    where
+        As: serde_with::DeserializeAs<'de, T>,
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<alloc::vec::Vec<T>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_seq(BoundedSeqVisitor::<T, As> {
+                max: MAX,
+                _ph: PhantomType::new(),
+            })
+        }
+    }
+
+    /// Deserializes a vector of at most `max` elements
+    ///
+    /// Same as [`BoundedVec`], but the bound is specified at runtime. Deserialization fails as
+    /// soon as more than `max` elements are encountered.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), serde_json::Error> {
+    /// use generic_ec::{Point, curves::Secp256k1};
+    ///
+    /// let g = Point::<Secp256k1>::generator().to_point();
+    /// let serialized = serde_json::to_value(vec![g; 4])?;
+    ///
+    /// let points: Vec<Point<Secp256k1>> =
+    ///     generic_ec::serde::deserialize_bounded_vec(&serialized, 4)?;
+    /// assert_eq!(points.len(), 4);
+    /// assert!(generic_ec::serde::deserialize_bounded_vec::<Point<Secp256k1>, _>(&serialized, 3).is_err());
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn deserialize_bounded_vec<'de, T, D>(
+        deserializer: D,
+        max: usize,
+    ) -> Result<alloc::vec::Vec<T>, D::Error>
+    where
+        T: serde::Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(BoundedSeqVisitor::<T, serde_with::Same> {
+            max,
+            _ph: PhantomType::new(),
+        })
+    }
+
+    /// Visits a sequence of at most `max` elements deserialized using `As` helper
+    #[cfg(feature = "alloc")]
+    struct BoundedSeqVisitor<T, As> {
+        max: usize,
+        _ph: PhantomType<(T, As)>,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<'de, T, As> serde::de::Visitor<'de> for BoundedSeqVisitor<T, As>
+    where
+        As: serde_with::DeserializeAs<'de, T>,
+    {
+        type Value = alloc::vec::Vec<T>;
+        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "a sequence of at most {} elements", self.max)
+        }
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let capacity = seq.size_hint().unwrap_or(0).min(self.max);
+            let mut items = alloc::vec::Vec::with_capacity(capacity);
+            while items.len() < self.max {
+                match seq.next_element::<serde_with::de::DeserializeAsWrap<T, As>>()? {
+                    Some(item) => items.push(item.into_inner()),
+                    None => return Ok(items),
+                }
+            }
+            if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                return Err(<A::Error as serde::de::Error>::invalid_length(
+                    self.max.saturating_add(1),
+                    &self,
+                ));
+            }
+            Ok(items)
+        }
+    }
+
+    /// Wraps a [`serde::Deserializer`] and overrides `fn is_human_readable()`
+    struct OverrideHumanReadable<D> {
+        is_human_readable: bool,
+        deserializer: D,
+    }
+    impl<'de, D> serde::Deserializer<'de> for OverrideHumanReadable<D>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        type Error = <D as serde::Deserializer<'de>>::Error;
+
+        fn is_human_readable(&self) -> bool {
+            self.is_human_readable
+        }
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: serde::de::Visitor<'de>,
+        {
+            self.deserializer.deserialize_any(visitor)
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    /// See [`serde::de::value`]. New type deserializer is missing in the `serde` crate.
+    struct NewTypeDeserializer<D> {
+        deserializer: D,
+    }
+    impl<D> NewTypeDeserializer<D> {
+        pub fn new(deserializer: D) -> Self {
+            Self { deserializer }
+        }
+    }
+    impl<'de, D> serde::Deserializer<'de> for NewTypeDeserializer<D>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        type Error = D::Error;
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: serde::de::Visitor<'de>,
+        {
+            visitor.visit_newtype_struct(self.deserializer)
+        }
+        fn is_human_readable(&self) -> bool {
+            self.deserializer.is_human_readable()
+        }
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
         }
     }
 
@@ -510,13 +1204,12 @@ mod optional {
         use serde::{Deserialize, Serialize};
         use serde_with::serde_as;
 
-        use crate::core::{CompressedEncoding, IntegerEncoding, UncompressedEncoding};
+        use crate::core::{ByteArray, CompressedEncoding, IntegerEncoding, UncompressedEncoding};
         use crate::{as_raw::AsRaw, Curve, Point, Scalar};
 
-        use super::{
-            error_msg::{InvalidPoint, InvalidScalar},
-            CurveName,
-        };
+        use crate::errors::{InvalidPoint, InvalidScalar};
+
+        use super::CurveName;
 
         #[serde_as]
         #[derive(Serialize, Deserialize)]
@@ -538,7 +1231,7 @@ mod optional {
         impl<E: Curve> TryFrom<PointUncompressed<E>> for Point<E> {
             type Error = InvalidPoint;
             fn try_from(value: PointUncompressed<E>) -> Result<Self, Self::Error> {
-                Point::from_bytes(value.point).or(Err(InvalidPoint))
+                Point::from_bytes(value.point)
             }
         }
 
@@ -557,7 +1250,207 @@ mod optional {
         impl<E: Curve> TryFrom<PointCompact<E>> for Point<E> {
             type Error = InvalidPoint;
             fn try_from(value: PointCompact<E>) -> Result<Self, Self::Error> {
-                Point::from_bytes(value.0).or(Err(InvalidPoint))
+                Point::from_bytes(value.0)
+            }
+        }
+
+        /// Point is serialized as its affine $x, y$ coordinates
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct PointAffine<E: Curve> {
+            #[serde_as(as = "super::utils::Bytes")]
+            x: E::CoordinateArray,
+            #[serde_as(as = "super::utils::Bytes")]
+            y: E::CoordinateArray,
+        }
+        impl<E: Curve> TryFrom<&Point<E>> for PointAffine<E>
+        where
+            Point<E>: crate::coords::HasAffineXY<E>,
+        {
+            type Error = &'static str;
+            fn try_from(p: &Point<E>) -> Result<Self, Self::Error> {
+                let coords = crate::coords::HasAffineXY::coords(p)
+                    .ok_or("zero point doesn't have affine coordinates")?;
+                Ok(Self::from(&coords))
+            }
+        }
+        impl<E: Curve> TryFrom<PointAffine<E>> for Point<E>
+        where
+            Point<E>: crate::coords::HasAffineXY<E>,
+        {
+            type Error = InvalidPoint;
+            fn try_from(value: PointAffine<E>) -> Result<Self, Self::Error> {
+                crate::coords::HasAffineXY::from_coords(&value.into())
+                    .ok_or(InvalidPoint::NotOnCurve)
+            }
+        }
+
+        impl<E: Curve> From<&crate::coords::Coordinates<E>> for PointAffine<E> {
+            fn from(coords: &crate::coords::Coordinates<E>) -> Self {
+                Self {
+                    x: coords.x.as_array().clone(),
+                    y: coords.y.as_array().clone(),
+                }
+            }
+        }
+        impl<E: Curve> From<PointAffine<E>> for crate::coords::Coordinates<E> {
+            fn from(value: PointAffine<E>) -> Self {
+                Self {
+                    x: crate::coords::Coordinate::new(value.x),
+                    y: crate::coords::Coordinate::new(value.y),
+                }
+            }
+        }
+
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct CoordinatesUncompressed<E: Curve> {
+            curve: CurveName<E>,
+            #[serde_as(as = "super::utils::Bytes")]
+            x: E::CoordinateArray,
+            #[serde_as(as = "super::utils::Bytes")]
+            y: E::CoordinateArray,
+        }
+        impl<E: Curve> From<&crate::coords::Coordinates<E>> for CoordinatesUncompressed<E> {
+            fn from(coords: &crate::coords::Coordinates<E>) -> Self {
+                Self {
+                    curve: CurveName::new(),
+                    x: coords.x.as_array().clone(),
+                    y: coords.y.as_array().clone(),
+                }
+            }
+        }
+        impl<E: Curve> From<CoordinatesUncompressed<E>> for crate::coords::Coordinates<E> {
+            fn from(value: CoordinatesUncompressed<E>) -> Self {
+                Self {
+                    x: crate::coords::Coordinate::new(value.x),
+                    y: crate::coords::Coordinate::new(value.y),
+                }
+            }
+        }
+
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct CoordinateUncompressed<E: Curve> {
+            curve: CurveName<E>,
+            #[serde_as(as = "super::utils::Bytes")]
+            coordinate: E::CoordinateArray,
+        }
+        impl<E: Curve> From<&crate::coords::Coordinate<E>> for CoordinateUncompressed<E> {
+            fn from(coord: &crate::coords::Coordinate<E>) -> Self {
+                Self {
+                    curve: CurveName::new(),
+                    coordinate: coord.as_array().clone(),
+                }
+            }
+        }
+        impl<E: Curve> From<CoordinateUncompressed<E>> for crate::coords::Coordinate<E> {
+            fn from(value: CoordinateUncompressed<E>) -> Self {
+                Self::new(value.coordinate)
+            }
+        }
+
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct CoordinateCompact<E: Curve>(
+            #[serde_as(as = "super::utils::Bytes")] E::CoordinateArray,
+        );
+        impl<E: Curve> From<&crate::coords::Coordinate<E>> for CoordinateCompact<E> {
+            fn from(coord: &crate::coords::Coordinate<E>) -> Self {
+                Self(coord.as_array().clone())
+            }
+        }
+        impl<E: Curve> From<CoordinateCompact<E>> for crate::coords::Coordinate<E> {
+            fn from(value: CoordinateCompact<E>) -> Self {
+                Self::new(value.0)
+            }
+        }
+
+        /// Encoded point is serialized as is, it can be either compressed or uncompressed
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct EncodedPoint<E: Curve> {
+            curve: CurveName<E>,
+            #[serde_as(as = "super::utils::BytesUpTo")]
+            point: (E::UncompressedPointArray, usize),
+        }
+        impl<E: Curve> From<&crate::EncodedPoint<E>> for EncodedPoint<E> {
+            fn from(p: &crate::EncodedPoint<E>) -> Self {
+                Self {
+                    curve: CurveName::new(),
+                    point: encoded_point_bytes(p),
+                }
+            }
+        }
+        impl<E: Curve> TryFrom<EncodedPoint<E>> for crate::EncodedPoint<E> {
+            type Error = InvalidPoint;
+            fn try_from(value: EncodedPoint<E>) -> Result<Self, Self::Error> {
+                encoded_point_from_bytes(value.point)
+            }
+        }
+
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct EncodedPointCompact<E: Curve>(
+            #[serde_as(as = "super::utils::BytesUpTo")] (E::UncompressedPointArray, usize),
+        );
+        impl<E: Curve> From<&crate::EncodedPoint<E>> for EncodedPointCompact<E> {
+            fn from(p: &crate::EncodedPoint<E>) -> Self {
+                Self(encoded_point_bytes(p))
+            }
+        }
+        impl<E: Curve> TryFrom<EncodedPointCompact<E>> for crate::EncodedPoint<E> {
+            type Error = InvalidPoint;
+            fn try_from(value: EncodedPointCompact<E>) -> Result<Self, Self::Error> {
+                encoded_point_from_bytes(value.0)
+            }
+        }
+
+        fn encoded_point_bytes<E: Curve>(
+            p: &crate::EncodedPoint<E>,
+        ) -> (E::UncompressedPointArray, usize) {
+            let mut bytes = E::UncompressedPointArray::zeroes();
+            let len = p.as_bytes().len();
+            bytes.as_mut()[..len].copy_from_slice(p.as_bytes());
+            (bytes, len)
+        }
+        fn encoded_point_from_bytes<E: Curve>(
+            (bytes, len): (E::UncompressedPointArray, usize),
+        ) -> Result<crate::EncodedPoint<E>, InvalidPoint> {
+            // Strict decoding guarantees that point encoded in the same form
+            // results into the same bytes
+            let bytes = bytes
+                .as_ref()
+                .get(..len)
+                .ok_or(InvalidPoint::InvalidLength)?;
+            let point = Point::<E>::from_bytes_strict(bytes)?;
+            Ok(point.to_bytes(len == Point::<E>::COMPRESSED_LEN))
+        }
+
+        /// X-only point is serialized as its $x$ coordinate
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct XOnlyPoint<E: Curve>(#[serde_as(as = "super::utils::Bytes")] E::CoordinateArray);
+        impl<E: Curve> From<&crate::XOnlyPoint<E>> for XOnlyPoint<E> {
+            fn from(p: &crate::XOnlyPoint<E>) -> Self {
+                Self(p.x().as_array().clone())
+            }
+        }
+        impl<E: Curve> TryFrom<XOnlyPoint<E>> for crate::XOnlyPoint<E>
+        where
+            Point<E>: crate::coords::HasAffineXAndParity<E>,
+        {
+            type Error = InvalidPoint;
+            fn try_from(value: XOnlyPoint<E>) -> Result<Self, Self::Error> {
+                crate::XOnlyPoint::lift_x(&crate::coords::Coordinate::new(value.0))
+                    .ok_or(InvalidPoint::NotOnCurve)
             }
         }
 
@@ -581,7 +1474,20 @@ mod optional {
         impl<E: Curve> TryFrom<ScalarUncompressed<E>> for Scalar<E> {
             type Error = InvalidScalar;
             fn try_from(value: ScalarUncompressed<E>) -> Result<Self, Self::Error> {
-                Scalar::from_be_bytes(value.scalar).or(Err(InvalidScalar))
+                Scalar::from_be_bytes(value.scalar)
+            }
+        }
+        impl<E: Curve> From<&crate::EncodedScalar<E>> for ScalarUncompressed<E> {
+            fn from(s: &crate::EncodedScalar<E>) -> Self {
+                Self {
+                    curve: CurveName::new(),
+                    scalar: s.as_raw().clone(),
+                }
+            }
+        }
+        impl<E: Curve> From<ScalarUncompressed<E>> for crate::EncodedScalar<E> {
+            fn from(value: ScalarUncompressed<E>) -> Self {
+                crate::EncodedScalar::new(value.scalar)
             }
         }
 
@@ -598,18 +1504,30 @@ mod optional {
         impl<E: Curve> TryFrom<ScalarCompact<E>> for Scalar<E> {
             type Error = InvalidScalar;
             fn try_from(value: ScalarCompact<E>) -> Result<Self, Self::Error> {
-                Scalar::from_be_bytes(&value.0).or(Err(InvalidScalar))
+                Scalar::from_be_bytes(&value.0)
+            }
+        }
+        impl<E: Curve> From<&crate::EncodedScalar<E>> for ScalarCompact<E> {
+            fn from(s: &crate::EncodedScalar<E>) -> Self {
+                Self(s.as_raw().clone())
+            }
+        }
+        impl<E: Curve> From<ScalarCompact<E>> for crate::EncodedScalar<E> {
+            fn from(value: ScalarCompact<E>) -> Self {
+                crate::EncodedScalar::new(value.0)
             }
         }
     }
 
-    mod utils {
+    pub(crate) mod utils {
         use core::fmt;
 
         use serde::de::{self, Visitor};
         use serde_with::{DeserializeAs, SerializeAs};
 
         use crate::core::ByteArray;
+        #[cfg(any(feature = "base64", feature = "base58"))]
+        use zeroize::Zeroize;
 
         pub struct Bytes;
 
@@ -722,6 +1640,220 @@ mod optional {
                 }
             }
         }
+
+        /// Bytes of length at most `A::LEN`, (de)serialized in the same way as [`Bytes`]
+        ///
+        /// Used for data that can have different length (e.g. point that's either compressed
+        /// or uncompressed). Represented as byte array and its actual length.
+        pub struct BytesUpTo;
+
+        impl<A> SerializeAs<(A, usize)> for BytesUpTo
+        where
+            A: ByteArray,
+        {
+            fn serialize_as<S>(source: &(A, usize), serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let bytes = source.0.as_ref().get(..source.1).ok_or_else(|| {
+                    <S::Error as serde::ser::Error>::custom("length exceeds the array size")
+                })?;
+                Bytes::serialize_as(&bytes, serializer)
+            }
+        }
+
+        impl<'de, A> DeserializeAs<'de, (A, usize)> for BytesUpTo
+        where
+            A: ByteArray,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<(A, usize), D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct BytesVisitor<A>(A);
+                impl<'de, A: AsRef<[u8]> + AsMut<[u8]>> Visitor<'de> for BytesVisitor<A> {
+                    type Value = (A, usize);
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "at most {} bytes", self.0.as_ref().len())
+                    }
+                    fn visit_str<E>(mut self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        let max_len = self.0.as_ref().len();
+                        let len = v.len() / 2;
+                        let out = self.0.as_mut().get_mut(..len).ok_or_else(|| {
+                            E::invalid_length(len, &super::error_msg::ExpectedAtMost(max_len))
+                        })?;
+                        hex::decode_to_slice(v, out).map_err(E::custom)?;
+                        Ok((self.0, len))
+                    }
+                    fn visit_bytes<E>(mut self, v: &[u8]) -> Result<Self::Value, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        let max_len = self.0.as_ref().len();
+                        let out = self.0.as_mut().get_mut(..v.len()).ok_or_else(|| {
+                            E::invalid_length(v.len(), &super::error_msg::ExpectedAtMost(max_len))
+                        })?;
+                        out.copy_from_slice(v);
+                        Ok((self.0, v.len()))
+                    }
+                    fn visit_seq<S>(mut self, mut seq: S) -> Result<Self::Value, S::Error>
+                    where
+                        S: serde::de::SeqAccess<'de>,
+                    {
+                        let max_len = self.0.as_ref().len();
+                        let mut len = 0;
+                        while let Some(byte) = seq.next_element()? {
+                            let byte_i = self.0.as_mut().get_mut(len).ok_or_else(|| {
+                                <S::Error as de::Error>::invalid_length(
+                                    len + 1,
+                                    &super::error_msg::ExpectedAtMost(max_len),
+                                )
+                            })?;
+                            *byte_i = byte;
+                            len += 1;
+                        }
+                        Ok((self.0, len))
+                    }
+                }
+                let visitor = BytesVisitor(A::zeroes());
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_str(visitor)
+                } else {
+                    deserializer.deserialize_bytes(visitor)
+                }
+            }
+        }
+
+        /// Text encoding of bytes used in human-readable formats
+        #[cfg(any(feature = "base64", feature = "base58"))]
+        pub trait TextEncoding {
+            type Error: fmt::Display;
+            /// Encodes `bytes` into `out`, returns encoded string
+            fn encode<'o>(bytes: &[u8], out: &'o mut [u8]) -> Option<&'o str>;
+            /// Decodes `s` into `out`, returns amount of decoded bytes
+            fn decode(s: &str, out: &mut [u8]) -> Result<usize, Self::Error>;
+        }
+
+        #[cfg(feature = "base64")]
+        pub struct Base64Alphabet;
+        #[cfg(feature = "base64")]
+        impl TextEncoding for Base64Alphabet {
+            type Error = base64::DecodeError;
+            fn encode<'o>(bytes: &[u8], out: &'o mut [u8]) -> Option<&'o str> {
+                if out.len() < bytes.len().div_ceil(3) * 4 {
+                    return None;
+                }
+                let len = base64::encode_config_slice(bytes, base64::STANDARD, out);
+                core::str::from_utf8(&out[..len]).ok()
+            }
+            fn decode(s: &str, out: &mut [u8]) -> Result<usize, Self::Error> {
+                // `decode_config_slice` panics if output buffer is too small
+                if out.len() < s.len().div_ceil(4) * 3 {
+                    return Err(base64::DecodeError::InvalidLength);
+                }
+                base64::decode_config_slice(s, base64::STANDARD, out)
+            }
+        }
+
+        #[cfg(feature = "base58")]
+        pub struct Base58Alphabet;
+        #[cfg(feature = "base58")]
+        impl TextEncoding for Base58Alphabet {
+            type Error = bs58::decode::Error;
+            fn encode<'o>(bytes: &[u8], out: &'o mut [u8]) -> Option<&'o str> {
+                let len = bs58::encode(bytes).onto(&mut *out).ok()?;
+                core::str::from_utf8(&out[..len]).ok()
+            }
+            fn decode(s: &str, out: &mut [u8]) -> Result<usize, Self::Error> {
+                bs58::decode(s).onto(out)
+            }
+        }
+
+        /// Bytes encoded with `Enc` in human-readable formats, and raw bytes in binary formats
+        #[cfg(any(feature = "base64", feature = "base58"))]
+        pub struct Text<Enc>(core::marker::PhantomData<Enc>);
+
+        #[cfg(any(feature = "base64", feature = "base58"))]
+        impl<T, Enc> SerializeAs<T> for Text<Enc>
+        where
+            T: AsRef<[u8]>,
+            Enc: TextEncoding,
+        {
+            fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                if serializer.is_human_readable() {
+                    // Similarly to `Bytes`, we only support serialization of byte arrays up to 128 bytes
+                    let mut buf = [0u8; 256];
+                    let result = match Enc::encode(source.as_ref(), &mut buf) {
+                        Some(encoded) => serializer.serialize_str(encoded),
+                        None => Err(<S::Error as serde::ser::Error>::custom(
+                            super::error_msg::ByteArrayTooLarge {
+                                len: source.as_ref().len(),
+                                supported_len: 128,
+                            },
+                        )),
+                    };
+                    // Encoded bytes may be a secret scalar
+                    buf.zeroize();
+                    result
+                } else {
+                    serializer.serialize_bytes(source.as_ref())
+                }
+            }
+        }
+
+        #[cfg(any(feature = "base64", feature = "base58"))]
+        impl<'de, T, Enc> DeserializeAs<'de, T> for Text<Enc>
+        where
+            T: ByteArray,
+            Enc: TextEncoding,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct TextVisitor<T, Enc>(T, core::marker::PhantomData<Enc>);
+                impl<'de, T: AsMut<[u8]>, Enc: TextEncoding> Visitor<'de> for TextVisitor<T, Enc> {
+                    type Value = T;
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "encoded bytes")
+                    }
+                    fn visit_str<E>(mut self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        let expected_len = self.0.as_mut().len();
+                        let mut buf = [0u8; 192];
+                        let result = match Enc::decode(v, &mut buf) {
+                            Ok(len) if len == expected_len => {
+                                self.0.as_mut().copy_from_slice(&buf[..len]);
+                                Ok(self.0)
+                            }
+                            Ok(len) => Err(E::invalid_length(
+                                len,
+                                &super::error_msg::ExpectedLen(expected_len),
+                            )),
+                            Err(err) => Err(E::custom(err)),
+                        };
+                        // Decoded bytes may be a secret scalar
+                        buf.zeroize();
+                        result
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    deserializer
+                        .deserialize_str(TextVisitor(T::zeroes(), core::marker::PhantomData::<Enc>))
+                } else {
+                    Bytes::deserialize_as(deserializer)
+                }
+            }
+        }
     }
 
     pub(super) mod error_msg {
@@ -753,17 +1885,11 @@ mod optional {
             }
         }
 
-        pub struct InvalidPoint;
-        impl fmt::Display for InvalidPoint {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "invalid point")
-            }
-        }
+        pub struct ExpectedAtMost(pub usize);
 
-        pub struct InvalidScalar;
-        impl fmt::Display for InvalidScalar {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "invalid scalar")
+        impl Expected for ExpectedAtMost {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "at most {} bytes", self.0)
             }
         }
 
@@ -0,0 +1,236 @@
+//! JSON Schema of serialized points and scalars
+
+use alloc::{borrow::Cow, format};
+
+use phantom_type::PhantomType;
+use schemars::{json_schema, JsonSchema, SchemaGenerator};
+
+use crate::{Curve, NonZero, Point, Scalar, SecretScalar};
+
+use super::{Compact, CurveName, PreferCompact};
+
+/// JSON Schema of `T` serialized with [serde_with] adapter `As`
+///
+/// `schemars` can't see through `#[serde_as(as = "...")]` attribute, so field schema needs to be
+/// specified explicitly via `#[schemars(with = "...")]`:
+///
+/// ```rust
+/// use generic_ec::{Curve, Point, Scalar, serde::{Compact, SchemaAs}};
+/// use serde::{Serialize, Deserialize};
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize, schemars::JsonSchema)]
+/// #[serde(bound = "")]
+/// #[schemars(bound = "")]
+/// pub struct ZkProof<E: Curve> {
+///     some_point: Point<E>,
+///     #[serde_as(as = "Compact")]
+///     #[schemars(with = "SchemaAs<Scalar<E>, Compact>")]
+///     some_scalar: Scalar<E>,
+/// }
+/// ```
+///
+/// Implements [`JsonSchema`] when `As` is [`Compact`] or [`PreferCompact`].
+pub struct SchemaAs<T, As>(PhantomType<(T, As)>);
+
+impl<E: Curve> JsonSchema for CurveName<E> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("CurveName_{}", E::CURVE_NAME).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> schemars::Schema {
+        json_schema!({
+            "type": "string",
+            "const": E::CURVE_NAME,
+        })
+    }
+}
+
+impl<E: Curve> JsonSchema for Point<E> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Point_{}", E::CURVE_NAME).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> schemars::Schema {
+        json_schema!({
+            "type": "object",
+            "description": format!("Point on {} curve", E::CURVE_NAME),
+            "properties": {
+                "curve": generator.subschema_for::<CurveName<E>>(),
+                "point": hex_string(
+                    Point::<E>::UNCOMPRESSED_LEN,
+                    "Hex-encoded uncompressed point",
+                ),
+            },
+            "required": ["curve", "point"],
+        })
+    }
+}
+
+impl<E: Curve> JsonSchema for Scalar<E> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Scalar_{}", E::CURVE_NAME).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> schemars::Schema {
+        json_schema!({
+            "type": "object",
+            "description": format!("Scalar of {} curve", E::CURVE_NAME),
+            "properties": {
+                "curve": generator.subschema_for::<CurveName<E>>(),
+                "scalar": hex_string(
+                    Scalar::<E>::SERIALIZED_LEN,
+                    "Hex-encoded scalar in big-endian order",
+                ),
+            },
+            "required": ["curve", "scalar"],
+        })
+    }
+}
+
+impl<E: Curve> JsonSchema for SecretScalar<E> {
+    fn inline_schema() -> bool {
+        Scalar::<E>::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Scalar::<E>::schema_name()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> schemars::Schema {
+        Scalar::<E>::json_schema(generator)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for NonZero<T> {
+    fn inline_schema() -> bool {
+        T::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        T::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> schemars::Schema {
+        T::json_schema(generator)
+    }
+}
+
+impl<E: Curve> JsonSchema for SchemaAs<Point<E>, Compact> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("CompactPoint_{}", E::CURVE_NAME).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> schemars::Schema {
+        hex_string(
+            Point::<E>::COMPRESSED_LEN,
+            &format!("Hex-encoded compressed point on {} curve", E::CURVE_NAME),
+        )
+    }
+}
+
+impl<E: Curve> JsonSchema for SchemaAs<Scalar<E>, Compact> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("CompactScalar_{}", E::CURVE_NAME).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> schemars::Schema {
+        hex_string(
+            Scalar::<E>::SERIALIZED_LEN,
+            &format!(
+                "Hex-encoded scalar of {} curve in big-endian order",
+                E::CURVE_NAME
+            ),
+        )
+    }
+}
+
+impl<E: Curve> JsonSchema for SchemaAs<SecretScalar<E>, Compact> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        SchemaAs::<Scalar<E>, Compact>::schema_name()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> schemars::Schema {
+        SchemaAs::<Scalar<E>, Compact>::json_schema(generator)
+    }
+}
+
+impl<T> JsonSchema for SchemaAs<NonZero<T>, Compact>
+where
+    SchemaAs<T, Compact>: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        SchemaAs::<T, Compact>::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        SchemaAs::<T, Compact>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        SchemaAs::<T, Compact>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> schemars::Schema {
+        SchemaAs::<T, Compact>::json_schema(generator)
+    }
+}
+
+impl<T> JsonSchema for SchemaAs<T, PreferCompact>
+where
+    T: JsonSchema,
+    SchemaAs<T, Compact>: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("PreferCompact_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("PreferCompact_{}", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> schemars::Schema {
+        json_schema!({
+            "anyOf": [
+                generator.subschema_for::<SchemaAs<T, Compact>>(),
+                generator.subschema_for::<T>(),
+            ],
+        })
+    }
+}
+
+/// Schema of a hex string encoding exactly `len` bytes
+fn hex_string(len: usize, description: &str) -> schemars::Schema {
+    json_schema!({
+        "type": "string",
+        "description": description,
+        "minLength": 2 * len,
+        "maxLength": 2 * len,
+        "pattern": format!("^[0-9a-fA-F]{{{}}}$", 2 * len),
+    })
+}
@@ -0,0 +1,349 @@
+//! OpenSSH key formats
+//!
+//! Public keys are encoded as OpenSSH public key lines (as found in `authorized_keys` and `.pub`
+//! files), secret keys are encoded as unencrypted `openssh-key-v1` containers (as produced by
+//! `ssh-keygen`). Supported curves implement [`SshCurve`]:
+//!
+//! | Curve     | Key type              | Specification |
+//! |-----------|-----------------------|---------------|
+//! | Ed25519   | `ssh-ed25519`         | [RFC 8709]    |
+//! | secp256r1 | `ecdsa-sha2-nistp256` | [RFC 5656]    |
+//!
+//! ```rust
+//! use generic_ec::{Point, curves::Ed25519};
+//!
+//! let line = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIClDo2fbokq7rvO15BFtyRO5yngY/2sKaA/8vB5PcfS8 alice@example";
+//! let public_key = Point::<Ed25519>::from_openssh_public_key(line)?;
+//! assert_eq!(public_key.to_openssh_public_key("alice@example")?, line);
+//! assert_eq!(
+//!     public_key.openssh_fingerprint()?,
+//!     "SHA256:9o6psSACGhbPWRWBOu3Ey0ax77YkTCEzJdMzRSl7nhs",
+//! );
+//! # Ok::<_, generic_ec::errors::SshError>(())
+//! ```
+//!
+//! Ed25519 secret key is stored as a seed, which is expanded into a secret scalar on import.
+//! The expansion can't be reverted, so exporting [`SecretScalar<Ed25519>`](crate::curves::Ed25519)
+//! returns [`SshError::Unsupported`].
+//!
+//! [RFC 5656]: https://www.rfc-editor.org/rfc/rfc5656#section-3.1
+//! [RFC 8709]: https://www.rfc-editor.org/rfc/rfc8709#section-4
+
+use alloc::{string::String, vec::Vec};
+
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    errors::{InvalidPoint, InvalidScalar, SshError},
+    key_format::{self, ArmorError},
+    traits::IsZero,
+    Curve, Point, SecretScalar,
+};
+
+/// Label of `openssh-key-v1` armor
+const PRIVATE_KEY_LABEL: &str = "OPENSSH PRIVATE KEY";
+/// Magic bytes at the beginning of `openssh-key-v1` container
+const AUTH_MAGIC: &[u8] = b"openssh-key-v1\0";
+/// Block size that private section is padded to, when it's not encrypted
+const BLOCK_SIZE: usize = 8;
+
+/// Curve supported by OpenSSH
+pub trait SshCurve: Curve {
+    /// Key type name, e.g. `ssh-ed25519`
+    const KEY_TYPE: &'static str;
+}
+
+#[cfg(feature = "curve-ed25519")]
+impl SshCurve for crate::curves::Ed25519 {
+    const KEY_TYPE: &'static str = "ssh-ed25519";
+}
+
+#[cfg(feature = "curve-secp256r1")]
+impl SshCurve for crate::curves::Secp256r1 {
+    const KEY_TYPE: &'static str = "ecdsa-sha2-nistp256";
+}
+
+impl<E: SshCurve> Point<E> {
+    /// Encodes public key as OpenSSH public key line
+    ///
+    /// Comment is appended to the line if it's not empty. Returns error if point is zero.
+    pub fn to_openssh_public_key(&self, comment: &str) -> Result<String, SshError> {
+        let blob = public_key_blob(self)?;
+        let mut line = String::from(E::KEY_TYPE);
+        line.push(' ');
+        line.push_str(&base64::encode(blob));
+        if !comment.is_empty() {
+            line.push(' ');
+            line.push_str(comment);
+        }
+        Ok(line)
+    }
+
+    /// Decodes public key from OpenSSH public key line
+    ///
+    /// Comment, if present, is ignored. `authorized_keys` options preceding the key type
+    /// are not supported.
+    pub fn from_openssh_public_key(line: &str) -> Result<Self, SshError> {
+        let mut parts = line.split_whitespace();
+        let key_type = parts.next().ok_or(SshError::Malformed)?;
+        if key_type != E::KEY_TYPE {
+            return Err(SshError::KeyTypeMismatch);
+        }
+        let blob = parts.next().ok_or(SshError::Malformed)?;
+        let blob = base64::decode(blob).map_err(|_| SshError::Malformed)?;
+
+        let mut reader = Reader(&blob);
+        let point = read_public_key(&mut reader)?;
+        reader.finish()?;
+        Ok(point)
+    }
+
+    /// Computes SHA-256 fingerprint of the public key, as displayed by `ssh-keygen -l`
+    ///
+    /// Fingerprint has form `SHA256:<base64 of hash without padding>`. Returns error if point is zero.
+    pub fn openssh_fingerprint(&self) -> Result<String, SshError> {
+        use sha2::Digest;
+
+        let hash = sha2::Sha256::digest(public_key_blob(self)?);
+        let mut fingerprint = String::from("SHA256:");
+        fingerprint.push_str(&base64::encode_config(hash, base64::STANDARD_NO_PAD));
+        Ok(fingerprint)
+    }
+}
+
+impl<E: SshCurve> SecretScalar<E> {
+    /// Encodes secret key as unencrypted `openssh-key-v1` container
+    ///
+    /// Returns [`SshError::Unsupported`] for Ed25519, see [`ssh`](crate::ssh) module.
+    pub fn to_openssh_private_key(&self, comment: &str) -> Result<Zeroizing<String>, SshError> {
+        let curve_name = ecdsa_curve_name::<E>().ok_or(SshError::Unsupported)?;
+        let public_key = Point::generator() * self;
+        let public_key_blob = public_key_blob(&public_key)?;
+
+        // Check integers are only used to verify decryption, so they're derived from public key
+        // instead of being random
+        let checkint = {
+            use sha2::Digest;
+            let hash = sha2::Sha256::digest(&public_key_blob);
+            [hash[0], hash[1], hash[2], hash[3]]
+        };
+
+        let mut private_section = Zeroizing::new(Vec::new());
+        private_section.extend_from_slice(&checkint);
+        private_section.extend_from_slice(&checkint);
+        write_string(&mut private_section, E::KEY_TYPE.as_bytes());
+        write_string(&mut private_section, curve_name.as_bytes());
+        write_string(&mut private_section, &public_key.to_bytes(false));
+        let mut d = self.as_ref().to_be_bytes();
+        write_mpint(&mut private_section, &d);
+        d.as_mut().zeroize();
+        write_string(&mut private_section, comment.as_bytes());
+        let padding = (BLOCK_SIZE - private_section.len() % BLOCK_SIZE) % BLOCK_SIZE;
+        private_section.extend((1..=padding).map(|i| i as u8));
+
+        let mut container = Zeroizing::new(Vec::new());
+        container.extend_from_slice(AUTH_MAGIC);
+        write_string(&mut container, b"none");
+        write_string(&mut container, b"none");
+        write_string(&mut container, b"");
+        container.extend_from_slice(&1u32.to_be_bytes());
+        write_string(&mut container, &public_key_blob);
+        write_string(&mut container, &private_section);
+
+        Ok(Zeroizing::new(key_format::encode_armor(
+            PRIVATE_KEY_LABEL,
+            &container,
+            70,
+        )))
+    }
+
+    /// Decodes secret key from unencrypted `openssh-key-v1` container
+    ///
+    /// Returns [`SshError::Encrypted`] if the key is protected with passphrase.
+    pub fn from_openssh_private_key(key: &str) -> Result<Self, SshError> {
+        let container =
+            key_format::decode_armor(PRIVATE_KEY_LABEL, key).map_err(|err| match err {
+                ArmorError::Malformed | ArmorError::UnexpectedLabel => SshError::Malformed,
+            })?;
+
+        let mut reader = Reader(&container);
+        if reader.take(AUTH_MAGIC.len())? != AUTH_MAGIC {
+            return Err(SshError::Malformed);
+        }
+        let cipher_name = reader.read_string()?;
+        let kdf_name = reader.read_string()?;
+        let _kdf_options = reader.read_string()?;
+        if cipher_name != b"none" || kdf_name != b"none" {
+            return Err(SshError::Encrypted);
+        }
+        if reader.read_u32()? != 1 {
+            // Containers with several keys are not supported
+            return Err(SshError::Unsupported);
+        }
+        let public_key = {
+            let mut blob = Reader(reader.read_string()?);
+            let point = read_public_key::<E>(&mut blob)?;
+            blob.finish()?;
+            point
+        };
+        let mut private_section = Reader(reader.read_string()?);
+        reader.finish()?;
+
+        if private_section.read_u32()? != private_section.read_u32()? {
+            return Err(SshError::Malformed);
+        }
+        let secret_key = read_secret_key::<E>(&mut private_section, public_key)?;
+        let _comment = private_section.read_string()?;
+        let padding = private_section.0;
+        if padding.len() >= BLOCK_SIZE || padding.iter().zip(1..).any(|(&b, i)| b != i) {
+            return Err(SshError::Malformed);
+        }
+
+        Ok(secret_key)
+    }
+}
+
+/// Returns curve name used in ECDSA keys, or `None` for non-ECDSA keys
+fn ecdsa_curve_name<E: SshCurve>() -> Option<&'static str> {
+    E::KEY_TYPE.strip_prefix("ecdsa-sha2-")
+}
+
+/// Encodes public key in SSH wire format
+fn public_key_blob<E: SshCurve>(point: &Point<E>) -> Result<Vec<u8>, SshError> {
+    if point.is_zero() {
+        return Err(InvalidPoint::Identity.into());
+    }
+    let mut blob = Vec::new();
+    write_string(&mut blob, E::KEY_TYPE.as_bytes());
+    match ecdsa_curve_name::<E>() {
+        Some(curve_name) => {
+            write_string(&mut blob, curve_name.as_bytes());
+            write_string(&mut blob, &point.to_bytes(false));
+        }
+        None => write_string(&mut blob, &point.to_bytes(true)),
+    }
+    Ok(blob)
+}
+
+/// Reads public key in SSH wire format
+fn read_public_key<E: SshCurve>(reader: &mut Reader<'_>) -> Result<Point<E>, SshError> {
+    if reader.read_string()? != E::KEY_TYPE.as_bytes() {
+        return Err(SshError::KeyTypeMismatch);
+    }
+    let point = match ecdsa_curve_name::<E>() {
+        Some(curve_name) => {
+            if reader.read_string()? != curve_name.as_bytes() {
+                return Err(SshError::KeyTypeMismatch);
+            }
+            Point::from_bytes_strict(reader.read_string()?)?
+        }
+        None => {
+            let bytes = reader.read_string()?;
+            if bytes.len() != Point::<E>::COMPRESSED_LEN {
+                return Err(InvalidPoint::InvalidLength.into());
+            }
+            Point::from_bytes_strict(bytes)?
+        }
+    };
+    if point.is_zero() {
+        return Err(InvalidPoint::Identity.into());
+    }
+    Ok(point)
+}
+
+/// Reads key type, public and secret keys from private section of `openssh-key-v1` container
+fn read_secret_key<E: SshCurve>(
+    reader: &mut Reader<'_>,
+    public_key: Point<E>,
+) -> Result<SecretScalar<E>, SshError> {
+    if read_public_key::<E>(reader)? != public_key {
+        return Err(SshError::PublicKeyMismatch);
+    }
+    let secret_key = match ecdsa_curve_name::<E>() {
+        Some(_) => {
+            let d = reader.read_mpint()?;
+            SecretScalar::<E>::from_be_bytes(d)?
+        }
+        None => {
+            // Ed25519 secret key is `seed || public_key`
+            let sk = reader.read_string()?;
+            if sk.len() != 64 || sk[32..] != *public_key.to_bytes(true) {
+                return Err(SshError::Malformed);
+            }
+            key_format::expand_ed25519_seed(&sk[..32])?
+        }
+    };
+    if secret_key.as_ref().is_zero() {
+        return Err(InvalidScalar::OutOfRange.into());
+    }
+    if Point::generator() * &secret_key != public_key {
+        return Err(SshError::PublicKeyMismatch);
+    }
+    Ok(secret_key)
+}
+
+fn write_string(out: &mut Vec<u8>, bytes: &[u8]) {
+    // Keys are always much smaller than 4GB
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+/// Writes non-negative integer given as big-endian bytes
+fn write_mpint(out: &mut Vec<u8>, be_bytes: &[u8]) {
+    let first_nonzero = be_bytes
+        .iter()
+        .position(|&b| b != 0)
+        .unwrap_or(be_bytes.len());
+    let bytes = &be_bytes[first_nonzero..];
+    // Leading zero byte indicates that integer is positive
+    let needs_zero = bytes.first().map(|&b| b & 0x80 != 0).unwrap_or(false);
+    out.extend_from_slice(&((bytes.len() + usize::from(needs_zero)) as u32).to_be_bytes());
+    if needs_zero {
+        out.push(0);
+    }
+    out.extend_from_slice(bytes);
+}
+
+/// Reader of SSH wire format
+struct Reader<'b>(&'b [u8]);
+
+impl<'b> Reader<'b> {
+    fn take(&mut self, len: usize) -> Result<&'b [u8], SshError> {
+        if self.0.len() < len {
+            return Err(SshError::Malformed);
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, SshError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_string(&mut self) -> Result<&'b [u8], SshError> {
+        let len = self.read_u32()?;
+        self.take(usize::try_from(len).map_err(|_| SshError::Malformed)?)
+    }
+
+    /// Reads non-negative integer, returns its big-endian bytes
+    fn read_mpint(&mut self) -> Result<&'b [u8], SshError> {
+        let bytes = self.read_string()?;
+        match bytes {
+            [0, rest @ ..] if rest.first().map(|&b| b & 0x80 != 0).unwrap_or(false) => Ok(rest),
+            [first, ..] if first & 0x80 == 0 && *first != 0 => Ok(bytes),
+            [] => Ok(bytes),
+            // Negative or non-minimally encoded integer
+            _ => Err(SshError::Malformed),
+        }
+    }
+
+    fn finish(self) -> Result<(), SshError> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(SshError::Malformed)
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! X25519 key agreement and Montgomery form of Ed25519 points
+//!
+//! Edwards curve used by [`Ed25519`] is birationally equivalent to Montgomery curve25519, which is
+//! used by X25519 key agreement defined in [RFC 7748]. This module allows converting points between
+//! two forms, and doing X25519 key agreement with the key material managed as [`SecretScalar<Ed25519>`].
+//!
+//! ```rust
+//! use generic_ec::{Point, SecretScalar, curves::Ed25519, x25519::MontgomeryPoint};
+//! # let mut rng = rand::rngs::OsRng;
+//!
+//! let alice_sk = SecretScalar::<Ed25519>::random(&mut rng);
+//! let alice_pk = (Point::generator() * &alice_sk).to_montgomery();
+//!
+//! let bob_sk = SecretScalar::<Ed25519>::random(&mut rng);
+//! let bob_pk = (Point::generator() * &bob_sk).to_montgomery();
+//!
+//! let shared_secret_learned_by_alice = bob_pk.diffie_hellman(&alice_sk);
+//! let shared_secret_learned_by_bob = alice_pk.diffie_hellman(&bob_sk);
+//! assert_eq!(shared_secret_learned_by_alice, shared_secret_learned_by_bob);
+//! ```
+//!
+//! [RFC 7748]: https://www.rfc-editor.org/rfc/rfc7748
+
+use generic_ec_curves::ed25519::x25519 as raw;
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::{
+    as_raw::{AsRaw, TryFromRaw},
+    coords::Sign,
+    curves::Ed25519,
+    FullGroupPoint, Point, Scalar, SecretScalar,
+};
+
+/// Point on curve25519 in Montgomery form, represented by its $u$ coordinate
+///
+/// Coordinate is encoded as 32 bytes little-endian integer, as defined in [RFC 7748]. Any 32
+/// bytes are accepted as $u$ coordinate, so it may also be a point on the twist of the curve.
+///
+/// [RFC 7748]: https://www.rfc-editor.org/rfc/rfc7748#section-5
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MontgomeryPoint([u8; 32]);
+
+impl MontgomeryPoint {
+    /// Base point of curve25519 ($u = 9$) which corresponds to Ed25519 generator
+    pub const BASEPOINT: Self = Self([
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]);
+
+    /// Constructs a point from its $u$ coordinate
+    pub const fn from_bytes(u: [u8; 32]) -> Self {
+        Self(u)
+    }
+
+    /// Returns $u$ coordinate of the point
+    pub const fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Returns reference to $u$ coordinate of the point
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Converts the point into Edwards form
+    ///
+    /// Montgomery form doesn't preserve sign of $x$ coordinate, so it needs to be provided
+    /// explicitly. Returns `None` if $u$ coordinate doesn't correspond to a valid [`Point<Ed25519>`]:
+    /// it's not canonically encoded, it's a point on the twist, or the point has torsion component.
+    ///
+    /// Note that identity point can't be recovered as it's mapped to $u = 0$, which also
+    /// corresponds to point of order 2.
+    pub fn to_edwards(&self, x_sign: Sign) -> Option<Point<Ed25519>> {
+        let point = raw::from_montgomery(&self.0, x_sign)?;
+        let point = Point::try_from_raw(point)?;
+        if point.to_montgomery() != *self {
+            return None;
+        }
+        Some(point)
+    }
+
+    /// X25519 function: multiplies the point at clamped integer `k`
+    ///
+    /// Same as [`x25519(k, self.to_bytes())`](x25519)
+    pub fn mul_clamped(&self, k: [u8; 32]) -> Self {
+        Self(raw::x25519(k, self.0))
+    }
+
+    /// Computes shared secret using X25519 key agreement
+    ///
+    /// For any point on curve25519, output is the same as the output of X25519 function with
+    /// secret key $k$ such that [`clamp_to_scalar(k)`](clamp_to_scalar) equals to `secret`.
+    /// In particular, for secret key generated by X25519 library, `self.diffie_hellman(&clamp_to_secret_scalar(&mut k))`
+    /// equals `x25519(k, self.to_bytes())`.
+    ///
+    /// Torsion component of the point is eliminated, so it doesn't leak any information about
+    /// the secret. Note that output may be an identity point ($u = 0$) if `self` is a point of small
+    /// order, RFC 7748 recommends to check for that case.
+    pub fn diffie_hellman(&self, secret: &SecretScalar<Ed25519>) -> Self {
+        let scalar: &Scalar<Ed25519> = secret.as_ref();
+        Self(raw::diffie_hellman(&self.0, scalar.as_raw()))
+    }
+
+    /// Checks whether it's identity point $u = 0$
+    pub fn is_zero(&self) -> bool {
+        self.ct_eq(&Self::default()).into()
+    }
+}
+
+impl ConstantTimeEq for MontgomeryPoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl AsRef<[u8]> for MontgomeryPoint {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Point<Ed25519>> for MontgomeryPoint {
+    fn from(point: Point<Ed25519>) -> Self {
+        point.to_montgomery()
+    }
+}
+
+impl From<&Point<Ed25519>> for MontgomeryPoint {
+    fn from(point: &Point<Ed25519>) -> Self {
+        point.to_montgomery()
+    }
+}
+
+impl Point<Ed25519> {
+    /// Converts the point into Montgomery form
+    ///
+    /// Identity point is mapped to $u = 0$. Use [`MontgomeryPoint::to_edwards`] to convert it back.
+    pub fn to_montgomery(&self) -> MontgomeryPoint {
+        MontgomeryPoint(raw::to_montgomery(self.as_raw()))
+    }
+
+    /// Returns sign of $x$ coordinate, which is needed to convert the point back from Montgomery form
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Ed25519};
+    ///
+    /// let point = Point::<Ed25519>::generator() * generic_ec::Scalar::from(42);
+    /// let u = point.to_montgomery();
+    /// assert_eq!(u.to_edwards(point.x_sign()), Some(point));
+    /// ```
+    pub fn x_sign(&self) -> Sign {
+        let (sign, _) = crate::coords::AlwaysHasAffineYAndSign::y_and_sign(self);
+        sign
+    }
+}
+
+impl FullGroupPoint<Ed25519> {
+    /// Converts the point into Montgomery form
+    ///
+    /// Unlike [`Point::to_montgomery`], the point may have a torsion component.
+    pub fn to_montgomery(&self) -> MontgomeryPoint {
+        MontgomeryPoint(raw::to_montgomery(self.as_raw()))
+    }
+}
+
+/// X25519 function defined in [RFC 7748](https://www.rfc-editor.org/rfc/rfc7748#section-5)
+///
+/// Clamps `k`, and multiplies point with $u$ coordinate at it. Returns $u$ coordinate of the result.
+pub fn x25519(k: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    raw::x25519(k, u)
+}
+
+/// Clamps integer as defined in [RFC 7748](https://www.rfc-editor.org/rfc/rfc7748#section-5)
+///
+/// Integer is encoded in little-endian.
+pub const fn clamp_integer(k: [u8; 32]) -> [u8; 32] {
+    raw::clamp_integer(k)
+}
+
+/// Clamps integer `k` and reduces it modulo group order
+///
+/// Integer is encoded in little-endian.
+pub fn clamp_to_scalar(k: [u8; 32]) -> Scalar<Ed25519> {
+    Scalar::from_le_bytes_mod_order(clamp_integer(k))
+}
+
+/// Clamps secret key `k` and reduces it modulo group order
+///
+/// Resulting secret scalar can be used for X25519 key agreement via [`MontgomeryPoint::diffie_hellman`].
+/// `k` is zeroized.
+pub fn clamp_to_secret_scalar(k: &mut [u8; 32]) -> SecretScalar<Ed25519> {
+    let mut clamped = clamp_integer(*k);
+    let mut scalar = Scalar::from_le_bytes_mod_order(clamped);
+    zeroize::Zeroize::zeroize(k);
+    zeroize::Zeroize::zeroize(&mut clamped);
+    SecretScalar::new(&mut scalar)
+}
@@ -0,0 +1,258 @@
+use generic_ec::any::{AnyCurve, AnyPoint, AnyScalar};
+
+#[test]
+fn curve_names() {
+    assert_eq!(AnyCurve::ALL.len(), AnyCurve::NAMES.len());
+    for (curve, name) in AnyCurve::ALL.iter().zip(AnyCurve::NAMES) {
+        assert_eq!(curve.name(), *name);
+        assert_eq!(curve.to_string(), *name);
+        assert_eq!(name.parse::<AnyCurve>().unwrap(), *curve);
+
+        let json = serde_json::to_value(curve).unwrap();
+        assert_eq!(json, serde_json::json!(name));
+        assert_eq!(serde_json::from_value::<AnyCurve>(json).unwrap(), *curve);
+    }
+
+    assert!("secp256k2".parse::<AnyCurve>().is_err());
+    let err = serde_json::from_str::<AnyCurve>(r#""secp256k2""#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown variant `secp256k2`"));
+}
+
+#[test]
+fn curve_mismatch() {
+    let mut rng = rand_dev::DevRng::new();
+
+    for &a in AnyCurve::ALL {
+        for &b in AnyCurve::ALL {
+            let p = AnyPoint::generator(a);
+            let q = AnyPoint::generator(b);
+            let s = AnyScalar::random(b, &mut rng);
+
+            if a == b {
+                assert!(p.try_add(&q).is_ok());
+                assert!(p.try_mul(&s).is_ok());
+                continue;
+            }
+
+            let err = p.try_add(&q).unwrap_err();
+            assert_eq!(err.expected(), a);
+            assert_eq!(err.got(), b);
+            assert_eq!(
+                err.to_string(),
+                format!("curve mismatch: expected {a}, got {b}")
+            );
+            assert!(p.try_sub(&q).is_err());
+            assert!(p.try_mul(&s).is_err());
+            assert!(AnyScalar::one(a).try_add(&s).is_err());
+            assert!(AnyScalar::one(a).try_mul(&s).is_err());
+        }
+    }
+}
+
+#[generic_tests::define]
+mod generic {
+    use generic_ec::{
+        any::{AnyCurve, AnyPoint, AnyScalar, AnySecretScalar},
+        traits::IsZero,
+        Curve, Point, Scalar, SecretScalar,
+    };
+    use serde_test::{Configure, Token};
+
+    fn curve<E: Curve>() -> AnyCurve {
+        AnyCurve::from_name(E::CURVE_NAME).unwrap()
+    }
+
+    #[test]
+    fn arithmetic<E: Curve>()
+    where
+        AnyPoint: From<Point<E>>,
+        AnyScalar: From<Scalar<E>>,
+        Point<E>: TryFrom<AnyPoint>,
+        Scalar<E>: TryFrom<AnyScalar>,
+    {
+        let mut rng = rand_dev::DevRng::new();
+        let curve = curve::<E>();
+
+        let a = Scalar::<E>::random(&mut rng);
+        let b = Scalar::<E>::random(&mut rng);
+        let p = Point::<E>::generator() * a;
+        let q = Point::<E>::generator() * b;
+
+        let any_a = AnyScalar::from(a);
+        let any_b = AnyScalar::from(b);
+        let any_p = AnyPoint::from(p);
+        let any_q = AnyPoint::from(q);
+        assert_eq!(any_a.curve(), curve);
+        assert_eq!(any_p.curve(), curve);
+
+        assert_eq!(
+            AnyPoint::generator(curve),
+            Point::<E>::generator().to_point().into()
+        );
+        assert!(AnyPoint::zero(curve).is_zero());
+        assert!(AnyScalar::zero(curve).is_zero());
+        assert_eq!(AnyScalar::one(curve), Scalar::<E>::one().into());
+
+        assert_eq!(any_p.try_add(&any_q).unwrap(), (p + q).into());
+        assert_eq!(any_p.try_sub(&any_q).unwrap(), (p - q).into());
+        assert_eq!(-any_p, (-p).into());
+        assert_eq!(any_p.try_mul(&any_b).unwrap(), (p * b).into());
+
+        assert_eq!(any_a.try_add(&any_b).unwrap(), (a + b).into());
+        assert_eq!(any_a.try_sub(&any_b).unwrap(), (a - b).into());
+        assert_eq!(any_a.try_mul(&any_b).unwrap(), (a * b).into());
+        assert_eq!(-any_a, (-a).into());
+        assert_eq!(any_a.invert(), a.invert().map(AnyScalar::from));
+        assert_eq!(AnyScalar::zero(curve).invert(), None);
+
+        // Bytes encoding
+        assert_eq!(any_p.to_bytes(true), p.to_bytes(true).to_vec());
+        assert_eq!(
+            AnyPoint::from_bytes(curve, p.to_bytes(false)).unwrap(),
+            any_p
+        );
+        assert_eq!(any_a.to_be_bytes(), a.to_be_bytes().to_vec());
+        assert_eq!(
+            AnyScalar::from_be_bytes(curve, a.to_be_bytes()).unwrap(),
+            any_a
+        );
+
+        // Conversion back to typed values
+        assert!(Point::<E>::try_from(any_p).ok() == Some(p));
+        assert!(Scalar::<E>::try_from(any_a).ok() == Some(a));
+    }
+
+    #[test]
+    fn secret_scalar<E: Curve>()
+    where
+        AnyPoint: From<Point<E>>,
+        AnySecretScalar: From<SecretScalar<E>>,
+        SecretScalar<E>: TryFrom<AnySecretScalar>,
+    {
+        let mut rng = rand_dev::DevRng::new();
+        let curve = curve::<E>();
+
+        let sk = AnySecretScalar::random(curve, &mut rng);
+        assert_eq!(sk.curve(), curve);
+        assert!(AnySecretScalar::from_be_bytes(curve, &[0xff; 200]).is_err());
+
+        let typed_sk = SecretScalar::<E>::try_from(sk.clone()).ok().unwrap();
+        let expected_pk = AnyPoint::from(Point::generator() * &typed_sk);
+        assert_eq!(sk.public_key(), expected_pk);
+        assert_eq!(
+            AnyPoint::generator(curve).try_mul_secret(&sk).unwrap(),
+            expected_pk
+        );
+
+        let sk_inv = sk.invert().unwrap();
+        assert_eq!(
+            sk.public_key().try_mul_secret(&sk_inv).unwrap(),
+            AnyPoint::generator(curve)
+        );
+    }
+
+    #[test]
+    fn serialized_as_typed_values<E: Curve>()
+    where
+        AnyPoint: From<Point<E>>,
+        AnyScalar: From<Scalar<E>>,
+        AnySecretScalar: From<SecretScalar<E>>,
+    {
+        let mut rng = rand_dev::DevRng::new();
+
+        let point = Point::<E>::generator() * Scalar::random(&mut rng);
+        let scalar = Scalar::<E>::random(&mut rng);
+        let secret = SecretScalar::<E>::random(&mut rng);
+
+        let json = serde_json::to_value(point).unwrap();
+        assert_eq!(serde_json::to_value(AnyPoint::from(point)).unwrap(), json);
+        assert_eq!(
+            serde_json::from_value::<AnyPoint>(json).unwrap(),
+            point.into()
+        );
+
+        let json = serde_json::to_value(scalar).unwrap();
+        assert_eq!(serde_json::to_value(AnyScalar::from(scalar)).unwrap(), json);
+        assert_eq!(
+            serde_json::from_value::<AnyScalar>(json).unwrap(),
+            scalar.into()
+        );
+
+        let json = serde_json::to_value(&secret).unwrap();
+        let any_secret = AnySecretScalar::from(secret.clone());
+        assert_eq!(serde_json::to_value(&any_secret).unwrap(), json);
+        let decoded: AnySecretScalar = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            serde_json::to_value(decoded).unwrap(),
+            serde_json::to_value(&secret).unwrap()
+        );
+
+        // Non human-readable format
+        let point_bytes = point.to_bytes(false).to_vec().leak();
+        serde_test::assert_tokens(
+            &AnyPoint::from(point).compact(),
+            &[
+                Token::Struct {
+                    name: "PointUncompressed",
+                    len: 2,
+                },
+                Token::Str("curve"),
+                Token::Str(E::CURVE_NAME),
+                Token::Str("point"),
+                Token::Bytes(point_bytes),
+                Token::StructEnd,
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &AnyPoint::from(point).compact(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str(E::CURVE_NAME),
+                Token::Bytes(point_bytes),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn deserialization_errors<E: Curve>() {
+        let point = hex::encode(Point::<E>::generator().to_point().to_bytes(false));
+
+        // Curve must go first
+        let err = serde_json::from_str::<AnyPoint>(&format!(
+            r#"{{"point":"{point}","curve":"{}"}}"#,
+            E::CURVE_NAME
+        ))
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("`curve` field must precede `point` field"));
+
+        // Unknown curve
+        let err = serde_json::from_str::<AnyPoint>(&format!(
+            r#"{{"curve":"secp256k2","point":"{point}"}}"#
+        ))
+        .unwrap_err();
+        assert!(err.to_string().starts_with("unknown variant `secp256k2`"));
+
+        // Missing fields
+        let err =
+            serde_json::from_str::<AnyPoint>(&format!(r#"{{"point":"{point}"}}"#)).unwrap_err();
+        assert!(err.to_string().starts_with("`curve` field must precede"));
+        let err = serde_json::from_str::<AnyScalar>(&format!(r#"{{"curve":"{}"}}"#, E::CURVE_NAME))
+            .unwrap_err();
+        assert!(err.to_string().starts_with("missing field `scalar`"));
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
@@ -0,0 +1,353 @@
+fn digest(value: impl udigest::Digestable) -> Vec<u8> {
+    udigest::udigest::<sha2::Sha256>(udigest::Tag::new(b"generic-ec-tests"), value).to_vec()
+}
+
+#[generic_tests::define]
+mod generic {
+    use generic_ec::{
+        serde::Compact, Curve, EncodedPoint, EncodedScalar, Generator, NonZero, Point, Scalar,
+        SecretScalar,
+    };
+    use generic_ec_zkp::polynomial::{BoundedPolynomial, Polynomial};
+    use serde_json::json;
+    use serde_test::{Configure, Token};
+    use serde_with::serde_as;
+
+    use super::digest;
+
+    #[serde_as]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound = "")]
+    struct CompactMsg<E: Curve> {
+        #[serde_as(as = "Compact")]
+        encoded_point: EncodedPoint<E>,
+        #[serde_as(as = "Compact")]
+        encoded_scalar: EncodedScalar<E>,
+        #[serde_as(as = "Compact")]
+        generator: Generator<E>,
+        #[serde_as(as = "Compact")]
+        secret: NonZero<SecretScalar<E>>,
+    }
+
+    #[test]
+    fn encoded_point<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let point = Point::<E>::generator() * Scalar::random(&mut rng);
+        for compressed in [true, false] {
+            let encoded = point.to_bytes(compressed);
+            let hex = hex::encode(&encoded).leak();
+
+            serde_test::assert_tokens(
+                &encoded.clone().readable(),
+                &[
+                    Token::Struct {
+                        name: "EncodedPoint",
+                        len: 2,
+                    },
+                    Token::Str("curve"),
+                    Token::Str(E::CURVE_NAME),
+                    Token::Str("point"),
+                    Token::Str(hex),
+                    Token::StructEnd,
+                ],
+            );
+            serde_test::assert_tokens(
+                &encoded.clone().compact(),
+                &[
+                    Token::Struct {
+                        name: "EncodedPoint",
+                        len: 2,
+                    },
+                    Token::Str("curve"),
+                    Token::Str(E::CURVE_NAME),
+                    Token::Str("point"),
+                    Token::Bytes(encoded.to_vec().leak()),
+                    Token::StructEnd,
+                ],
+            );
+
+            // Form of the point is preserved
+            let json = serde_json::to_value(&encoded).unwrap();
+            let decoded: EncodedPoint<E> = serde_json::from_value(json).unwrap();
+            assert_eq!(decoded, encoded);
+        }
+
+        // Zero point can be encoded too
+        let zero = Point::<E>::zero().to_bytes(true);
+        let json = serde_json::to_value(&zero).unwrap();
+        assert_eq!(
+            serde_json::from_value::<EncodedPoint<E>>(json).unwrap(),
+            zero
+        );
+
+        // Invalid length
+        let mut bytes = point.to_bytes(true).to_vec();
+        bytes.push(0);
+        assert!(serde_json::from_value::<EncodedPoint<E>>(json!({
+            "curve": E::CURVE_NAME,
+            "point": hex::encode(&bytes),
+        }))
+        .is_err());
+        bytes.truncate(bytes.len() - 2);
+        assert!(serde_json::from_value::<EncodedPoint<E>>(json!({
+            "curve": E::CURVE_NAME,
+            "point": hex::encode(&bytes),
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn encoded_scalar<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let scalar = Scalar::<E>::random(&mut rng);
+        let encoded = scalar.to_be_bytes();
+
+        // Encoded scalar is serialized in the same way as scalar
+        assert_eq!(
+            serde_json::to_value(&encoded).unwrap(),
+            serde_json::to_value(scalar).unwrap()
+        );
+        let decoded: EncodedScalar<E> =
+            serde_json::from_value(serde_json::to_value(scalar).unwrap()).unwrap();
+        assert_eq!(decoded, encoded);
+
+        // Little-endian encoding is serialized as is
+        let encoded = scalar.to_le_bytes();
+        let json = serde_json::to_value(&encoded).unwrap();
+        assert_eq!(
+            json,
+            json!({ "curve": E::CURVE_NAME, "scalar": hex::encode(&encoded) })
+        );
+        assert_eq!(
+            serde_json::from_value::<EncodedScalar<E>>(json).unwrap(),
+            encoded
+        );
+    }
+
+    #[test]
+    fn generator<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let g = Point::<E>::generator();
+
+        // Generator is serialized in the same way as point
+        let json = serde_json::to_value(g).unwrap();
+        assert_eq!(json, serde_json::to_value(g.to_point()).unwrap());
+        assert!(serde_json::from_value::<Generator<E>>(json).unwrap() == g);
+
+        // Any other point is rejected
+        let point = Point::<E>::generator() * Scalar::random(&mut rng);
+        let Err(err) = serde_json::from_value::<Generator<E>>(serde_json::to_value(point).unwrap())
+        else {
+            panic!("point is not a generator")
+        };
+        assert_eq!(err.to_string(), "point is not a curve generator");
+    }
+
+    #[test]
+    fn compact<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let point = Point::<E>::generator() * Scalar::random(&mut rng);
+        let scalar = Scalar::<E>::random(&mut rng);
+        let secret = NonZero::<SecretScalar<E>>::random(&mut rng);
+        let msg = CompactMsg {
+            encoded_point: point.to_bytes(false),
+            encoded_scalar: scalar.to_be_bytes(),
+            generator: Point::generator(),
+            secret: secret.clone(),
+        };
+
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "encoded_point": hex::encode(point.to_bytes(false)),
+                "encoded_scalar": hex::encode(scalar.to_be_bytes()),
+                "generator": hex::encode(Point::<E>::generator().to_point().to_bytes(true)),
+                "secret": hex::encode(AsRef::<Scalar<E>>::as_ref(&secret).to_be_bytes()),
+            })
+        );
+
+        let decoded: CompactMsg<E> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.encoded_point, msg.encoded_point);
+        assert_eq!(decoded.encoded_scalar, msg.encoded_scalar);
+        assert_eq!(
+            AsRef::<Scalar<E>>::as_ref(&decoded.secret),
+            AsRef::<Scalar<E>>::as_ref(&secret)
+        );
+
+        // Zero secret scalar is rejected
+        let mut json = serde_json::to_value(&msg).unwrap();
+        json["secret"] = hex::encode(Scalar::<E>::zero().to_be_bytes()).into();
+        assert!(serde_json::from_value::<CompactMsg<E>>(json).is_err());
+    }
+
+    #[test]
+    fn udigest<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        // Generator, encoded point, and encoded scalar
+        let g = Point::<E>::generator();
+        assert_eq!(digest(g), digest(g.to_point()));
+        assert_eq!(digest(g.to_point().to_bytes(true)), digest(g.to_point()));
+        if Point::<E>::COMPRESSED_LEN != Point::<E>::UNCOMPRESSED_LEN {
+            assert_ne!(
+                digest(g.to_point().to_bytes(true)),
+                digest(g.to_point().to_bytes(false))
+            );
+        }
+        let scalar = Scalar::<E>::random(&mut rng);
+        assert_eq!(digest(scalar.to_be_bytes()), digest(scalar));
+
+        // Secret scalars are digested in the same way as public ones
+        let secret = NonZero::<SecretScalar<E>>::random(&mut rng);
+        let public = *AsRef::<Scalar<E>>::as_ref(&secret);
+        assert_eq!(
+            digest(AsRef::<SecretScalar<E>>::as_ref(&secret)),
+            digest(public)
+        );
+        assert_eq!(
+            digest(&secret),
+            digest(NonZero::from_scalar(public).unwrap())
+        );
+
+        // Polynomials
+        let f = Polynomial::<SecretScalar<E>>::sample(&mut rng, 3);
+        let f_public = Polynomial::from_coefs(
+            f.coefs()
+                .iter()
+                .map(|c| *c.as_ref())
+                .collect::<Vec<Scalar<E>>>(),
+        );
+        assert_eq!(digest(&f), digest(&f_public));
+
+        let f_bounded = BoundedPolynomial::<_, 3>::new(f_public.clone()).unwrap();
+        assert_eq!(digest(&f_bounded), digest(&f_public));
+
+        let f_nonzero = Polynomial::<NonZero<SecretScalar<E>>>::sample(&mut rng, 3);
+        assert_ne!(digest(&f_nonzero), digest(&f_public));
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
+
+#[generic_tests::define]
+mod coords {
+    use generic_ec::{coords::HasAffineXY, serde::Compact, Curve, Point, Scalar};
+    use serde_test::{Configure, Token};
+    use serde_with::serde_as;
+
+    use super::digest;
+
+    #[serde_as]
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    #[serde(bound = "")]
+    struct CompactCoords<E: Curve> {
+        #[serde_as(as = "Compact")]
+        x: generic_ec::coords::Coordinate<E>,
+        #[serde_as(as = "Compact")]
+        xy: generic_ec::coords::Coordinates<E>,
+    }
+
+    #[test]
+    fn coordinates<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let mut rng = rand_dev::DevRng::new();
+
+        let point = Point::<E>::generator() * Scalar::random(&mut rng);
+        let coords = point.coords().unwrap();
+        let x = hex::encode(coords.x.as_be_bytes()).leak();
+        let y = hex::encode(coords.y.as_be_bytes()).leak();
+
+        serde_test::assert_tokens(
+            &coords.x.clone().readable(),
+            &[
+                Token::Struct {
+                    name: "CoordinateUncompressed",
+                    len: 2,
+                },
+                Token::Str("curve"),
+                Token::Str(E::CURVE_NAME),
+                Token::Str("coordinate"),
+                Token::Str(x),
+                Token::StructEnd,
+            ],
+        );
+        serde_test::assert_tokens(
+            &coords.clone().readable(),
+            &[
+                Token::Struct {
+                    name: "CoordinatesUncompressed",
+                    len: 3,
+                },
+                Token::Str("curve"),
+                Token::Str(E::CURVE_NAME),
+                Token::Str("x"),
+                Token::Str(x),
+                Token::Str("y"),
+                Token::Str(y),
+                Token::StructEnd,
+            ],
+        );
+
+        // Compact
+        serde_test::assert_tokens(
+            &CompactCoords {
+                x: coords.x.clone(),
+                xy: coords.clone(),
+            }
+            .readable(),
+            &[
+                Token::Struct {
+                    name: "CompactCoords",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::NewtypeStruct {
+                    name: "CoordinateCompact",
+                },
+                Token::Str(x),
+                Token::Str("xy"),
+                Token::Struct {
+                    name: "PointAffine",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::Str(x),
+                Token::Str("y"),
+                Token::Str(y),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        // udigest
+        assert_ne!(digest(&coords.x), digest(&coords.y));
+        assert_ne!(digest(&coords), digest(&coords.x));
+        assert_ne!(digest(&coords), digest(point));
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
@@ -0,0 +1,176 @@
+#[generic_tests::define]
+mod generic {
+    use generic_ec::{
+        serde::{BoundedVec, Compact},
+        Curve, Point, Scalar,
+    };
+    use generic_ec_zkp::polynomial::{BoundedPolynomial, Polynomial};
+    use serde_test::{Configure, Token};
+    use serde_with::serde_as;
+
+    #[test]
+    fn polynomial_with_max_degree<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        for degree in [0, 1, 5] {
+            let f = Polynomial::<Scalar<E>>::sample(&mut rng, degree);
+            let serialized = serde_json::to_value(&f).unwrap();
+
+            for max_degree in [degree, degree + 1, 100] {
+                let g =
+                    Polynomial::<Scalar<E>>::deserialize_with_max_degree(&serialized, max_degree)
+                        .unwrap();
+                assert_eq!(f.coefs(), g.coefs());
+
+                let g: BoundedPolynomial<Scalar<E>, 5> =
+                    serde_json::from_value(serialized.clone()).unwrap();
+                assert_eq!(f.coefs(), g.as_polynomial().coefs());
+            }
+
+            if degree > 0 {
+                let err =
+                    Polynomial::<Scalar<E>>::deserialize_with_max_degree(&serialized, degree - 1)
+                        .unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    format!(
+                        "invalid length {}, expected a sequence of at most {} elements",
+                        degree + 1,
+                        degree,
+                    )
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bounded_polynomial<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let f = &Polynomial::<Scalar<E>>::sample(&mut rng, 3) * &Point::generator();
+        assert!(BoundedPolynomial::<_, 2>::new(f.clone()).is_none());
+        let f: BoundedPolynomial<Point<E>, 3> = BoundedPolynomial::new(f).unwrap();
+
+        // Serialized in the same way as `Polynomial`
+        let serialized = serde_json::to_value(&f).unwrap();
+        assert_eq!(serialized, serde_json::to_value(f.as_polynomial()).unwrap());
+
+        let g: BoundedPolynomial<Point<E>, 3> = serde_json::from_value(serialized.clone()).unwrap();
+        assert_eq!(f.as_polynomial().coefs(), g.as_polynomial().coefs());
+
+        let err = serde_json::from_value::<BoundedPolynomial<Point<E>, 2>>(serialized).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid length 4, expected a sequence of at most 3 elements"
+        );
+    }
+
+    #[test]
+    fn bounded_polynomial_doesnt_read_extra_coefs<E: Curve>() {
+        // Deserializer stops at the first coefficient after the bound without trying to parse it
+        serde_test::assert_de_tokens_error::<serde_test::Readable<BoundedPolynomial<Scalar<E>, 0>>>(
+            &[
+                Token::Seq { len: None },
+                Token::Struct {
+                    name: "ScalarUncompressed",
+                    len: 2,
+                },
+                Token::Str("curve"),
+                Token::Str(E::CURVE_NAME),
+                Token::Str("scalar"),
+                Token::Str(hex::encode(Scalar::<E>::one().to_be_bytes()).leak()),
+                Token::StructEnd,
+                Token::Bool(true),
+            ],
+            "invalid length 2, expected a sequence of at most 1 elements",
+        );
+    }
+
+    #[serde_as]
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug)]
+    #[serde(bound = "")]
+    struct Shares<E: Curve> {
+        #[serde_as(as = "BoundedVec<3>")]
+        scalars: Vec<Scalar<E>>,
+        #[serde_as(as = "BoundedVec<3, Compact>")]
+        points: Vec<Point<E>>,
+    }
+
+    #[test]
+    fn bounded_vec<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        for len in 0..=3 {
+            let shares = Shares::<E> {
+                scalars: (0..len).map(|_| Scalar::random(&mut rng)).collect(),
+                points: (0..len)
+                    .map(|_| Point::generator() * Scalar::random(&mut rng))
+                    .collect(),
+            };
+
+            let mut tokens = vec![
+                Token::Struct {
+                    name: "Shares",
+                    len: 2,
+                },
+                Token::Str("scalars"),
+                Token::Seq { len: Some(len) },
+            ];
+            for scalar in &shares.scalars {
+                tokens.extend([
+                    Token::Struct {
+                        name: "ScalarUncompressed",
+                        len: 2,
+                    },
+                    Token::Str("curve"),
+                    Token::Str(E::CURVE_NAME),
+                    Token::Str("scalar"),
+                    Token::Str(hex::encode(scalar.to_be_bytes()).leak()),
+                    Token::StructEnd,
+                ]);
+            }
+            tokens.extend([
+                Token::SeqEnd,
+                Token::Str("points"),
+                Token::Seq { len: Some(len) },
+            ]);
+            for point in &shares.points {
+                tokens.extend([
+                    Token::NewtypeStruct {
+                        name: "PointCompact",
+                    },
+                    Token::Str(hex::encode(point.to_bytes(true)).leak()),
+                ]);
+            }
+            tokens.extend([Token::SeqEnd, Token::StructEnd]);
+
+            serde_test::assert_tokens(&shares.readable(), &tokens);
+        }
+    }
+
+    #[test]
+    fn bounded_vec_too_long<E: Curve>() {
+        let shares = Shares::<E> {
+            scalars: vec![Scalar::one(); 4],
+            points: vec![],
+        };
+        let serialized = serde_json::to_string(&shares).unwrap();
+
+        let err = serde_json::from_str::<Shares<E>>(&serialized).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid length 4, expected a sequence of at most 3 elements"));
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
@@ -0,0 +1,165 @@
+#[generic_tests::define]
+mod generic {
+    use generic_ec::{curves::*, Curve, NonZero, Point, Scalar, SecretScalar};
+    use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+    use rand_dev::DevRng;
+
+    #[test]
+    fn borsh_roundtrip<E: Curve>() {
+        let mut rng = DevRng::new();
+        let scalar = NonZero::<Scalar<E>>::random(&mut rng);
+        let point = Point::generator() * scalar;
+        let secret = SecretScalar::<E>::random(&mut rng);
+
+        let bytes = borsh::to_vec(&point).unwrap();
+        assert_eq!(bytes, point.to_bytes_compressed().as_ref());
+        assert_eq!(borsh::from_slice::<Point<E>>(&bytes).unwrap(), *point);
+        assert_eq!(
+            borsh::from_slice::<NonZero<Point<E>>>(&bytes).unwrap(),
+            point
+        );
+
+        let bytes = borsh::to_vec(&scalar).unwrap();
+        assert_eq!(bytes, scalar.to_be_bytes().as_bytes());
+        assert_eq!(borsh::from_slice::<Scalar<E>>(&bytes).unwrap(), *scalar);
+        assert_eq!(
+            borsh::from_slice::<NonZero<Scalar<E>>>(&bytes).unwrap(),
+            scalar
+        );
+
+        let bytes = borsh::to_vec(&secret).unwrap();
+        assert_eq!(bytes, secret.as_ref().to_be_bytes().as_bytes());
+        let decoded = borsh::from_slice::<SecretScalar<E>>(&bytes).unwrap();
+        assert_eq!(decoded.as_ref(), secret.as_ref());
+
+        // Zero is accepted unless wrapped into `NonZero`
+        let zero = borsh::to_vec(&Point::<E>::zero()).unwrap();
+        assert_eq!(borsh::from_slice::<Point<E>>(&zero).unwrap(), Point::zero());
+        assert!(borsh::from_slice::<NonZero<Point<E>>>(&zero).is_err());
+        let zero = borsh::to_vec(&Scalar::<E>::zero()).unwrap();
+        assert!(borsh::from_slice::<NonZero<Scalar<E>>>(&zero).is_err());
+    }
+
+    #[test]
+    fn scale_roundtrip<E: Curve>() {
+        let mut rng = DevRng::new();
+        let scalar = NonZero::<Scalar<E>>::random(&mut rng);
+        let point = Point::generator() * scalar;
+        let secret = SecretScalar::<E>::random(&mut rng);
+
+        let bytes = point.encode();
+        assert_eq!(bytes, point.to_bytes_compressed().as_ref());
+        assert_eq!(bytes.len(), Point::<E>::max_encoded_len());
+        assert_eq!(Point::<E>::decode(&mut &bytes[..]).unwrap(), *point);
+        assert_eq!(NonZero::<Point<E>>::decode(&mut &bytes[..]).unwrap(), point);
+
+        let bytes = scalar.encode();
+        assert_eq!(bytes, scalar.to_be_bytes().as_bytes());
+        assert_eq!(bytes.len(), Scalar::<E>::max_encoded_len());
+        assert_eq!(Scalar::<E>::decode(&mut &bytes[..]).unwrap(), *scalar);
+        assert_eq!(
+            NonZero::<Scalar<E>>::decode(&mut &bytes[..]).unwrap(),
+            scalar
+        );
+
+        let bytes = secret.encode();
+        let decoded = SecretScalar::<E>::decode(&mut &bytes[..]).unwrap();
+        assert_eq!(decoded.as_ref(), secret.as_ref());
+
+        let zero = Point::<E>::zero().encode();
+        assert_eq!(Point::<E>::decode(&mut &zero[..]).unwrap(), Point::zero());
+        assert!(NonZero::<Point<E>>::decode(&mut &zero[..]).is_err());
+        let zero = Scalar::<E>::zero().encode();
+        assert!(NonZero::<Scalar<E>>::decode(&mut &zero[..]).is_err());
+    }
+
+    #[test]
+    fn rkyv_roundtrip<E: Curve>() {
+        use rkyv::rancor::Error;
+
+        let mut rng = DevRng::new();
+        let scalar = NonZero::<Scalar<E>>::random(&mut rng);
+        let point = Point::generator() * scalar;
+        let secret = SecretScalar::<E>::random(&mut rng);
+
+        let bytes = rkyv::to_bytes::<Error>(&point).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Point<E>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.as_slice(), point.to_bytes_compressed().as_ref());
+        assert_eq!(rkyv::from_bytes::<Point<E>, Error>(&bytes).unwrap(), *point);
+        assert_eq!(
+            rkyv::from_bytes::<NonZero<Point<E>>, Error>(&bytes).unwrap(),
+            point
+        );
+
+        let bytes = rkyv::to_bytes::<Error>(&scalar).unwrap();
+        assert_eq!(
+            rkyv::from_bytes::<Scalar<E>, Error>(&bytes).unwrap(),
+            *scalar
+        );
+        assert_eq!(
+            rkyv::from_bytes::<NonZero<Scalar<E>>, Error>(&bytes).unwrap(),
+            scalar
+        );
+
+        let bytes = rkyv::to_bytes::<Error>(&secret).unwrap();
+        let decoded = rkyv::from_bytes::<SecretScalar<E>, Error>(&bytes).unwrap();
+        assert_eq!(decoded.as_ref(), secret.as_ref());
+
+        let zero = rkyv::to_bytes::<Error>(&Point::<E>::zero()).unwrap();
+        assert_eq!(
+            rkyv::from_bytes::<Point<E>, Error>(&zero).unwrap(),
+            Point::zero()
+        );
+        assert!(rkyv::from_bytes::<NonZero<Point<E>>, Error>(&zero).is_err());
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+}
+
+mod invalid {
+    use generic_ec::curves::{Ed25519, Secp256k1};
+    use generic_ec::{Point, Scalar};
+    use parity_scale_codec::Decode;
+
+    /// x-coordinate of a point that is not on secp256k1 curve
+    const NOT_ON_CURVE: &str = "020000000000000000000000000000000000000000000000000000000000000005";
+    /// Ed25519 point of order 8
+    const TORSION: &str = "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a";
+    /// secp256k1 group order
+    const ORDER: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+    #[test]
+    fn invalid_point() {
+        let bytes = hex::decode(NOT_ON_CURVE).unwrap();
+        assert!(borsh::from_slice::<Point<Secp256k1>>(&bytes).is_err());
+        assert!(Point::<Secp256k1>::decode(&mut &bytes[..]).is_err());
+
+        let bytes = hex::decode(TORSION).unwrap();
+        assert!(borsh::from_slice::<Point<Ed25519>>(&bytes).is_err());
+        assert!(Point::<Ed25519>::decode(&mut &bytes[..]).is_err());
+    }
+
+    #[test]
+    fn invalid_scalar() {
+        let bytes = hex::decode(ORDER).unwrap();
+        assert!(borsh::from_slice::<Scalar<Secp256k1>>(&bytes).is_err());
+        assert!(Scalar::<Secp256k1>::decode(&mut &bytes[..]).is_err());
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = Point::<Secp256k1>::generator().to_point().to_bytes(true);
+        assert!(borsh::from_slice::<Point<Secp256k1>>(&bytes[..32]).is_err());
+        assert!(Point::<Secp256k1>::decode(&mut &bytes[..32]).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+#[generic_tests::define]
+mod generic {
+    use generic_ec::coords::HasAffineXY;
+    use generic_ec::errors::{InvalidPoint, KeyFormatError};
+    use generic_ec::{curves::*, Curve, Point, SecretScalar};
+    use rand_dev::DevRng;
+
+    #[test]
+    fn public_key_roundtrip<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let mut rng = DevRng::new();
+        let point = Point::<E>::generator() * SecretScalar::random(&mut rng);
+
+        let key = point.to_cose_key().unwrap();
+        assert_eq!(Point::<E>::from_cose_key(&key).unwrap(), point);
+
+        assert_eq!(
+            Point::<E>::zero().to_cose_key().err(),
+            Some(KeyFormatError::InvalidPoint(InvalidPoint::Identity))
+        );
+        assert_eq!(
+            SecretScalar::<E>::from_cose_key(&key).err(),
+            Some(KeyFormatError::MissingSecretKey)
+        );
+    }
+
+    #[test]
+    fn secret_key_roundtrip<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let mut rng = DevRng::new();
+        let sk = SecretScalar::<E>::random(&mut rng);
+
+        let key = sk.to_cose_key().unwrap();
+        let decoded = SecretScalar::<E>::from_cose_key(&key).unwrap();
+        assert_eq!(sk.as_ref(), decoded.as_ref());
+        assert_eq!(
+            Point::<E>::from_cose_key(&key).unwrap(),
+            Point::generator() * &sk
+        );
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+}
+
+mod vectors {
+    use generic_ec::coords::HasAffineXY;
+    use generic_ec::curves::{Ed25519, Secp256k1, Secp256r1};
+    use generic_ec::errors::KeyFormatError;
+    use generic_ec::{Point, SecretScalar};
+
+    const P256_X: &str = "65eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d";
+    const P256_Y: &str = "1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c";
+    const P256_D: &str = "aff907c99f9ad3aae6c4cdf21122bce2bd68b5283e6907154ad911840fa208cf";
+
+    /// Key from RFC 9052, Appendix C.7.2, with `kid` label
+    fn p256_key(with_d: bool) -> Vec<u8> {
+        let kid = b"meriadoc.brandybuck@buckland.example";
+        let mut key = vec![if with_d { 0xa6 } else { 0xa5 }, 0x01, 0x02, 0x02, 0x58];
+        key.push(kid.len() as u8);
+        key.extend_from_slice(kid);
+        key.extend_from_slice(&[0x20, 0x01, 0x21, 0x58, 0x20]);
+        key.extend_from_slice(&hex::decode(P256_X).unwrap());
+        key.extend_from_slice(&[0x22, 0x58, 0x20]);
+        key.extend_from_slice(&hex::decode(P256_Y).unwrap());
+        if with_d {
+            key.extend_from_slice(&[0x23, 0x58, 0x20]);
+            key.extend_from_slice(&hex::decode(P256_D).unwrap());
+        }
+        key
+    }
+
+    #[test]
+    fn rfc9052_p256() {
+        let pk = Point::<Secp256r1>::from_cose_key(&p256_key(false)).unwrap();
+        let coords = pk.coords().unwrap();
+        assert_eq!(hex::encode(coords.x.as_be_bytes()), P256_X);
+        assert_eq!(hex::encode(coords.y.as_be_bytes()), P256_Y);
+
+        let sk = SecretScalar::<Secp256r1>::from_cose_key(&p256_key(true)).unwrap();
+        assert_eq!(hex::encode(sk.as_ref().to_be_bytes()), P256_D);
+
+        // Deterministic encoding omits `kid`
+        let mut expected = vec![0xa4, 0x01, 0x02, 0x20, 0x01, 0x21, 0x58, 0x20];
+        expected.extend_from_slice(&hex::decode(P256_X).unwrap());
+        expected.extend_from_slice(&[0x22, 0x58, 0x20]);
+        expected.extend_from_slice(&hex::decode(P256_Y).unwrap());
+        assert_eq!(pk.to_cose_key().unwrap(), expected);
+    }
+
+    /// Key from RFC 9052, Appendix C.7.2
+    #[test]
+    fn rfc9052_ed25519() {
+        let x = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+        let d = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let mut key = vec![0xa4, 0x01, 0x01, 0x20, 0x06, 0x21, 0x58, 0x20];
+        key.extend_from_slice(&hex::decode(x).unwrap());
+        key.extend_from_slice(&[0x23, 0x58, 0x20]);
+        key.extend_from_slice(&hex::decode(d).unwrap());
+
+        let pk = Point::<Ed25519>::from_cose_key(&key).unwrap();
+        assert_eq!(hex::encode(pk.to_bytes(true)), x);
+        let sk = SecretScalar::<Ed25519>::from_cose_key(&key).unwrap();
+        assert_eq!(Point::generator() * &sk, pk);
+        assert_eq!(sk.to_cose_key().err(), Some(KeyFormatError::Unsupported));
+
+        let public_key = pk.to_cose_key().unwrap();
+        assert_eq!(public_key[0], 0xa3);
+        assert_eq!(public_key[1..], key[1..key.len() - 35]);
+    }
+
+    #[test]
+    fn curve_mismatch() {
+        assert_eq!(
+            Point::<Secp256k1>::from_cose_key(&p256_key(false)).err(),
+            Some(KeyFormatError::CurveMismatch)
+        );
+        assert_eq!(
+            Point::<Ed25519>::from_cose_key(&p256_key(false)).err(),
+            Some(KeyFormatError::CurveMismatch)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let key = p256_key(true);
+        for len in 0..key.len() {
+            assert_eq!(
+                SecretScalar::<Secp256r1>::from_cose_key(&key[..len]).err(),
+                Some(KeyFormatError::Malformed),
+                "len = {len}"
+            );
+        }
+
+        let mut trailing = key.clone();
+        trailing.push(0);
+        assert_eq!(
+            SecretScalar::<Secp256r1>::from_cose_key(&trailing).err(),
+            Some(KeyFormatError::Malformed)
+        );
+
+        // Compressed point: `y` is a boolean
+        let mut compressed = vec![0xa4, 0x01, 0x02, 0x20, 0x01, 0x21, 0x58, 0x20];
+        compressed.extend_from_slice(&hex::decode(P256_X).unwrap());
+        compressed.extend_from_slice(&[0x22, 0xf5]);
+        assert_eq!(
+            Point::<Secp256r1>::from_cose_key(&compressed).err(),
+            Some(KeyFormatError::Unsupported)
+        );
+    }
+}
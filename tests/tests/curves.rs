@@ -2,7 +2,8 @@
 
 #[generic_tests::define]
 mod tests {
-    use generic_ec::{curves::*, Curve, EncodedScalar, Point, Scalar};
+    use generic_ec::errors::{InvalidPoint, InvalidScalar};
+    use generic_ec::{curves::*, Curve, EncodedScalar, NonZero, Point, Scalar};
     use rand::Rng;
     use rand_dev::DevRng;
 
@@ -185,6 +186,167 @@ mod tests {
         assert_eq!(Scalar::<E>::from_be_bytes(one_be).unwrap(), one);
     }
 
+    #[test]
+    fn point_ct_from_bytes<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let random_point = Point::generator() * Scalar::<E>::random(&mut rng);
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            for compressed in [true, false] {
+                let bytes = point.to_bytes(compressed);
+                let decoded = Option::<Point<E>>::from(Point::ct_from_bytes(&bytes));
+                assert_eq!(decoded, Some(point));
+            }
+        }
+
+        let invalid = vec![0xFF; Point::<E>::generator().to_point().to_bytes(true).len()];
+        assert!(bool::from(Point::<E>::ct_from_bytes(&invalid).is_none()));
+        assert!(Point::<E>::from_bytes(&invalid).is_err());
+        assert_eq!(
+            Option::<Point<E>>::from(Point::ct_from_bytes([])),
+            Point::<E>::from_bytes([]).ok()
+        );
+    }
+
+    #[test]
+    fn scalar_ct_from_bytes<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let random_scalar = Scalar::<E>::random(&mut rng);
+        let minus_one = -Scalar::<E>::one();
+        for s in [Scalar::zero(), Scalar::one(), minus_one, random_scalar] {
+            let s_be = Scalar::ct_from_be_bytes(s.to_be_bytes());
+            let s_le = Scalar::ct_from_le_bytes(s.to_le_bytes());
+            assert_eq!(Option::<Scalar<E>>::from(s_be), Some(s));
+            assert_eq!(Option::<Scalar<E>>::from(s_le), Some(s));
+        }
+
+        // Group order `q = (q - 1) + 1` is not a valid scalar
+        let mut q_be = minus_one.to_be_bytes();
+        *q_be.as_mut().last_mut().unwrap() += 1;
+        assert!(bool::from(Scalar::<E>::ct_from_be_bytes(&q_be).is_none()));
+        assert!(Scalar::<E>::from_be_bytes(&q_be).is_err());
+        let mut q_le = minus_one.to_le_bytes();
+        q_le.as_mut()[0] += 1;
+        assert!(bool::from(Scalar::<E>::ct_from_le_bytes(&q_le).is_none()));
+        assert!(Scalar::<E>::from_le_bytes(&q_le).is_err());
+
+        let too_long = vec![0u8; minus_one.to_be_bytes().len() + 1];
+        assert!(bool::from(
+            Scalar::<E>::ct_from_be_bytes(&too_long).is_none()
+        ));
+        assert!(bool::from(
+            Scalar::<E>::ct_from_le_bytes(&too_long).is_none()
+        ));
+    }
+
+    #[test]
+    fn decoding_errors<E: Curve>() {
+        let compressed_len = Point::<E>::generator().to_point().to_bytes(true).len();
+
+        // It's not a valid point on any curve, but on Ed25519 it encodes a point with torsion
+        let invalid = vec![0xFF; compressed_len];
+        assert!(matches!(
+            Point::<E>::from_bytes(&invalid),
+            Err(InvalidPoint::NotOnCurve | InvalidPoint::HasTorsion)
+        ));
+        assert_eq!(
+            Point::<E>::from_bytes(vec![0x42; compressed_len + 1]),
+            Err(InvalidPoint::InvalidLength)
+        );
+
+        let minus_one = -Scalar::<E>::one();
+        let mut q_be = minus_one.to_be_bytes();
+        *q_be.as_mut().last_mut().unwrap() += 1;
+        assert_eq!(
+            Scalar::<E>::from_be_bytes(&q_be),
+            Err(InvalidScalar::OutOfRange)
+        );
+        let too_long = vec![0u8; q_be.len() + 1];
+        assert_eq!(
+            Scalar::<E>::from_be_bytes(&too_long),
+            Err(InvalidScalar::InvalidLength)
+        );
+        assert_eq!(
+            Scalar::<E>::from_le_bytes(&too_long),
+            Err(InvalidScalar::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn strict_decoding<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let random_point = Point::generator() * Scalar::<E>::random(&mut rng);
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            for compressed in [true, false] {
+                let bytes = point.to_bytes(compressed);
+                assert_eq!(Point::from_bytes_strict(&bytes), Ok(point));
+            }
+        }
+        assert_eq!(
+            NonZero::<Point<E>>::from_bytes_strict(random_point.to_bytes(true)),
+            Ok(NonZero::from_point(random_point).unwrap())
+        );
+        assert_eq!(
+            NonZero::<Point<E>>::from_bytes_strict(Point::<E>::zero().to_bytes(true)),
+            Err(InvalidPoint::Identity)
+        );
+        assert_eq!(
+            NonZero::<Point<E>>::from_bytes(Point::<E>::zero().to_bytes(true)),
+            Err(InvalidPoint::Identity)
+        );
+
+        let random_scalar = Scalar::<E>::random(&mut rng);
+        let be_bytes = random_scalar.to_be_bytes();
+        let le_bytes = random_scalar.to_le_bytes();
+        assert_eq!(Scalar::from_be_bytes_strict(&be_bytes), Ok(random_scalar));
+        assert_eq!(Scalar::from_le_bytes_strict(&le_bytes), Ok(random_scalar));
+        assert_eq!(
+            Scalar::<E>::from_be_bytes_strict(&be_bytes[1..]),
+            Err(InvalidScalar::InvalidLength)
+        );
+        assert_eq!(
+            Scalar::<E>::from_le_bytes_strict(&le_bytes[..le_bytes.len() - 1]),
+            Err(InvalidScalar::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn fixed_size_encoding<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let random_point = Point::generator() * Scalar::<E>::random(&mut rng);
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            let compressed = point.to_bytes_compressed();
+            let uncompressed = point.to_bytes_uncompressed();
+            assert_eq!(compressed.as_ref(), &*point.to_bytes(true));
+            assert_eq!(uncompressed.as_ref(), &*point.to_bytes(false));
+            assert_eq!(compressed.as_ref().len(), Point::<E>::COMPRESSED_LEN);
+            assert_eq!(uncompressed.as_ref().len(), Point::<E>::UNCOMPRESSED_LEN);
+            assert_eq!(Point::from_compressed(&compressed), Ok(point));
+            assert_eq!(Point::from_uncompressed(&uncompressed), Ok(point));
+
+            let mut buffer = [0xAA; 200];
+            let len = point.write_compressed(&mut buffer).unwrap();
+            assert_eq!(&buffer[..len], compressed.as_ref());
+            let len = point.write_uncompressed(&mut buffer).unwrap();
+            assert_eq!(&buffer[..len], uncompressed.as_ref());
+
+            let mut small_buffer = vec![0; Point::<E>::COMPRESSED_LEN - 1];
+            let err = point.write_compressed(&mut small_buffer).unwrap_err();
+            assert_eq!(err.required_len(), Point::<E>::COMPRESSED_LEN);
+        }
+
+        let scalar = Scalar::<E>::random(&mut rng);
+        let mut buffer = vec![0; Scalar::<E>::SERIALIZED_LEN];
+        assert_eq!(scalar.write_be_bytes(&mut buffer), Ok(buffer.len()));
+        assert_eq!(buffer, scalar.to_be_bytes().as_bytes());
+        assert_eq!(scalar.write_le_bytes(&mut buffer), Ok(buffer.len()));
+        assert_eq!(buffer, scalar.to_le_bytes().as_bytes());
+        assert!(scalar.write_be_bytes(&mut buffer[1..]).is_err());
+    }
+
     fn _is_copy<T: Copy>() {}
     fn _test_point_and_scalar_are_copy<E: Curve>() {
         _is_copy::<Scalar<E>>();
@@ -264,3 +426,295 @@ mod coordinates {
     #[instantiate_tests(<Stark>)]
     mod stark {}
 }
+
+#[generic_tests::define]
+mod full_group {
+    use generic_ec::core::MulByCofactor;
+    use generic_ec::curves::{Ed25519, Secp256k1, Secp256r1};
+    use generic_ec::{Curve, FullGroupPoint, Point, Scalar};
+
+    use rand_dev::DevRng;
+
+    #[test]
+    fn clear_cofactor_of_prime_order_point<E: Curve + MulByCofactor>() {
+        let mut rng = DevRng::new();
+        let point = Point::<E>::generator() * Scalar::random(&mut rng);
+
+        let full_group_point = FullGroupPoint::from(point);
+        assert!(full_group_point.is_torsion_free());
+        assert!(!full_group_point.is_small_order());
+        assert_eq!(full_group_point.to_point(), Some(point));
+
+        let bytes = full_group_point.to_bytes(true);
+        assert_eq!(bytes.as_bytes(), point.to_bytes(true).as_bytes());
+        assert_eq!(
+            FullGroupPoint::<E>::from_bytes(&bytes).unwrap(),
+            full_group_point
+        );
+
+        let cleared = full_group_point.clear_cofactor();
+        let cofactor_times_point = full_group_point.mul_by_cofactor().to_point().unwrap();
+        assert_eq!(cleared, cofactor_times_point);
+    }
+
+    #[test]
+    fn zero_is_small_order<E: Curve + MulByCofactor>() {
+        let zero = FullGroupPoint::<E>::zero();
+        assert!(zero.is_zero());
+        assert!(zero.is_small_order());
+        assert!(zero.clear_cofactor().is_zero());
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+}
+
+mod full_group_torsion {
+    use generic_ec::curves::{Ed25519, Secp256k1};
+    use generic_ec::{FullGroupPoint, Point, Scalar};
+
+    use rand_dev::DevRng;
+
+    #[test]
+    fn ed25519_torsion() {
+        // Point of order 2 (y = -1)
+        let mut bytes = [0xff; 32];
+        bytes[0] = 0xec;
+        bytes[31] = 0x7f;
+
+        assert!(Point::<Ed25519>::from_bytes(bytes).is_err());
+        let torsion = FullGroupPoint::<Ed25519>::from_bytes(bytes).unwrap();
+        assert!(!torsion.is_torsion_free());
+        assert!(torsion.is_small_order());
+        assert_eq!(torsion.to_point(), None);
+
+        let mut rng = DevRng::new();
+        let point = Point::<Ed25519>::generator() * Scalar::random(&mut rng);
+        let mixed = FullGroupPoint::from(point) + torsion;
+        assert!(!mixed.is_torsion_free());
+        assert!(!mixed.is_small_order());
+        assert_eq!(mixed.to_point(), None);
+        assert_eq!(mixed.clear_cofactor(), point * Scalar::from(8));
+        assert_eq!(mixed - torsion, FullGroupPoint::from(point));
+    }
+
+    #[test]
+    fn secp256k1_clear_cofactor_is_identity() {
+        let mut rng = DevRng::new();
+        let point = Point::<Secp256k1>::generator() * Scalar::random(&mut rng);
+        assert_eq!(FullGroupPoint::from(point).clear_cofactor(), point);
+    }
+}
+
+mod strict_decoding {
+    use generic_ec::curves::{Ed25519, Secp256k1};
+    use generic_ec::errors::InvalidPoint;
+    use generic_ec::{Point, Scalar};
+
+    use rand_dev::DevRng;
+
+    #[test]
+    fn ed25519_non_canonical_y() {
+        // y = p + 1, which is non-canonical encoding of identity point
+        let mut bytes = [0xff; 32];
+        bytes[0] = 0xee;
+        bytes[31] = 0x7f;
+
+        assert_eq!(Point::<Ed25519>::from_bytes(bytes), Ok(Point::zero()));
+        assert_eq!(
+            Point::<Ed25519>::from_bytes_strict(bytes),
+            Err(InvalidPoint::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn ed25519_torsion() {
+        // Point of order 2 (y = -1)
+        let mut bytes = [0xff; 32];
+        bytes[0] = 0xec;
+        bytes[31] = 0x7f;
+
+        assert_eq!(
+            Point::<Ed25519>::from_bytes(bytes),
+            Err(InvalidPoint::HasTorsion)
+        );
+        assert_eq!(
+            Point::<Ed25519>::from_bytes_strict(bytes),
+            Err(InvalidPoint::HasTorsion)
+        );
+        assert!(bool::from(Point::<Ed25519>::ct_from_bytes(bytes).is_none()));
+
+        // `y = 2` is not a `y` coordinate of any point on the curve
+        let mut bytes = [0; 32];
+        bytes[0] = 2;
+        assert!(bool::from(Point::<Ed25519>::ct_from_bytes(bytes).is_none()));
+        assert_eq!(
+            Point::<Ed25519>::from_bytes(bytes),
+            Err(InvalidPoint::NotOnCurve)
+        );
+    }
+
+    #[test]
+    fn sec1_short_identity() {
+        assert_eq!(Point::<Secp256k1>::from_bytes([0]), Ok(Point::zero()));
+        assert_eq!(
+            Point::<Secp256k1>::from_bytes_strict([0]),
+            Err(InvalidPoint::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn sec1_hybrid_encoding() {
+        let mut rng = DevRng::new();
+        let point = Point::<Secp256k1>::generator() * Scalar::random(&mut rng);
+        let compressed = point.to_bytes(true);
+        let mut hybrid = point.to_bytes(false).to_vec();
+        // Hybrid encoding uses prefix 0x06 or 0x07 depending on parity of y
+        hybrid[0] = compressed[0] + 4;
+
+        assert!(Point::<Secp256k1>::from_bytes(&hybrid).map_or(true, |p| p == point));
+        assert_eq!(
+            Point::<Secp256k1>::from_bytes_strict(&hybrid),
+            Err(InvalidPoint::NotOnCurve)
+        );
+    }
+}
+
+mod ed25519_coordinates {
+    use generic_ec::coords::{
+        AlwaysHasAffineY, AlwaysHasAffineYAndSign, Coordinate, Coordinates, HasAffineX,
+        HasAffineXY, HasAffineY, Sign,
+    };
+    use generic_ec::curves::Ed25519;
+    use generic_ec::{Point, Scalar};
+
+    use rand_dev::DevRng;
+
+    /// Parses a coordinate from big-endian hex
+    fn coordinate(be_hex: &str) -> Coordinate<Ed25519> {
+        let bytes = hex::decode(format!("{be_hex:0>64}")).unwrap();
+        Coordinate::from_be_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn generator_coords_match_rfc8032() {
+        let g = Point::<Ed25519>::generator().to_point();
+        let expected = Coordinates {
+            x: coordinate("216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a"),
+            y: coordinate("6666666666666666666666666666666666666666666666666666666666666658"),
+        };
+
+        assert_eq!(g.coords(), Some(expected.clone()));
+        assert_eq!(Point::from_coords(&expected), Some(g));
+        // `x` is even
+        assert_eq!(g.y_and_sign(), (Sign::NonNegative, expected.y.clone()));
+        assert_eq!(
+            Point::from_y_and_sign(Sign::NonNegative, &expected.y),
+            Some(g)
+        );
+        assert_eq!(
+            Point::from_y_and_sign(Sign::Negative, &expected.y),
+            Some(-g)
+        );
+    }
+
+    #[test]
+    fn le_coords_match_rfc8032_encoding() {
+        let mut rng = DevRng::new();
+        for _ in 0..20 {
+            let point = Point::<Ed25519>::generator() * Scalar::random(&mut rng);
+            let (sign, y) = point.y_and_sign();
+
+            // RFC 8032 encoding: `y` in little-endian, sign of `x` in the most significant bit
+            let mut encoding = y.to_le_bytes();
+            encoding[31] |= u8::from(sign.is_negative()) << 7;
+            assert_eq!(encoding.as_slice(), &*point.to_bytes(true));
+
+            let y_le = y.to_le_bytes();
+            assert_eq!(Coordinate::from_le_bytes(&y_le).unwrap(), y);
+        }
+        assert!(Coordinate::<Ed25519>::from_le_bytes(&[0; 31]).is_err());
+    }
+
+    #[test]
+    fn identity_has_coords() {
+        let zero = Point::<Ed25519>::zero();
+        let coords = zero.coords().unwrap();
+        assert_eq!(coords.x, coordinate("00"));
+        assert_eq!(coords.y, coordinate("01"));
+        assert_eq!(Point::from_coords(&coords), Some(zero));
+        assert_eq!(
+            Point::<Ed25519>::from_y_and_sign(Sign::NonNegative, &coords.y),
+            Some(zero)
+        );
+        // Negative zero is not a valid encoding
+        assert_eq!(
+            Point::<Ed25519>::from_y_and_sign(Sign::Negative, &coords.y),
+            None
+        );
+    }
+
+    #[test]
+    fn coords_roundtrip() {
+        let mut rng = DevRng::new();
+        for _ in 0..20 {
+            let point = Point::<Ed25519>::generator() * Scalar::random(&mut rng);
+
+            let coords = point.coords().unwrap();
+            assert_eq!(HasAffineX::x(&point), Some(coords.x.clone()));
+            assert_eq!(HasAffineY::y(&point), Some(coords.y.clone()));
+            assert_eq!(AlwaysHasAffineY::y(&point), coords.y);
+            assert_eq!(Point::from_coords(&coords), Some(point));
+
+            let (sign, y) = point.y_and_sign();
+            assert_eq!(y, coords.y);
+            assert_eq!(sign.is_negative(), coords.x.as_be_bytes()[31] & 1 == 1);
+            assert_eq!(Point::from_y_and_sign(sign, &y), Some(point));
+
+            // Compressed point is little-endian `y` with sign of `x` in the most significant bit
+            let mut compressed = [0u8; 32];
+            compressed.copy_from_slice(y.as_be_bytes());
+            compressed.reverse();
+            compressed[31] |= u8::from(sign.is_negative()) << 7;
+            assert_eq!(point.to_bytes(true).as_bytes(), compressed);
+        }
+    }
+
+    #[test]
+    fn invalid_coords_are_rejected() {
+        let mut rng = DevRng::new();
+        let point = Point::<Ed25519>::generator() * Scalar::random(&mut rng);
+        let coords = point.coords().unwrap();
+
+        // Point is not on curve
+        let mut invalid = coords.clone();
+        invalid.x.as_mut()[31] ^= 1;
+        assert_eq!(Point::from_coords(&invalid), None);
+
+        // Non-canonical `y = p + 1`
+        let non_canonical_y =
+            coordinate("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffee");
+        assert_eq!(
+            Point::<Ed25519>::from_y_and_sign(Sign::NonNegative, &non_canonical_y),
+            None
+        );
+        let non_canonical = Coordinates {
+            x: coordinate("00"),
+            y: non_canonical_y,
+        };
+        assert_eq!(Point::from_coords(&non_canonical), None);
+
+        // Point of order 2 is on curve but has torsion component
+        let torsion = Coordinates {
+            x: coordinate("00"),
+            y: coordinate("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffec"),
+        };
+        assert_eq!(Point::from_coords(&torsion), None);
+    }
+}
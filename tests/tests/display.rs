@@ -0,0 +1,181 @@
+#[generic_tests::define]
+mod generic {
+    use generic_ec::coords::Coordinate;
+    use generic_ec::core::ByteArray;
+    use generic_ec::errors::{InvalidPoint, InvalidScalar, ParseError};
+    use generic_ec::{curves::*, Curve, NonZero, Point, Scalar, SecretScalar};
+    use rand_dev::DevRng;
+
+    #[test]
+    fn point_roundtrip<E: Curve>() {
+        let mut rng = DevRng::new();
+        let random_point = Point::generator() * Scalar::<E>::random(&mut rng);
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            let compressed = hex::encode(point.to_bytes(true));
+            assert_eq!(point.to_string(), compressed);
+            assert_eq!(format!("{point:x}"), compressed);
+            assert_eq!(format!("{point:X}"), compressed.to_uppercase());
+            assert_eq!(format!("{point:#x}"), format!("0x{compressed}"));
+
+            for s in [
+                compressed.clone(),
+                compressed.to_uppercase(),
+                format!("0x{compressed}"),
+                hex::encode(point.to_bytes(false)),
+            ] {
+                assert_eq!(s.parse::<Point<E>>(), Ok(point));
+            }
+        }
+
+        let non_zero = NonZero::from_point(random_point).unwrap();
+        assert_eq!(non_zero.to_string(), random_point.to_string());
+        assert_eq!(non_zero.to_string().parse(), Ok(non_zero));
+        assert_eq!(
+            Point::<E>::zero().to_string().parse::<NonZero<Point<E>>>(),
+            Err(ParseError::InvalidPoint(InvalidPoint::Identity))
+        );
+    }
+
+    #[test]
+    fn point_parse_errors<E: Curve>() {
+        assert_eq!("".parse::<Point<E>>(), Err(ParseError::MalformedHex));
+        assert_eq!("abc".parse::<Point<E>>(), Err(ParseError::MalformedHex));
+        assert_eq!("zz".parse::<Point<E>>(), Err(ParseError::MalformedHex));
+        assert_eq!(
+            "00".repeat(200).parse::<Point<E>>(),
+            Err(ParseError::InvalidPoint(InvalidPoint::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn scalar_roundtrip<E: Curve>() {
+        let mut rng = DevRng::new();
+        for scalar in [
+            Scalar::<E>::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+            Scalar::random(&mut rng),
+        ] {
+            let be_hex = hex::encode(scalar.to_be_bytes());
+            assert_eq!(format!("{scalar:x}"), be_hex);
+            assert_eq!(format!("{scalar:X}"), be_hex.to_uppercase());
+            assert_eq!(format!("{scalar:#x}"), format!("0x{be_hex}"));
+
+            assert_eq!(scalar.to_string().parse(), Ok(scalar));
+            assert_eq!(format!("{scalar:#x}").parse(), Ok(scalar));
+            assert_eq!(format!("{scalar:#X}").parse(), Ok(scalar));
+        }
+
+        assert_eq!(Scalar::<E>::zero().to_string(), "0");
+        assert_eq!(Scalar::<E>::from(1_000_000_u64).to_string(), "1000000");
+        assert_eq!(
+            Scalar::<E>::from(u64::MAX).to_string(),
+            u64::MAX.to_string()
+        );
+        assert_eq!(
+            (Scalar::<E>::from(u64::MAX) * Scalar::from(u64::MAX)).to_string(),
+            (u128::from(u64::MAX) * u128::from(u64::MAX)).to_string()
+        );
+        assert_eq!("0x2a".parse(), Ok(Scalar::<E>::from(42)));
+        assert_eq!("00042".parse(), Ok(Scalar::<E>::from(42)));
+
+        let non_zero = NonZero::<Scalar<E>>::random(&mut rng);
+        assert_eq!(non_zero.to_string(), non_zero.as_ref().to_string());
+        assert_eq!(non_zero.to_string().parse(), Ok(non_zero));
+        assert_eq!(
+            "0".parse::<NonZero<Scalar<E>>>(),
+            Err(ParseError::ZeroScalar)
+        );
+    }
+
+    #[test]
+    fn scalar_parse_errors<E: Curve>() {
+        assert_eq!("".parse::<Scalar<E>>(), Err(ParseError::MalformedDecimal));
+        assert_eq!("-1".parse::<Scalar<E>>(), Err(ParseError::MalformedDecimal));
+        assert_eq!(
+            "12a".parse::<Scalar<E>>(),
+            Err(ParseError::MalformedDecimal)
+        );
+        assert_eq!("0x".parse::<Scalar<E>>(), Err(ParseError::MalformedHex));
+        assert_eq!("0xzz".parse::<Scalar<E>>(), Err(ParseError::MalformedHex));
+
+        // Group order is not a valid scalar
+        let minus_one = (-Scalar::<E>::one()).to_string();
+        let last_digit = minus_one.as_bytes()[minus_one.len() - 1];
+        assert!(last_digit < b'9');
+        let order = format!(
+            "{}{}",
+            &minus_one[..minus_one.len() - 1],
+            char::from(last_digit + 1)
+        );
+        assert_eq!(
+            order.parse::<Scalar<E>>(),
+            Err(ParseError::InvalidScalar(InvalidScalar::OutOfRange))
+        );
+        // Doesn't fit into scalar bytes representation at all
+        assert_eq!(
+            "9".repeat(200).parse::<Scalar<E>>(),
+            Err(ParseError::InvalidScalar(InvalidScalar::OutOfRange))
+        );
+        assert_eq!(
+            format!("0x{}", "00".repeat(Scalar::<E>::SERIALIZED_LEN + 1)).parse::<Scalar<E>>(),
+            Err(ParseError::InvalidScalar(InvalidScalar::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn secret_scalar_is_redacted<E: Curve>() {
+        let mut rng = DevRng::new();
+        let secret = SecretScalar::<E>::random(&mut rng);
+        assert_eq!(secret.to_string(), "<redacted>");
+    }
+
+    #[test]
+    fn coordinate_roundtrip<E: Curve>() {
+        let mut rng = DevRng::new();
+        let coordinate = Coordinate::<E>::new({
+            let mut bytes = <E::CoordinateArray as ByteArray>::zeroes();
+            rand::RngCore::fill_bytes(&mut rng, bytes.as_mut());
+            bytes
+        });
+        let coordinate_hex = hex::encode(coordinate.as_be_bytes());
+        assert_eq!(coordinate.to_string(), coordinate_hex);
+        assert_eq!(format!("{coordinate:X}"), coordinate_hex.to_uppercase());
+        assert_eq!(coordinate_hex.parse(), Ok(coordinate.clone()));
+        assert_eq!(format!("{coordinate:#x}").parse(), Ok(coordinate));
+
+        assert_eq!(
+            "00".parse::<Coordinate<E>>(),
+            Err(ParseError::InvalidCoordinate)
+        );
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+}
+
+mod decimal {
+    use generic_ec::curves::{Ed25519, Secp256k1};
+    use generic_ec::Scalar;
+
+    #[test]
+    fn group_order_minus_one() {
+        assert_eq!(
+            (-Scalar::<Secp256k1>::one()).to_string(),
+            "115792089237316195423570985008687907852837564279074904382605163141518161494336"
+        );
+        assert_eq!(
+            (-Scalar::<Ed25519>::one()).to_string(),
+            "7237005577332262213973186563042994240857116359379907606001950938285454250988"
+        );
+    }
+}
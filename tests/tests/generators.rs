@@ -0,0 +1,86 @@
+#[generic_tests::define]
+mod generic {
+    use generic_ec::core::hash_to_curve::HashToCurve;
+    use generic_ec::hash_to_curve::{FromHash, Tag};
+    use generic_ec::{curves::*, Curve, Generators, Point};
+    use rand_dev::DevRng;
+
+    const TAG: Tag = Tag::new_unwrap(b"generic-ec-tests/generators");
+
+    #[test]
+    fn derivation<E: Curve + HashToCurve>() {
+        let generators = Generators::<E>::new(TAG, 4).unwrap();
+        assert_eq!(generators.len(), 4);
+        assert_eq!(generators.tag().as_bytes(), TAG.as_bytes());
+
+        for (i, h) in generators.iter().enumerate() {
+            let i = i as u64;
+            assert_eq!(*h, Point::hash(TAG, &i.to_be_bytes()).unwrap());
+            assert_eq!(*h, Generators::derive(TAG, i).unwrap());
+            assert!(!h.is_zero());
+            assert_ne!(*h, Point::generator().to_point());
+        }
+
+        // All generators are distinct
+        for (i, a) in generators.iter().enumerate() {
+            for b in &generators.as_slice()[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+
+        // Different tag produces different generators
+        let other = Generators::<E>::new(Tag::new_unwrap(b"other tag"), 1).unwrap();
+        assert_ne!(other[0], generators[0]);
+    }
+
+    #[test]
+    fn caching<E: Curve + HashToCurve>() {
+        let mut generators = Generators::<E>::new(TAG, 0).unwrap();
+        assert!(generators.is_empty());
+        assert_eq!(generators.get(0), None);
+
+        let h2 = generators.get_or_derive(2).unwrap();
+        assert_eq!(generators.len(), 3);
+        assert_eq!(generators.get(2), Some(h2));
+
+        generators.extend_to(1).unwrap();
+        assert_eq!(generators.len(), 3);
+        generators.extend_to(5).unwrap();
+        assert_eq!(generators.len(), 5);
+
+        let precomputed = Generators::<E>::new(TAG, 5).unwrap();
+        assert_eq!(generators.as_slice(), precomputed.as_slice());
+
+        assert!(generators.get_or_derive(usize::MAX).is_err());
+        assert_eq!(generators.len(), 5);
+    }
+
+    #[test]
+    fn tag_too_long<E: Curve + HashToCurve>() {
+        let tag = [b'a'; 256];
+        let err = Generators::<E>::new(Tag::new_unwrap(&tag), 1).unwrap_err();
+        assert_eq!(err.to_string(), "tag is too long");
+        assert!(Generators::<E>::new(Tag::new_unwrap(&tag[..255]), 1).is_ok());
+    }
+
+    #[test]
+    fn random_unknown_dlog<E: Curve + HashToCurve>() {
+        let mut rng = DevRng::new();
+        let a = Point::<E>::random_unknown_dlog(&mut rng).unwrap();
+        let b = Point::<E>::random_unknown_dlog(&mut rng).unwrap();
+        assert!(!a.is_zero());
+        assert_ne!(a, b);
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<StarkTryAndIncrement>)]
+    mod stark {}
+
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+}
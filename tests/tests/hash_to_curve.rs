@@ -0,0 +1,297 @@
+use generic_ec::coords::HasAffineXY;
+use generic_ec::curves::{
+    Ed25519, Secp256k1, Secp256k1Sha512, Secp256k1Shake128, Secp256r1, StarkTryAndIncrement,
+};
+use generic_ec::hash_to_curve::{EncodeToCurve, FromHash, PointHasher, ScalarHasher, Tag, TagBuf};
+use generic_ec::Curve;
+use generic_ec::{Point, Scalar};
+
+/// Test vectors from [RFC 9380, Appendix J.1](https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J.1)
+#[test]
+fn p256_test_vectors() {
+    const TAG_RO: Tag = Tag::new_unwrap(b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_");
+    const TAG_NU: Tag = Tag::new_unwrap(b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_");
+
+    let point = Point::<Secp256r1>::hash(TAG_RO, b"abc").unwrap();
+    let coords = point.coords().unwrap();
+    assert_eq!(
+        hex::encode(coords.x.as_be_bytes()),
+        "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f"
+    );
+    assert_eq!(
+        hex::encode(coords.y.as_be_bytes()),
+        "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e"
+    );
+
+    let point = Point::<Secp256r1>::encode(TAG_NU, b"").unwrap();
+    let coords = point.coords().unwrap();
+    assert_eq!(
+        hex::encode(coords.x.as_be_bytes()),
+        "f871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1"
+    );
+    assert_eq!(
+        hex::encode(coords.y.as_be_bytes()),
+        "87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b"
+    );
+}
+
+/// Test vectors from [RFC 9380, Appendix J.5](https://www.rfc-editor.org/rfc/rfc9380.html#appendix-J.5)
+///
+/// Expected points are given in compressed form, i.e. little-endian `y` coordinate with
+/// sign of `x` in the most significant bit
+#[test]
+fn edwards25519_test_vectors() {
+    const TAG_RO: Tag = Tag::new_unwrap(b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_");
+    const TAG_NU: Tag = Tag::new_unwrap(b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_");
+
+    let point = Point::<Ed25519>::hash(TAG_RO, b"").unwrap();
+    assert_eq!(
+        hex::encode(point.to_bytes(true)),
+        "21dc15e10253796df23a7699c8a383ea624cce88c52431f6be220b1a56c8a609"
+    );
+    let point = Point::<Ed25519>::hash(TAG_RO, b"abc").unwrap();
+    assert_eq!(
+        hex::encode(point.to_bytes(true)),
+        "31558a26887f23fb8218f143e69d5f0af2e7831130bd5b432ef23883b895839a"
+    );
+
+    let point = Point::<Ed25519>::encode(TAG_NU, b"").unwrap();
+    assert_eq!(
+        hex::encode(point.to_bytes(true)),
+        "9b0f7f682dabce2190b14e21a175f39eb6a6b29fff2a9f5e72d5a4044d312e22"
+    );
+}
+
+#[test]
+fn incremental_hashing_matches_hash_concat() {
+    fn test<E: Curve + generic_ec::core::hash_to_curve::IncrementalHashToCurve>() {
+        let long_tag = [b'a'; 300];
+        for tag in [
+            Tag::new_unwrap(b"generic-ec-tests"),
+            Tag::new_unwrap(&long_tag),
+        ] {
+            let chunks: [&[u8]; 4] = [b"hello", b"", b" ", &[b'x'; 1000]];
+
+            let mut scalar_hasher = ScalarHasher::<E>::new(tag);
+            let mut point_hasher = PointHasher::<E>::new(tag);
+            for chunk in chunks {
+                scalar_hasher.update(chunk);
+                point_hasher.update(chunk);
+            }
+            assert_eq!(
+                scalar_hasher.finalize().unwrap(),
+                Scalar::<E>::hash_concat(tag, &chunks).unwrap()
+            );
+            assert_eq!(
+                point_hasher.finalize().unwrap(),
+                Point::<E>::hash_concat(tag, &chunks).unwrap()
+            );
+
+            let point = PointHasher::<E>::new(tag)
+                .chain(b"hello world")
+                .finalize()
+                .unwrap();
+            assert_eq!(point, Point::<E>::hash(tag, b"hello world").unwrap());
+        }
+    }
+
+    test::<Secp256k1>();
+    test::<Secp256k1Sha512>();
+    test::<Secp256k1Shake128>();
+    test::<Secp256r1>();
+    test::<Ed25519>();
+    test::<StarkTryAndIncrement>();
+}
+
+/// `map_to_curve` applied to field element from RFC 9380 test vectors must output
+/// the same point as `encode_to_curve`
+#[test]
+fn map_to_curve_matches_encode_to_curve() {
+    const TAG_P256: Tag = Tag::new_unwrap(b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_");
+    const TAG_ED25519: Tag =
+        Tag::new_unwrap(b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_");
+
+    let u =
+        hex::decode("b22d487045f80e9edcb0ecc8d4bf77833e2bf1f3a54004d7df1d57f4802d311f").unwrap();
+    assert_eq!(
+        Point::<Secp256r1>::map_to_curve(&u).unwrap(),
+        Point::<Secp256r1>::encode(TAG_P256, b"").unwrap()
+    );
+
+    let u =
+        hex::decode("7f3e7fb9428103ad7f52db32f9df32505d7b427d894c5093f7a0f0374a30641d").unwrap();
+    assert_eq!(
+        Point::<Ed25519>::map_to_curve(&u).unwrap(),
+        Point::<Ed25519>::encode(TAG_ED25519, b"").unwrap()
+    );
+
+    // Too long field element
+    assert!(Point::<Ed25519>::map_to_curve(&[1; 49]).is_err());
+    // StarkTryAndIncrement doesn't expose `map_to_curve`
+    assert!(Point::<StarkTryAndIncrement>::map_to_curve(&u).is_err());
+}
+
+#[test]
+fn stark_try_and_increment() {
+    const TAG: Tag = Tag::new_unwrap(b"generic-ec-tests");
+
+    let points = (0..10_u8)
+        .map(|i| Point::<StarkTryAndIncrement>::hash(TAG, &[i]).unwrap())
+        .collect::<Vec<_>>();
+    for (i, point) in points.iter().enumerate() {
+        assert!(!point.is_zero());
+        assert!(!points[..i].contains(point));
+    }
+
+    let s1 = Scalar::<StarkTryAndIncrement>::hash(TAG, b"hello").unwrap();
+    let s2 = Scalar::<StarkTryAndIncrement>::hash(TAG, b"hello!").unwrap();
+    assert_ne!(s1, s2);
+}
+
+#[test]
+fn encode_concat_matches_encode() {
+    const TAG: Tag = Tag::new_unwrap(b"generic-ec-tests");
+
+    let p1 = Point::<Secp256k1>::encode(TAG, b"hello world").unwrap();
+    let p2 = Point::<Secp256k1>::encode_concat(TAG, &[b"hello", b" ", b"world"]).unwrap();
+    assert_eq!(p1, p2);
+
+    let p3 = Point::<Secp256k1>::hash(TAG, b"hello world").unwrap();
+    assert_ne!(p1, p3);
+}
+
+#[test]
+fn suites_produce_different_outputs() {
+    const TAG: Tag = Tag::new_unwrap(b"generic-ec-tests");
+    let msg = b"hello world";
+
+    let sha256 = Point::<Secp256k1>::hash(TAG, msg).unwrap().to_bytes(true);
+    let sha512 = Point::<Secp256k1Sha512>::hash(TAG, msg)
+        .unwrap()
+        .to_bytes(true);
+    let shake128 = Point::<Secp256k1Shake128>::hash(TAG, msg)
+        .unwrap()
+        .to_bytes(true);
+    assert_ne!(sha256.as_bytes(), sha512.as_bytes());
+    assert_ne!(sha256.as_bytes(), shake128.as_bytes());
+    assert_ne!(sha512.as_bytes(), shake128.as_bytes());
+
+    // Same curve, so points are interchangeable
+    let point = Point::<Secp256k1>::from_bytes(&shake128).unwrap();
+    assert_eq!(point.to_bytes(true).as_bytes(), shake128.as_bytes());
+
+    let s1 = Scalar::<Secp256k1>::hash(TAG, msg).unwrap();
+    let s2 = Scalar::<Secp256k1Shake128>::hash(TAG, msg).unwrap();
+    assert_ne!(s1.to_be_bytes().as_bytes(), s2.to_be_bytes().as_bytes());
+}
+
+#[test]
+fn hash_digestable() {
+    #[derive(udigest::Digestable)]
+    struct Transcript<'a> {
+        round: u16,
+        point: Point<Secp256k1>,
+        #[udigest(as_bytes)]
+        data: &'a [u8],
+    }
+
+    const TAG1: Tag = Tag::new_unwrap(b"generic-ec-tests-1");
+    const TAG2: Tag = Tag::new_unwrap(b"generic-ec-tests-2");
+
+    let value = Transcript {
+        round: 1,
+        point: Point::generator().into(),
+        data: b"hello world",
+    };
+    let other = Transcript { round: 2, ..value };
+
+    // Digest is mapped to scalar via hash to field, the same way as for points
+    let s1 = Scalar::<Secp256k1>::hash_digestable::<sha2::Sha256>(TAG1, &value).unwrap();
+    let digest = udigest::udigest::<sha2::Sha256>(udigest::Tag::new(TAG1.as_bytes()), &value);
+    assert_eq!(s1, Scalar::<Secp256k1>::hash(TAG1, &digest).unwrap());
+    assert_eq!(
+        s1,
+        Scalar::<Secp256k1>::hash_digestable::<sha2::Sha256>(TAG1, &value).unwrap()
+    );
+    assert_ne!(
+        s1,
+        Scalar::<Secp256k1>::hash_digestable::<sha2::Sha256>(TAG2, &value).unwrap()
+    );
+    assert_ne!(
+        s1,
+        Scalar::<Secp256k1>::hash_digestable::<sha2::Sha256>(TAG1, &other).unwrap()
+    );
+    assert_ne!(
+        s1,
+        Scalar::<Secp256k1>::hash_digestable::<sha2::Sha512>(TAG1, &value).unwrap()
+    );
+
+    let p1 = Point::<Secp256k1>::hash_digestable::<sha2::Sha256>(TAG1, &value).unwrap();
+    assert_eq!(
+        p1,
+        Point::<Secp256k1>::hash_digestable::<sha2::Sha256>(TAG1, &value).unwrap()
+    );
+    assert_ne!(
+        p1,
+        Point::<Secp256k1>::hash_digestable::<sha2::Sha256>(TAG2, &value).unwrap()
+    );
+    assert_ne!(
+        p1,
+        Point::<Secp256k1>::hash_digestable::<sha2::Sha256>(TAG1, &other).unwrap()
+    );
+}
+
+#[test]
+fn tag_buf() {
+    const ROOT: TagBuf = TagBuf::new_unwrap(b"generic-ec-tests").with_version(12);
+    const ROUND1: TagBuf = ROOT.derive("round1");
+    const SUBROUND: TagBuf = ROUND1.derive("a");
+
+    assert_eq!(ROOT.as_bytes(), b"generic-ec-tests@v12");
+    assert_eq!(ROUND1.as_bytes(), b"generic-ec-tests@v12/round1");
+    assert_eq!(SUBROUND.as_bytes(), b"generic-ec-tests@v12/round1/a");
+    assert_eq!(TagBuf::new_unwrap(b"a").with_version(0).as_bytes(), b"a@v0");
+    assert_eq!(
+        TagBuf::new_unwrap(b"a").with_version(u16::MAX).as_bytes(),
+        b"a@v65535"
+    );
+
+    assert!(TagBuf::new(b"").is_none());
+    assert!(TagBuf::new(&[b'a'; 256]).is_none());
+    assert!(ROOT.try_derive("").is_none());
+    assert!(ROOT.try_derive("a/b").is_none());
+    assert!(ROOT.try_derive("a@v1").is_none());
+    assert!(TagBuf::new_unwrap(&[b'a'; 254]).try_derive("b").is_none());
+
+    let s1 = Scalar::<Secp256k1>::hash(ROUND1.as_tag(), b"hello").unwrap();
+    let s2 = Scalar::<Secp256k1>::hash(Tag::from(&SUBROUND), b"hello").unwrap();
+    assert_ne!(s1, s2);
+}
+
+#[test]
+fn tag_buf_oversized() {
+    let long_tag = [b'a'; 300];
+    let tag = Tag::new_unwrap(&long_tag);
+
+    let xmd = TagBuf::new_xmd::<sha2::Sha256>(&long_tag).unwrap();
+    assert_eq!(xmd.as_bytes().len(), 32);
+    assert_eq!(
+        Point::<Secp256k1>::hash(xmd.as_tag(), b"hello").unwrap(),
+        Point::<Secp256k1>::hash(tag, b"hello").unwrap()
+    );
+
+    let xof = TagBuf::new_xof::<sha3::Shake128>(&long_tag).unwrap();
+    assert_eq!(xof.as_bytes().len(), 32);
+    assert_eq!(
+        Point::<Secp256k1Shake128>::hash(xof.as_tag(), b"hello").unwrap(),
+        Point::<Secp256k1Shake128>::hash(tag, b"hello").unwrap()
+    );
+
+    // Short tags are kept as is
+    assert_eq!(
+        TagBuf::new_xmd::<sha2::Sha256>(b"short")
+            .unwrap()
+            .as_bytes(),
+        b"short"
+    );
+}
@@ -0,0 +1,167 @@
+#[generic_tests::define]
+mod generic {
+    use generic_ec::coords::HasAffineXY;
+    use generic_ec::errors::{InvalidPoint, KeyFormatError};
+    use generic_ec::jwk::Jwk;
+    use generic_ec::{curves::*, Curve, Point, SecretScalar};
+    use rand_dev::DevRng;
+
+    #[test]
+    fn public_key_roundtrip<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let mut rng = DevRng::new();
+        let point = Point::<E>::generator() * SecretScalar::random(&mut rng);
+
+        let jwk = point.to_jwk().unwrap();
+        assert!(jwk.d.is_none());
+        assert_eq!(Point::<E>::from_jwk(&jwk).unwrap(), point);
+
+        let json = serde_json::to_string(&jwk).unwrap();
+        let parsed: Jwk = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, jwk);
+
+        assert_eq!(
+            Point::<E>::zero().to_jwk().err(),
+            Some(KeyFormatError::InvalidPoint(InvalidPoint::Identity))
+        );
+        assert_eq!(
+            SecretScalar::<E>::from_jwk(&jwk).err(),
+            Some(KeyFormatError::MissingSecretKey)
+        );
+    }
+
+    #[test]
+    fn secret_key_roundtrip<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let mut rng = DevRng::new();
+        let sk = SecretScalar::<E>::random(&mut rng);
+        let pk = Point::generator() * &sk;
+
+        let jwk = sk.to_jwk().unwrap();
+        let decoded = SecretScalar::<E>::from_jwk(&jwk).unwrap();
+        assert_eq!(sk.as_ref(), decoded.as_ref());
+        assert_eq!(Point::<E>::from_jwk(&jwk).unwrap(), pk);
+
+        assert_eq!(jwk.to_public(), pk.to_jwk().unwrap());
+        assert_eq!(jwk.thumbprint(), pk.to_jwk().unwrap().thumbprint());
+        assert!(!format!("{jwk:?}").contains(jwk.d.as_deref().unwrap().as_str()));
+
+        // Public key that doesn't match secret key
+        let other = Point::<E>::generator() * SecretScalar::random(&mut rng);
+        let mut mismatched = other.to_jwk().unwrap();
+        mismatched.d = jwk.d.clone();
+        assert_eq!(
+            SecretScalar::<E>::from_jwk(&mismatched).err(),
+            Some(KeyFormatError::PublicKeyMismatch)
+        );
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+}
+
+mod vectors {
+    use generic_ec::coords::HasAffineXY;
+    use generic_ec::curves::{Ed25519, Secp256k1, Secp256r1, Stark};
+    use generic_ec::errors::KeyFormatError;
+    use generic_ec::jwk::Jwk;
+    use generic_ec::{Point, SecretScalar};
+    use sha2::Digest;
+
+    /// Example from RFC 7517, Appendices A.1 and A.2
+    #[test]
+    fn rfc7517_p256() {
+        let jwk: Jwk = serde_json::from_str(
+            r#"{"kty":"EC",
+                "crv":"P-256",
+                "x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4",
+                "y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM",
+                "d":"870MB6gfuTJ4HtUnUvYMyJpr5eUZNP4Bk43bVdj3eAE",
+                "use":"enc",
+                "kid":"1"}"#,
+        )
+        .unwrap();
+
+        let pk = Point::<Secp256r1>::from_jwk(&jwk).unwrap();
+        let sk = SecretScalar::<Secp256r1>::from_jwk(&jwk).unwrap();
+        assert_eq!(Point::generator() * &sk, pk);
+        assert_eq!(
+            hex::encode(pk.coords().unwrap().x.as_be_bytes()),
+            "30a0424cd21c2944838a2d75c92b37e76ea20d9f00893a3b4eee8a3c0aafec3e"
+        );
+        assert_eq!(sk.to_jwk().unwrap(), jwk);
+
+        let canonical = r#"{"crv":"P-256","kty":"EC","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}"#;
+        let expected: [u8; 32] = sha2::Sha256::digest(canonical).into();
+        assert_eq!(jwk.thumbprint().unwrap(), expected);
+    }
+
+    /// Example from RFC 8037, Appendices A.1 - A.3
+    #[test]
+    fn rfc8037_ed25519() {
+        let jwk: Jwk = serde_json::from_str(
+            r#"{"kty":"OKP","crv":"Ed25519",
+                "d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+                "x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+        )
+        .unwrap();
+
+        let pk = Point::<Ed25519>::from_jwk(&jwk).unwrap();
+        assert_eq!(
+            hex::encode(pk.to_bytes(true)),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+        assert_eq!(pk.to_jwk().unwrap(), jwk.to_public());
+
+        let sk = SecretScalar::<Ed25519>::from_jwk(&jwk).unwrap();
+        assert_eq!(Point::generator() * &sk, pk);
+        assert_eq!(sk.to_jwk().err(), Some(KeyFormatError::Unsupported));
+
+        assert_eq!(
+            hex::encode(jwk.thumbprint().unwrap()),
+            "90facafea9b1556698540f70c0117a22ea37bd5cf3ed3c47093c1707282b4b89"
+        );
+    }
+
+    #[test]
+    fn curve_mismatch() {
+        let jwk = Point::<Secp256r1>::generator().to_point().to_jwk().unwrap();
+        assert_eq!(
+            Point::<Secp256k1>::from_jwk(&jwk).err(),
+            Some(KeyFormatError::CurveMismatch)
+        );
+        assert_eq!(
+            Point::<Ed25519>::from_jwk(&jwk).err(),
+            Some(KeyFormatError::CurveMismatch)
+        );
+        assert_eq!(
+            Point::<Stark>::generator().to_point().to_jwk().err(),
+            Some(KeyFormatError::UnsupportedCurve)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let mut jwk = Point::<Secp256k1>::generator().to_point().to_jwk().unwrap();
+        jwk.y = None;
+        assert_eq!(
+            Point::<Secp256k1>::from_jwk(&jwk).err(),
+            Some(KeyFormatError::Malformed)
+        );
+        assert_eq!(jwk.thumbprint().err(), Some(KeyFormatError::Malformed));
+
+        let mut jwk = Point::<Secp256k1>::generator().to_point().to_jwk().unwrap();
+        jwk.x.push('=');
+        assert_eq!(
+            Point::<Secp256k1>::from_jwk(&jwk).err(),
+            Some(KeyFormatError::Malformed)
+        );
+    }
+}
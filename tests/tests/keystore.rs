@@ -0,0 +1,304 @@
+#[generic_tests::define]
+mod generic {
+    use generic_ec::errors::KeystoreError;
+    use generic_ec::keystore::{Argon2Params, Keystore, ScryptParams};
+    use generic_ec::{curves::*, Curve, SecretScalar};
+    use rand_dev::DevRng;
+
+    /// Fast parameters, so tests don't take forever
+    const PARAMS: ScryptParams = ScryptParams {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+    const ARGON2_PARAMS: Argon2Params = Argon2Params { m: 64, t: 1, p: 1 };
+
+    #[test]
+    fn roundtrip<E: Curve>() {
+        let mut rng = DevRng::new();
+        let sk = SecretScalar::<E>::random(&mut rng);
+
+        let keystore = sk
+            .to_keystore_with_params(b"password", PARAMS, &mut rng)
+            .unwrap();
+        assert_eq!(keystore.version, 3);
+        assert_eq!(keystore.curve.as_deref(), Some(E::CURVE_NAME));
+        assert_eq!(keystore.id.len(), 36);
+
+        let json = serde_json::to_string(&keystore).unwrap();
+        let parsed: Keystore = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, keystore);
+
+        let decrypted = SecretScalar::<E>::from_keystore(&parsed, b"password").unwrap();
+        assert_eq!(decrypted.as_ref(), sk.as_ref());
+
+        assert_eq!(
+            SecretScalar::<E>::from_keystore(&keystore, b"wrong password").err(),
+            Some(KeystoreError::WrongPassword)
+        );
+    }
+
+    #[test]
+    fn argon2id_roundtrip<E: Curve>() {
+        let mut rng = DevRng::new();
+        let sk = SecretScalar::<E>::random(&mut rng);
+
+        let keystore = sk
+            .to_keystore_argon2id(b"password", ARGON2_PARAMS, &mut rng)
+            .unwrap();
+        assert_eq!(keystore.crypto.kdf, "argon2id");
+        assert_eq!(keystore.crypto.cipher, "chacha20-poly1305");
+
+        let json = serde_json::to_string(&keystore).unwrap();
+        let parsed: Keystore = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, keystore);
+
+        let decrypted = SecretScalar::<E>::from_keystore(&parsed, b"password").unwrap();
+        assert_eq!(decrypted.as_ref(), sk.as_ref());
+
+        assert_eq!(
+            SecretScalar::<E>::from_keystore(&keystore, b"wrong password").err(),
+            Some(KeystoreError::WrongPassword)
+        );
+
+        // Ciphertext is authenticated
+        let mut tampered = keystore;
+        let mut ciphertext = hex::decode(&tampered.crypto.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        tampered.crypto.ciphertext = hex::encode(ciphertext);
+        assert_eq!(
+            SecretScalar::<E>::from_keystore(&tampered, b"password").err(),
+            Some(KeystoreError::WrongPassword)
+        );
+    }
+
+    #[test]
+    fn randomized<E: Curve>() {
+        let mut rng = DevRng::new();
+        let sk = SecretScalar::<E>::random(&mut rng);
+
+        let keystore1 = sk.to_keystore_with_params(b"", PARAMS, &mut rng).unwrap();
+        let keystore2 = sk.to_keystore_with_params(b"", PARAMS, &mut rng).unwrap();
+        assert_ne!(keystore1.id, keystore2.id);
+        assert_ne!(keystore1.crypto.ciphertext, keystore2.crypto.ciphertext);
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+}
+
+mod vectors {
+    use generic_ec::curves::{Secp256k1, Secp256r1};
+    use generic_ec::errors::KeystoreError;
+    use generic_ec::keystore::{Argon2Params, KdfLimits, Keystore, ScryptParams};
+    use generic_ec::SecretScalar;
+    use rand_dev::DevRng;
+
+    const PRIVATE_KEY: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+    /// PBKDF2 test vector from Web3 Secret Storage Definition
+    const PBKDF2_KEYSTORE: &str = r#"{
+        "crypto" : {
+            "cipher" : "aes-128-ctr",
+            "cipherparams" : {
+                "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+            },
+            "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf" : "pbkdf2",
+            "kdfparams" : {
+                "c" : 262144,
+                "dklen" : 32,
+                "prf" : "hmac-sha256",
+                "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version" : 3
+    }"#;
+
+    /// Scrypt test vector from Web3 Secret Storage Definition doesn't satisfy RFC 7914 requirement
+    /// $n < 2^{16r}$, so this one is computed independently with light parameters
+    const SCRYPT_KEYSTORE: &str = r#"{
+        "crypto" : {
+            "cipher" : "aes-128-ctr",
+            "cipherparams" : {
+                "iv" : "83dbcc02d8ccb40e466191a123791e0e"
+            },
+            "ciphertext" : "584f4eb2783472ec149b334c22d77ddbf118a0783ea24e66ca8a7883cd2b4bf5",
+            "kdf" : "scrypt",
+            "kdfparams" : {
+                "dklen" : 32,
+                "n" : 4096,
+                "p" : 6,
+                "r" : 8,
+                "salt" : "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            },
+            "mac" : "860ad3ce9642f99d709abc696e25ede7cd35daf6a6041e975d14c53dae118886"
+        },
+        "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version" : 3
+    }"#;
+
+    #[test]
+    fn web3_secret_storage() {
+        for keystore in [PBKDF2_KEYSTORE, SCRYPT_KEYSTORE] {
+            let keystore: Keystore = serde_json::from_str(keystore).unwrap();
+            assert_eq!(keystore.curve, None);
+
+            let sk = SecretScalar::<Secp256k1>::from_keystore(&keystore, b"testpassword").unwrap();
+            assert_eq!(hex::encode(sk.as_ref().to_be_bytes()), PRIVATE_KEY);
+
+            // Keystore without curve name holds secp256k1 key
+            assert_eq!(
+                SecretScalar::<Secp256r1>::from_keystore(&keystore, b"testpassword").err(),
+                Some(KeystoreError::CurveMismatch)
+            );
+        }
+    }
+
+    #[test]
+    fn rfc7914_params() {
+        let keystore: Keystore = serde_json::from_str(SCRYPT_KEYSTORE).unwrap();
+        let mut invalid = keystore.clone();
+        invalid.crypto.kdfparams.r = Some(1);
+        invalid.crypto.kdfparams.n = Some(1 << 18);
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&invalid, b"testpassword").err(),
+            Some(KeystoreError::InvalidKdfParams)
+        );
+
+        let mut invalid = keystore;
+        invalid.crypto.kdfparams.n = Some(1000);
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&invalid, b"testpassword").err(),
+            Some(KeystoreError::InvalidKdfParams)
+        );
+    }
+
+    #[test]
+    fn kdf_limits() {
+        let keystore: Keystore = serde_json::from_str(SCRYPT_KEYSTORE).unwrap();
+
+        // Running scrypt with such parameters would require 8 TiB of memory, so keystore must be
+        // rejected before running the KDF
+        let mut oversized = keystore.clone();
+        oversized.crypto.kdfparams.n = Some(1 << 40);
+        oversized.crypto.kdfparams.r = Some(8);
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&oversized, b"testpassword").err(),
+            Some(KeystoreError::InvalidKdfParams)
+        );
+
+        let limits = KdfLimits {
+            max_scrypt_p: 4,
+            ..KdfLimits::DEFAULT
+        };
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore_with_limits(
+                &keystore,
+                b"testpassword",
+                limits
+            )
+            .err(),
+            Some(KeystoreError::InvalidKdfParams)
+        );
+
+        let keystore: Keystore = serde_json::from_str(PBKDF2_KEYSTORE).unwrap();
+        let mut oversized = keystore.clone();
+        oversized.crypto.kdfparams.c = Some(u32::MAX);
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&oversized, b"testpassword").err(),
+            Some(KeystoreError::InvalidKdfParams)
+        );
+        let limits = KdfLimits {
+            max_pbkdf2_c: 1 << 18,
+            ..KdfLimits::DEFAULT
+        };
+        let sk = SecretScalar::<Secp256k1>::from_keystore_with_limits(
+            &keystore,
+            b"testpassword",
+            limits,
+        )
+        .unwrap();
+        assert_eq!(hex::encode(sk.as_ref().to_be_bytes()), PRIVATE_KEY);
+
+        let mut rng = DevRng::new();
+        let keystore = sk
+            .to_keystore_argon2id(b"", Argon2Params { m: 64, t: 1, p: 1 }, &mut rng)
+            .unwrap();
+        let mut oversized = keystore.clone();
+        oversized.crypto.kdfparams.m = Some(u32::MAX);
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&oversized, b"").err(),
+            Some(KeystoreError::InvalidKdfParams)
+        );
+        let limits = KdfLimits {
+            max_argon2_m: 32,
+            ..KdfLimits::DEFAULT
+        };
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore_with_limits(&keystore, b"", limits).err(),
+            Some(KeystoreError::InvalidKdfParams)
+        );
+    }
+
+    #[test]
+    fn curve_mismatch() {
+        let mut rng = DevRng::new();
+        let params = ScryptParams {
+            log_n: 4,
+            r: 8,
+            p: 1,
+        };
+        let keystore = SecretScalar::<Secp256r1>::random(&mut rng)
+            .to_keystore_with_params(b"", params, &mut rng)
+            .unwrap();
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&keystore, b"").err(),
+            Some(KeystoreError::CurveMismatch)
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let keystore: Keystore = serde_json::from_str(PBKDF2_KEYSTORE).unwrap();
+
+        let mut unsupported = keystore.clone();
+        unsupported.version = 4;
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&unsupported, b"testpassword").err(),
+            Some(KeystoreError::UnsupportedVersion)
+        );
+
+        let mut unsupported = keystore.clone();
+        unsupported.crypto.cipher = "aes-128-cbc".into();
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&unsupported, b"testpassword").err(),
+            Some(KeystoreError::UnsupportedCipher)
+        );
+
+        let mut unsupported = keystore.clone();
+        unsupported.crypto.kdf = "balloon".into();
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&unsupported, b"testpassword").err(),
+            Some(KeystoreError::UnsupportedKdf)
+        );
+
+        let mut malformed = keystore;
+        malformed.crypto.mac.pop();
+        assert_eq!(
+            SecretScalar::<Secp256k1>::from_keystore(&malformed, b"testpassword").err(),
+            Some(KeystoreError::Malformed)
+        );
+    }
+}
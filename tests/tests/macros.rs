@@ -0,0 +1,77 @@
+use generic_ec::curves::{Ed25519, Secp256k1, Secp256r1, Stark};
+use generic_ec::{point, scalar, Point, Scalar};
+
+#[test]
+fn scalar_literals() {
+    assert_eq!(scalar!(Secp256k1, "0"), Scalar::zero());
+    assert_eq!(scalar!(Secp256r1, "1"), Scalar::one());
+    assert_eq!(scalar!(Stark, "0x2a"), Scalar::from(42));
+    assert_eq!(
+        scalar!(Ed25519, "18446744073709551615"),
+        Scalar::from(u64::MAX)
+    );
+    assert_eq!(
+        scalar!(
+            generic_ec::curves::Ed25519,
+            "7237005577332262213973186563042994240857116359379907606001950938285454250988",
+        ),
+        -Scalar::one()
+    );
+    assert_eq!(
+        scalar!(
+            Secp256k1,
+            "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364140"
+        ),
+        -Scalar::one()
+    );
+}
+
+#[test]
+fn point_literals() {
+    let g = Point::<Secp256k1>::generator().to_point();
+    assert_eq!(
+        point!(
+            Secp256k1,
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        ),
+        g
+    );
+    assert_eq!(
+        point!(
+            Secp256k1,
+            "0x0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+             483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        ),
+        g
+    );
+    assert_eq!(
+        point!(
+            Secp256k1,
+            "000000000000000000000000000000000000000000000000000000000000000000"
+        ),
+        Point::zero()
+    );
+    assert_eq!(
+        point!(
+            Ed25519,
+            "5866666666666666666666666666666666666666666666666666666666666666"
+        ),
+        Point::<Ed25519>::generator().to_point()
+    );
+}
+
+#[test]
+fn curve_aliases() {
+    use generic_ec::curves::{Secp256k1Sha512, StarkTryAndIncrement};
+    type Curve = Secp256r1;
+
+    assert_eq!(scalar!(Secp256k1Sha512, "42"), Scalar::from(42));
+    assert_eq!(scalar!(StarkTryAndIncrement, "0x2a"), Scalar::from(42));
+    assert_eq!(
+        point!(
+            Curve,
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        ),
+        Point::<Curve>::generator().to_point()
+    );
+}
@@ -0,0 +1,108 @@
+#[generic_tests::define]
+mod generic {
+    use generic_ec::serde::{Compact, CurveName, PreferCompact, SchemaAs};
+    use generic_ec::{curves::*, Curve, NonZero, Point, Scalar, SecretScalar};
+    use rand_dev::DevRng;
+    use serde_json::{json, Value};
+    use serde_with::serde_as;
+
+    /// Checks that `value` is a hex string matching length constraints of the `schema`
+    fn check_hex_string(schema: &Value, value: &Value) {
+        assert_eq!(schema["type"], "string");
+        let s = value.as_str().unwrap();
+        assert_eq!(s.len() as u64, schema["minLength"].as_u64().unwrap());
+        assert_eq!(s.len() as u64, schema["maxLength"].as_u64().unwrap());
+        assert_eq!(
+            schema["pattern"],
+            format!("^[0-9a-fA-F]{{{}}}$", s.len()).as_str()
+        );
+        assert!(hex::decode(s).is_ok());
+    }
+
+    #[test]
+    fn default_format<E: Curve>() {
+        let mut rng = DevRng::new();
+        let scalar = Scalar::<E>::random(&mut rng);
+        let point = Point::generator() * scalar;
+
+        let schema = schemars::schema_for!(Point<E>).to_value();
+        let value = serde_json::to_value(point).unwrap();
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["required"], json!(["curve", "point"]));
+        assert_eq!(
+            schema["properties"]["curve"],
+            json!({"type": "string", "const": E::CURVE_NAME})
+        );
+        assert_eq!(value["curve"], E::CURVE_NAME);
+        check_hex_string(&schema["properties"]["point"], &value["point"]);
+
+        let schema = schemars::schema_for!(Scalar<E>).to_value();
+        let value = serde_json::to_value(scalar).unwrap();
+        assert_eq!(schema["required"], json!(["curve", "scalar"]));
+        check_hex_string(&schema["properties"]["scalar"], &value["scalar"]);
+
+        // `SecretScalar` and `NonZero` share schemas of underlying types
+        assert_eq!(schemars::schema_for!(SecretScalar<E>).to_value(), schema);
+        assert_eq!(schemars::schema_for!(NonZero<Scalar<E>>).to_value(), schema);
+
+        assert_eq!(
+            schemars::schema_for!(CurveName<E>).to_value()["const"],
+            E::CURVE_NAME
+        );
+    }
+
+    #[serde_as]
+    #[derive(serde::Serialize, schemars::JsonSchema)]
+    #[serde(bound = "")]
+    #[schemars(bound = "")]
+    struct Proof<E: Curve> {
+        point: Point<E>,
+        #[serde_as(as = "Compact")]
+        #[schemars(with = "SchemaAs<NonZero<Point<E>>, Compact>")]
+        compact_point: NonZero<Point<E>>,
+        #[serde_as(as = "Compact")]
+        #[schemars(with = "SchemaAs<Scalar<E>, Compact>")]
+        compact_scalar: Scalar<E>,
+        #[serde_as(as = "PreferCompact")]
+        #[schemars(with = "SchemaAs<Point<E>, PreferCompact>")]
+        prefer_compact: Point<E>,
+    }
+
+    #[test]
+    fn serde_with_adapters<E: Curve>() {
+        let mut rng = DevRng::new();
+        let proof = Proof::<E> {
+            point: Point::generator() * Scalar::random(&mut rng),
+            compact_point: Point::generator() * NonZero::<Scalar<E>>::random(&mut rng),
+            compact_scalar: Scalar::random(&mut rng),
+            prefer_compact: Point::generator() * Scalar::random(&mut rng),
+        };
+
+        let schema = schemars::schema_for!(Proof<E>).to_value();
+        let value = serde_json::to_value(&proof).unwrap();
+        let properties = &schema["properties"];
+
+        check_hex_string(&properties["compact_point"], &value["compact_point"]);
+        check_hex_string(&properties["compact_scalar"], &value["compact_scalar"]);
+
+        let any_of = properties["prefer_compact"]["anyOf"].as_array().unwrap();
+        assert_eq!(any_of.len(), 2);
+        check_hex_string(&any_of[0], &value["prefer_compact"]);
+        assert_eq!(
+            any_of[1]["$ref"],
+            format!("#/$defs/Point_{}", E::CURVE_NAME).as_str()
+        );
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+}
@@ -0,0 +1,526 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Wraps `T` so it's (de)serialized via `serde_with` adapter `A`
+struct With<A, T>(T, PhantomData<A>);
+
+impl<A, T> With<A, T> {
+    fn new(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<A, T: PartialEq> PartialEq for With<A, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<A, T: fmt::Debug> fmt::Debug for With<A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("With").field(&self.0).finish()
+    }
+}
+
+impl<A, T> serde::Serialize for With<A, T>
+where
+    A: serde_with::SerializeAs<T>,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        A::serialize_as(&self.0, serializer)
+    }
+}
+
+impl<'de, A, T> serde::Deserialize<'de> for With<A, T>
+where
+    A: serde_with::DeserializeAs<'de, T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        A::deserialize_as(deserializer).map(Self::new)
+    }
+}
+
+#[generic_tests::define]
+mod affine_xy {
+    use generic_ec::{coords::HasAffineXY, serde::AffineXY, Curve, NonZero, Point, Scalar};
+    use serde_test::{Configure, Token};
+
+    use super::With;
+
+    #[test]
+    fn point<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let mut rng = rand_dev::DevRng::new();
+
+        let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+        for point in [Point::generator().to_point(), random_point] {
+            let coords = point.coords().unwrap();
+            let x = coords.x.as_be_bytes().to_vec().leak();
+            let y = coords.y.as_be_bytes().to_vec().leak();
+
+            // Human-readable
+            serde_test::assert_tokens(
+                &With::<AffineXY, _>::new(point).readable(),
+                &[
+                    Token::Struct {
+                        name: "PointAffine",
+                        len: 2,
+                    },
+                    Token::Str("x"),
+                    Token::Str(hex::encode(&x).leak()),
+                    Token::Str("y"),
+                    Token::Str(hex::encode(&y).leak()),
+                    Token::StructEnd,
+                ],
+            );
+
+            // Binary
+            let tokens = &[
+                Token::Struct {
+                    name: "PointAffine",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::Bytes(x),
+                Token::Str("y"),
+                Token::Bytes(y),
+                Token::StructEnd,
+            ];
+            serde_test::assert_tokens(&With::<AffineXY, _>::new(point).compact(), tokens);
+            serde_test::assert_tokens(
+                &With::<AffineXY, _>::new(NonZero::from_point(point).unwrap()).compact(),
+                tokens,
+            );
+        }
+    }
+
+    #[test]
+    fn zero_point<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let zero = Point::<E>::zero();
+        match zero.coords() {
+            // Point at infinity doesn't have affine coordinates
+            None => serde_test::assert_ser_tokens_error(
+                &With::<AffineXY, _>::new(zero).readable(),
+                &[],
+                "zero point doesn't have affine coordinates",
+            ),
+            // Curves in complete form (e.g. Ed25519) have coordinates of zero point
+            Some(coords) => serde_test::assert_tokens(
+                &With::<AffineXY, _>::new(zero).readable(),
+                &[
+                    Token::Struct {
+                        name: "PointAffine",
+                        len: 2,
+                    },
+                    Token::Str("x"),
+                    Token::Str(hex::encode(coords.x.as_be_bytes()).leak()),
+                    Token::Str("y"),
+                    Token::Str(hex::encode(coords.y.as_be_bytes()).leak()),
+                    Token::StructEnd,
+                ],
+            ),
+        }
+    }
+
+    #[test]
+    fn point_not_on_curve<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let coords = Point::<E>::generator().to_point().coords().unwrap();
+        let x = coords.x.as_be_bytes().to_vec().leak();
+
+        serde_test::assert_de_tokens_error::<serde_test::Compact<With<AffineXY, Point<E>>>>(
+            &[
+                Token::Struct {
+                    name: "PointAffine",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::Bytes(x),
+                Token::Str("y"),
+                Token::Bytes(x),
+                Token::StructEnd,
+            ],
+            "invalid point: not on curve",
+        );
+    }
+
+    #[test]
+    fn collection<E: Curve>()
+    where
+        Point<E>: HasAffineXY<E>,
+    {
+        let mut rng = rand_dev::DevRng::new();
+
+        let points = (0..3)
+            .map(|_| Point::<E>::generator() * Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut tokens = vec![Token::Seq { len: Some(3) }];
+        for point in &points {
+            let coords = point.coords().unwrap();
+            tokens.extend([
+                Token::Struct {
+                    name: "PointAffine",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::Str(hex::encode(coords.x.as_be_bytes()).leak()),
+                Token::Str("y"),
+                Token::Str(hex::encode(coords.y.as_be_bytes()).leak()),
+                Token::StructEnd,
+            ]);
+        }
+        tokens.push(Token::SeqEnd);
+
+        serde_test::assert_tokens(&With::<Vec<AffineXY>, _>::new(points).readable(), &tokens);
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
+
+#[generic_tests::define]
+mod x_only {
+    use generic_ec::{
+        coords::{HasAffineXAndParity, Parity},
+        serde::XOnly,
+        Curve, NonZero, Point, Scalar, XOnlyPoint,
+    };
+    use serde_test::{Configure, Token};
+
+    use super::With;
+
+    #[test]
+    fn point<E: Curve>()
+    where
+        Point<E>: HasAffineXAndParity<E>,
+    {
+        let mut rng = rand_dev::DevRng::new();
+
+        for _ in 0..10 {
+            let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+            let (x_only, parity) = XOnlyPoint::from_point(&random_point).unwrap();
+            let x = x_only.x().as_be_bytes().to_vec().leak();
+
+            if parity == Parity::Odd {
+                serde_test::assert_ser_tokens_error(
+                    &With::<XOnly, _>::new(random_point).readable(),
+                    &[],
+                    "point has odd y coordinate, it can't be serialized as x-only point",
+                );
+            }
+
+            let point = x_only.to_point();
+            serde_test::assert_tokens(
+                &With::<XOnly, _>::new(point).readable(),
+                &[
+                    Token::NewtypeStruct { name: "XOnlyPoint" },
+                    Token::Str(hex::encode(&x).leak()),
+                ],
+            );
+            serde_test::assert_tokens(
+                &With::<XOnly, _>::new(x_only.to_nonzero_point()).compact(),
+                &[Token::NewtypeStruct { name: "XOnlyPoint" }, Token::Bytes(x)],
+            );
+
+            // Format is the same as of `XOnlyPoint`
+            serde_test::assert_ser_tokens(
+                &x_only.readable(),
+                &[
+                    Token::NewtypeStruct { name: "XOnlyPoint" },
+                    Token::Str(hex::encode(&x).leak()),
+                ],
+            );
+        }
+    }
+
+    #[test]
+    fn zero_point<E: Curve>()
+    where
+        Point<E>: HasAffineXAndParity<E>,
+    {
+        serde_test::assert_ser_tokens_error(
+            &With::<XOnly, _>::new(Point::<E>::zero()).readable(),
+            &[],
+            "zero point can't be serialized as x-only point",
+        );
+    }
+
+    #[test]
+    fn collection<E: Curve>()
+    where
+        Point<E>: HasAffineXAndParity<E>,
+    {
+        let mut rng = rand_dev::DevRng::new();
+
+        let points = (0..3)
+            .map(|_| {
+                let point = Point::<E>::generator() * Scalar::random(&mut rng);
+                XOnlyPoint::from_point(&point).unwrap().0
+            })
+            .collect::<Vec<_>>();
+
+        let mut tokens = vec![Token::Seq { len: Some(3) }];
+        for point in &points {
+            tokens.extend([
+                Token::NewtypeStruct { name: "XOnlyPoint" },
+                Token::Str(hex::encode(point.x().as_be_bytes()).leak()),
+            ]);
+        }
+        tokens.push(Token::SeqEnd);
+
+        let points = points
+            .iter()
+            .map(XOnlyPoint::to_nonzero_point)
+            .collect::<Vec<NonZero<Point<E>>>>();
+        serde_test::assert_tokens(&With::<Vec<XOnly>, _>::new(points).readable(), &tokens);
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+}
+
+#[generic_tests::define]
+mod text_encodings {
+    use generic_ec::{
+        serde::{Base58, Base64},
+        Curve, NonZero, Point, Scalar, SecretScalar,
+    };
+    use serde_test::{Configure, Token};
+
+    use super::With;
+
+    #[test]
+    fn point<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            let bytes = point.to_bytes(true).to_vec().leak();
+
+            // Human-readable
+            serde_test::assert_tokens(
+                &With::<Base64, _>::new(point).readable(),
+                &[Token::Str(base64::encode(&bytes).leak())],
+            );
+            serde_test::assert_tokens(
+                &With::<Base58, _>::new(point).readable(),
+                &[Token::Str(bs58::encode(&bytes).into_string().leak())],
+            );
+
+            // Binary
+            serde_test::assert_tokens(
+                &With::<Base64, _>::new(point).compact(),
+                &[Token::Bytes(bytes)],
+            );
+            serde_test::assert_tokens(
+                &With::<Base58, _>::new(point).compact(),
+                &[Token::Bytes(bytes)],
+            );
+        }
+    }
+
+    #[test]
+    fn scalar<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let random_scalar = Scalar::<E>::random(&mut rng);
+        for scalar in [Scalar::zero(), Scalar::one(), -Scalar::one(), random_scalar] {
+            let bytes = scalar.to_be_bytes().to_vec().leak();
+            let base64: &str = base64::encode(&bytes).leak();
+            let base58: &str = bs58::encode(&bytes).into_string().leak();
+
+            // Human-readable
+            serde_test::assert_tokens(
+                &With::<Base64, _>::new(scalar).readable(),
+                &[Token::Str(base64)],
+            );
+            serde_test::assert_tokens(
+                &With::<Base58, _>::new(scalar).readable(),
+                &[Token::Str(base58)],
+            );
+
+            // Binary
+            serde_test::assert_tokens(
+                &With::<Base64, _>::new(scalar).compact(),
+                &[Token::Bytes(bytes)],
+            );
+            serde_test::assert_tokens(
+                &With::<Base58, _>::new(scalar).compact(),
+                &[Token::Bytes(bytes)],
+            );
+
+            // Secret scalar
+            let secret_scalar = SecretScalar::new(&mut scalar.clone());
+            serde_test::assert_ser_tokens(
+                &With::<Base64, _>::new(secret_scalar.clone()).readable(),
+                &[Token::Str(base64)],
+            );
+            let deserialized: With<Base58, SecretScalar<E>> =
+                serde_json::from_value(serde_json::Value::String(base58.into())).unwrap();
+            assert_eq!(deserialized.0.as_ref(), &scalar);
+        }
+    }
+
+    #[test]
+    fn non_zero<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let point =
+            Point::<E>::generator().to_nonzero_point() * NonZero::<Scalar<E>>::random(&mut rng);
+        let scalar = NonZero::<Scalar<E>>::random(&mut rng);
+
+        serde_test::assert_tokens(
+            &With::<Base64, _>::new(point).readable(),
+            &[Token::Str(base64::encode(point.to_bytes(true)).leak())],
+        );
+        serde_test::assert_tokens(
+            &With::<Base58, _>::new(scalar).readable(),
+            &[Token::Str(
+                bs58::encode(scalar.to_be_bytes()).into_string().leak(),
+            )],
+        );
+
+        serde_test::assert_de_tokens_error::<serde_test::Readable<With<Base64, NonZero<Point<E>>>>>(
+            &[Token::Str(
+                base64::encode(Point::<E>::zero().to_bytes(true)).leak(),
+            )],
+            "zero point",
+        );
+        serde_test::assert_de_tokens_error::<serde_test::Readable<With<Base58, NonZero<Scalar<E>>>>>(
+            &[Token::Str(
+                bs58::encode(Scalar::<E>::zero().to_be_bytes())
+                    .into_string()
+                    .leak(),
+            )],
+            "zero scalar",
+        );
+    }
+
+    #[test]
+    fn collection<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let scalars = (0..3)
+            .map(|_| Scalar::<E>::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut tokens = vec![Token::Seq { len: Some(3) }];
+        tokens.extend(
+            scalars
+                .iter()
+                .map(|s| Token::Str(base64::encode(s.to_be_bytes()).leak())),
+        );
+        tokens.push(Token::SeqEnd);
+
+        serde_test::assert_tokens(&With::<Vec<Base64>, _>::new(scalars).readable(), &tokens);
+    }
+
+    #[test]
+    fn invalid_length<E: Curve>() {
+        let bytes = Scalar::<E>::one().to_be_bytes();
+        let truncated = &bytes[1..];
+
+        serde_test::assert_de_tokens_error::<serde_test::Readable<With<Base64, Scalar<E>>>>(
+            &[Token::Str(base64::encode(truncated).leak())],
+            &format!(
+                "invalid length {}, expected {} bytes",
+                truncated.len(),
+                bytes.len()
+            ),
+        );
+        serde_test::assert_de_tokens_error::<serde_test::Readable<With<Base58, Scalar<E>>>>(
+            &[Token::Str(bs58::encode(truncated).into_string().leak())],
+            &format!(
+                "invalid length {}, expected {} bytes",
+                truncated.len(),
+                bytes.len()
+            ),
+        );
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
+
+mod vectors {
+    use generic_ec::{
+        curves::Secp256k1,
+        serde::{Base58, Base64},
+        Point, Scalar,
+    };
+    use serde_test::{Configure, Token};
+
+    use super::With;
+
+    #[test]
+    fn secp256k1_generator() {
+        let g = Point::<Secp256k1>::generator().to_point();
+
+        serde_test::assert_tokens(
+            &With::<Base64, _>::new(g).readable(),
+            &[Token::Str("Anm+Zn753LusVaBilc6HCwcCm/zbLc4o2VnygVsW+BeY")],
+        );
+        serde_test::assert_tokens(
+            &With::<Base58, _>::new(g).readable(),
+            &[Token::Str("jesTu2BpszP8DKSoi1R5G6ggjHrsrVnboLdx6V47vkoR")],
+        );
+    }
+
+    #[test]
+    fn secp256k1_one() {
+        let one = Scalar::<Secp256k1>::one();
+
+        serde_test::assert_tokens(
+            &With::<Base64, _>::new(one).readable(),
+            &[Token::Str("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAE=")],
+        );
+        serde_test::assert_tokens(
+            &With::<Base58, _>::new(one).readable(),
+            &[Token::Str("11111111111111111111111111111112")],
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        serde_test::assert_de_tokens_error::<serde_test::Readable<With<Base64, Point<Secp256k1>>>>(
+            &[Token::Str("not base64!")],
+            "Invalid byte 32, offset 3.",
+        );
+        serde_test::assert_de_tokens_error::<serde_test::Readable<With<Base58, Point<Secp256k1>>>>(
+            &[Token::Str("0OIl")],
+            "provided string contained invalid character '0' at byte 0",
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use generic_ec::coords::{AlwaysHasAffineYAndSign, Sign};
+use generic_ec::curves::Ed25519;
+use generic_ec::x25519::{self, MontgomeryPoint};
+use generic_ec::{FullGroupPoint, Point, Scalar, SecretScalar};
+
+use rand_dev::DevRng;
+
+fn bytes32(hex_str: &str) -> [u8; 32] {
+    hex::decode(hex_str).unwrap().try_into().unwrap()
+}
+
+/// Test vector from RFC 7748 Section 5.2
+#[test]
+fn x25519_test_vector() {
+    let k = bytes32("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+    let u = bytes32("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+    let expected = bytes32("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552");
+
+    assert_eq!(x25519::x25519(k, u), expected);
+    assert_eq!(
+        MontgomeryPoint::from_bytes(u).mul_clamped(k).to_bytes(),
+        expected
+    );
+}
+
+/// Test vector from RFC 7748 Section 6.1
+#[test]
+fn diffie_hellman_test_vector() {
+    let mut alice_sk = bytes32("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let alice_pk = bytes32("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a");
+    let bob_pk = bytes32("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f");
+    let shared_secret = bytes32("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+
+    assert_eq!(
+        MontgomeryPoint::BASEPOINT.mul_clamped(alice_sk).to_bytes(),
+        alice_pk
+    );
+    assert_eq!(x25519::x25519(alice_sk, bob_pk), shared_secret);
+
+    let alice_scalar = x25519::clamp_to_scalar(alice_sk);
+    assert_eq!(
+        (Point::generator() * alice_scalar)
+            .to_montgomery()
+            .to_bytes(),
+        alice_pk
+    );
+
+    let alice_secret = x25519::clamp_to_secret_scalar(&mut alice_sk);
+    assert_eq!(alice_sk, [0u8; 32]);
+    assert_eq!(
+        MontgomeryPoint::from_bytes(bob_pk)
+            .diffie_hellman(&alice_secret)
+            .to_bytes(),
+        shared_secret
+    );
+}
+
+#[test]
+fn clamping() {
+    let clamped = x25519::clamp_integer([0xff; 32]);
+    assert_eq!(clamped[0], 0xf8);
+    assert_eq!(clamped[31], 0x7f);
+    assert!(clamped[1..31].iter().all(|b| *b == 0xff));
+
+    let clamped = x25519::clamp_integer([0; 32]);
+    assert_eq!(clamped[31], 0x40);
+}
+
+#[test]
+fn generator_maps_to_basepoint() {
+    let g = Point::<Ed25519>::generator().to_point();
+    assert_eq!(g.to_montgomery(), MontgomeryPoint::BASEPOINT);
+    assert_eq!(
+        MontgomeryPoint::BASEPOINT.to_edwards(Sign::NonNegative),
+        Some(g)
+    );
+    assert_eq!(
+        MontgomeryPoint::BASEPOINT.to_edwards(Sign::Negative),
+        Some(-g)
+    );
+    assert!(Point::<Ed25519>::zero().to_montgomery().is_zero());
+}
+
+#[test]
+fn birational_map_roundtrip() {
+    let mut rng = DevRng::new();
+    for _ in 0..20 {
+        let point = Point::<Ed25519>::generator() * Scalar::random(&mut rng);
+        let u = MontgomeryPoint::from(point);
+
+        let (sign, _) = point.y_and_sign();
+        assert_eq!(point.x_sign(), sign);
+        assert_eq!(u.to_edwards(sign), Some(point));
+        assert_eq!(u.to_edwards(other_sign(sign)), Some(-point));
+        assert_eq!((-point).to_montgomery(), u);
+    }
+}
+
+#[test]
+fn invalid_montgomery_points_are_rejected() {
+    // `u = 0` corresponds to point of order 2
+    assert_eq!(
+        MontgomeryPoint::default().to_edwards(Sign::NonNegative),
+        None
+    );
+    // `u = 2` is a point on the twist
+    let mut u = [0u8; 32];
+    u[0] = 2;
+    assert_eq!(
+        MontgomeryPoint::from_bytes(u).to_edwards(Sign::NonNegative),
+        None
+    );
+    // Non-canonical encoding of `u = 9`
+    let mut u = bytes32("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+    u[0] += 9;
+    assert_eq!(
+        MontgomeryPoint::from_bytes(u).to_edwards(Sign::NonNegative),
+        None
+    );
+}
+
+#[test]
+fn diffie_hellman_matches_edwards_arithmetic() {
+    let mut rng = DevRng::new();
+    let alice_sk = SecretScalar::<Ed25519>::random(&mut rng);
+    let bob_sk = SecretScalar::<Ed25519>::random(&mut rng);
+    let alice_pk = Point::generator() * &alice_sk;
+    let bob_pk = Point::generator() * &bob_sk;
+
+    let shared_secret = alice_pk.to_montgomery().diffie_hellman(&bob_sk);
+    assert_eq!(
+        shared_secret,
+        bob_pk.to_montgomery().diffie_hellman(&alice_sk)
+    );
+    assert_eq!(shared_secret, (alice_pk * &bob_sk).to_montgomery());
+}
+
+#[test]
+fn diffie_hellman_matches_x25519() {
+    // Point of order 2
+    let mut torsion = [0xff; 32];
+    torsion[0] = 0xec;
+    torsion[31] = 0x7f;
+    let torsion = FullGroupPoint::<Ed25519>::from_bytes(torsion).unwrap();
+
+    let mut rng = DevRng::new();
+    for _ in 0..10 {
+        let mut k = [0u8; 32];
+        rand::RngCore::fill_bytes(&mut rng, &mut k);
+        // Peer public key with a torsion component
+        let peer = FullGroupPoint::from(Point::generator() * Scalar::random(&mut rng)) + torsion;
+        let peer = peer.to_montgomery();
+        assert_eq!(peer.to_edwards(Sign::NonNegative), None);
+
+        let expected = peer.mul_clamped(k);
+        let secret = x25519::clamp_to_secret_scalar(&mut k);
+        assert_eq!(peer.diffie_hellman(&secret), expected);
+    }
+}
+
+fn other_sign(sign: Sign) -> Sign {
+    match sign {
+        Sign::Negative => Sign::NonNegative,
+        Sign::NonNegative => Sign::Negative,
+    }
+}
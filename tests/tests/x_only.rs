@@ -0,0 +1,118 @@
+use generic_ec::coords::{HasAffineXAndParity, Parity};
+use generic_ec::curves::Secp256k1;
+use generic_ec::{Point, Scalar, SecretScalar, XOnlyPoint};
+
+use rand_dev::DevRng;
+use serde_test::{Configure, Token};
+
+/// Secret and public keys from BIP340 test vectors
+const BIP340_KEYS: &[(&str, &str)] = &[
+    (
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+    ),
+    (
+        "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef",
+        "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+    ),
+    (
+        "c90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b14e5c9",
+        "dd308afec5777e13121fa72b9cc1b7cc0139715309b086c960e18fd969774eb8",
+    ),
+    (
+        "0b432b2677937381aef05bb02a66ecd012773062cf3fa2549e44f58ed2401710",
+        "25d1dff95105f5253c4022f628a996ad3a0d95fbf21d468a1b33f8c160d8f517",
+    ),
+];
+
+#[test]
+fn bip340_keys() {
+    for (secret_key, public_key) in BIP340_KEYS {
+        let mut secret_key =
+            Scalar::<Secp256k1>::from_be_bytes(hex::decode(secret_key).unwrap()).unwrap();
+        let secret_key = SecretScalar::new(&mut secret_key);
+        let public_key = hex::decode(public_key).unwrap();
+
+        let (even_secret_key, x_only) = secret_key.to_even_y().unwrap();
+        assert_eq!(x_only.to_bytes().as_be_bytes(), public_key);
+        assert_eq!(x_only.as_ref(), public_key);
+        assert_eq!(Point::generator() * &even_secret_key, x_only.to_point());
+
+        let parsed = XOnlyPoint::<Secp256k1>::from_bytes(&public_key).unwrap();
+        assert_eq!(parsed, x_only);
+        let (_, parity) = x_only.to_point().x_and_parity().unwrap();
+        assert_eq!(parity, Parity::Even);
+    }
+}
+
+#[test]
+fn invalid_x_only_points() {
+    // Not on curve (BIP340 test vector 5)
+    let not_on_curve =
+        hex::decode("eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34").unwrap();
+    assert!(XOnlyPoint::<Secp256k1>::from_bytes(not_on_curve).is_err());
+    // Exceeds field size (BIP340 test vector 14)
+    let too_large =
+        hex::decode("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30").unwrap();
+    assert!(XOnlyPoint::<Secp256k1>::from_bytes(too_large).is_err());
+    // Wrong length
+    assert!(XOnlyPoint::<Secp256k1>::from_bytes([1u8; 33]).is_err());
+    // Zero point has no x-only form
+    assert!(XOnlyPoint::from_point(&Point::<Secp256k1>::zero()).is_none());
+    assert!(SecretScalar::<Secp256k1>::zero().to_even_y().is_none());
+}
+
+#[test]
+fn from_point_reports_negation() {
+    let mut rng = DevRng::new();
+    let mut seen = [false; 2];
+    for _ in 0..20 {
+        let point = Point::<Secp256k1>::generator() * Scalar::random(&mut rng);
+        let (x_only, parity) = XOnlyPoint::from_point(&point).unwrap();
+        match parity {
+            Parity::Even => assert_eq!(x_only.to_point(), point),
+            Parity::Odd => assert_eq!(x_only.to_point(), -point),
+        }
+        seen[usize::from(parity.is_even())] = true;
+
+        assert_eq!(
+            Some(x_only.x().clone()),
+            point.x_and_parity().map(|(x, _)| x)
+        );
+        assert_eq!(XOnlyPoint::lift_x(x_only.x()), Some(x_only.clone()));
+        let (lifted, parity) = XOnlyPoint::from_point(&x_only.to_point()).unwrap();
+        assert_eq!(lifted, x_only);
+        assert_eq!(parity, Parity::Even);
+    }
+    assert_eq!(seen, [true, true]);
+}
+
+#[test]
+fn serde() {
+    let mut rng = DevRng::new();
+    let point = Point::<Secp256k1>::generator() * Scalar::random(&mut rng);
+    let (x_only, _) = XOnlyPoint::from_point(&point).unwrap();
+    let x = x_only.x().as_be_bytes().to_vec().leak();
+    let x_hex = hex::encode(&x).leak();
+
+    serde_test::assert_tokens(
+        &x_only.clone().readable(),
+        &[
+            Token::NewtypeStruct { name: "XOnlyPoint" },
+            Token::Str(x_hex),
+        ],
+    );
+    serde_test::assert_tokens(
+        &x_only.compact(),
+        &[Token::NewtypeStruct { name: "XOnlyPoint" }, Token::Bytes(x)],
+    );
+
+    // Not on curve (BIP340 test vector 5)
+    serde_test::assert_de_tokens_error::<serde_test::Readable<XOnlyPoint<Secp256k1>>>(
+        &[
+            Token::NewtypeStruct { name: "XOnlyPoint" },
+            Token::Str("eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34"),
+        ],
+        "invalid point: not on curve",
+    );
+}